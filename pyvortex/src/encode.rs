@@ -6,7 +6,7 @@ use arrow::record_batch::RecordBatchReader;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use vortex::array::chunked::ChunkedArray;
-use vortex::arrow::{FromArrowArray, FromArrowType};
+use vortex::arrow::{FromArrowArray, TryFromArrowType};
 use vortex::{ArrayData, IntoArray, ToArrayData};
 use vortex_dtype::DType;
 
@@ -40,7 +40,9 @@ pub fn encode(obj: &PyAny) -> PyResult<Py<PyArray>> {
         let dtype: DType = obj
             .getattr("type")
             .and_then(DataType::from_pyarrow)
-            .map(|dt| DType::from_arrow(&Field::new("_", dt, false)))?;
+            .and_then(|dt| {
+                DType::try_from_arrow(&Field::new("_", dt, false)).map_err(PyVortexError::map_err)
+            })?;
         PyArray::wrap(
             obj.py(),
             ChunkedArray::try_new(encoded_chunks, dtype)
@@ -49,7 +51,7 @@ pub fn encode(obj: &PyAny) -> PyResult<Py<PyArray>> {
         )
     } else if obj.is_instance(table)? {
         let array_stream = ArrowArrayStreamReader::from_pyarrow(obj)?;
-        let dtype = DType::from_arrow(array_stream.schema());
+        let dtype = DType::try_from_arrow(array_stream.schema()).map_err(PyVortexError::map_err)?;
         let chunks = array_stream
             .into_iter()
             .map(|b| {
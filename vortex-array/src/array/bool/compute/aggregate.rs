@@ -0,0 +1,34 @@
+use vortex_dtype::Nullability;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::bool::BoolArray;
+use crate::compute::aggregate::{Aggregate, AggregateFn};
+use crate::validity::ArrayValidity;
+use crate::{ArrayDType, ArrayTrait};
+
+impl AggregateFn for BoolArray {
+    fn aggregate(&self, agg: Aggregate) -> VortexResult<Scalar> {
+        let values = self.boolean_buffer();
+        let (trues, valid) = match self.logical_validity().to_null_buffer()? {
+            None => (values.count_set_bits(), self.len()),
+            Some(nulls) => (
+                (&values & nulls.inner()).count_set_bits(),
+                nulls.len() - nulls.null_count(),
+            ),
+        };
+        let falses = valid - trues;
+
+        Ok(match agg {
+            Aggregate::Count => (valid as u64).into(),
+            Aggregate::Any => (trues > 0).into(),
+            Aggregate::All => (falses == 0).into(),
+            Aggregate::Min | Aggregate::Max if valid == 0 => {
+                Scalar::null(self.dtype().as_nullable())
+            }
+            Aggregate::Min => Scalar::bool(falses == 0, Nullability::Nullable),
+            Aggregate::Max => Scalar::bool(trues > 0, Nullability::Nullable),
+            Aggregate::Sum => vortex_bail!("{:?} is not supported for {}", agg, self.dtype()),
+        })
+    }
+}
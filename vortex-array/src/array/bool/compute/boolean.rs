@@ -0,0 +1,149 @@
+use arrow_buffer::BooleanBuffer;
+use vortex_error::VortexResult;
+
+use crate::array::bool::BoolArray;
+use crate::compute::boolean::{BinaryBooleanFn, BooleanOperator, NotFn};
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray};
+
+impl BinaryBooleanFn for BoolArray {
+    fn binary_boolean(&self, rhs: &Array, op: BooleanOperator) -> VortexResult<Array> {
+        let rhs = rhs.clone().flatten_bool()?;
+        let lhs_values = self.boolean_buffer();
+        let rhs_values = rhs.boolean_buffer();
+        let values = match op {
+            BooleanOperator::And => &lhs_values & &rhs_values,
+            BooleanOperator::AndNot => &lhs_values & &!&rhs_values,
+            BooleanOperator::Or => &lhs_values | &rhs_values,
+            BooleanOperator::Xor => &lhs_values ^ &rhs_values,
+        };
+
+        let validity = if !self.dtype().is_nullable() && !rhs.dtype().is_nullable() {
+            Validity::NonNullable
+        } else {
+            let lhs_valid = self.logical_validity().to_null_buffer()?;
+            let rhs_valid = rhs.logical_validity().to_null_buffer()?;
+            if lhs_valid.is_none() && rhs_valid.is_none() {
+                Validity::AllValid
+            } else {
+                let lhs_valid = lhs_valid
+                    .map(|n| n.into_inner())
+                    .unwrap_or_else(|| BooleanBuffer::new_set(self.len()));
+                let rhs_valid = rhs_valid
+                    .map(|n| n.into_inner())
+                    .unwrap_or_else(|| BooleanBuffer::new_set(self.len()));
+                Validity::from(kleene_validity(
+                    op,
+                    &lhs_values,
+                    &lhs_valid,
+                    &rhs_values,
+                    &rhs_valid,
+                ))
+            }
+        };
+
+        BoolArray::try_new(values, validity).map(|a| a.into_array())
+    }
+}
+
+/// The positions whose result is known despite nulls: both sides are valid, or one valid side
+/// decides the result on its own.
+fn kleene_validity(
+    op: BooleanOperator,
+    lhs: &BooleanBuffer,
+    lhs_valid: &BooleanBuffer,
+    rhs: &BooleanBuffer,
+    rhs_valid: &BooleanBuffer,
+) -> BooleanBuffer {
+    let both_valid = lhs_valid & rhs_valid;
+    match op {
+        // A valid false decides an AND.
+        BooleanOperator::And => &(&both_valid | &(lhs_valid & &!lhs)) | &(rhs_valid & &!rhs),
+        // A valid false on the left, or a valid true on the right, decides an AND NOT.
+        BooleanOperator::AndNot => &(&both_valid | &(lhs_valid & &!lhs)) | &(rhs_valid & rhs),
+        // A valid true decides an OR.
+        BooleanOperator::Or => &(&both_valid | &(lhs_valid & lhs)) | &(rhs_valid & rhs),
+        BooleanOperator::Xor => both_valid,
+    }
+}
+
+impl NotFn for BoolArray {
+    fn not(&self) -> VortexResult<Array> {
+        BoolArray::try_new(!&self.boolean_buffer(), self.validity()).map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use crate::array::bool::BoolArray;
+    use crate::compute::boolean::{and, and_not, not, or, xor};
+    use crate::compute::scalar_at::scalar_at;
+    use crate::{Array, ArrayDType, IntoArray};
+
+    fn to_options(array: Array) -> Vec<Option<bool>> {
+        (0..array.len())
+            .map(|i| bool::try_from(&scalar_at(&array, i).unwrap()).ok())
+            .collect_vec()
+    }
+
+    /// Every combination of true, false and null.
+    fn operands() -> (Array, Array) {
+        let values = [Some(true), Some(false), None];
+        let (lhs, rhs): (Vec<_>, Vec<_>) = values
+            .iter()
+            .cartesian_product(values.iter())
+            .map(|(l, r)| (*l, *r))
+            .unzip();
+        (
+            BoolArray::from_iter(lhs).into_array(),
+            BoolArray::from_iter(rhs).into_array(),
+        )
+    }
+
+    #[test]
+    fn kleene_logic() {
+        let (lhs, rhs) = operands();
+        let t = Some(true);
+        let f = Some(false);
+        assert_eq!(
+            to_options(and(&lhs, &rhs).unwrap()),
+            vec![t, f, None, f, f, f, None, f, None]
+        );
+        assert_eq!(
+            to_options(or(&lhs, &rhs).unwrap()),
+            vec![t, t, t, t, f, None, t, None, None]
+        );
+        assert_eq!(
+            to_options(and_not(&lhs, &rhs).unwrap()),
+            vec![f, t, None, f, f, f, f, None, None]
+        );
+        assert_eq!(
+            to_options(xor(&lhs, &rhs).unwrap()),
+            vec![f, t, None, t, f, None, None, None, None]
+        );
+        assert_eq!(
+            to_options(not(&lhs).unwrap()),
+            vec![f, f, f, t, t, t, None, None, None]
+        );
+    }
+
+    #[test]
+    fn non_nullable() {
+        let lhs = BoolArray::from(vec![true, true, false, false]).into_array();
+        let rhs = BoolArray::from(vec![true, false, true, false]).into_array();
+        let result = and(&lhs, &rhs).unwrap();
+        assert!(!result.dtype().is_nullable());
+        assert_eq!(
+            result
+                .flatten_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect_vec(),
+            vec![true, false, false, false]
+        );
+        assert!(xor(&lhs, &BoolArray::from(vec![true]).into_array()).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use arrow_buffer::BooleanBufferBuilder;
+use vortex_error::VortexResult;
+
+use crate::array::bool::BoolArray;
+use crate::compute::filter::FilterFn;
+use crate::{Array, IntoArray};
+
+impl FilterFn for BoolArray {
+    fn filter(&self, mask: &Array) -> VortexResult<Array> {
+        let validity = self.validity().filter(mask)?;
+        let mask = mask.clone().flatten_bool()?.boolean_buffer();
+        let bools = self.boolean_buffer();
+
+        let mut filtered = BooleanBufferBuilder::new(mask.count_set_bits());
+        for (start, end) in mask.set_slices() {
+            filtered.append_buffer(&bools.slice(start, end - start));
+        }
+        BoolArray::try_new(filtered.finish(), validity).map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use crate::array::bool::BoolArray;
+    use crate::compute::filter::filter;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::IntoArray;
+
+    #[test]
+    fn filter_nullable() {
+        let array = BoolArray::from_iter(vec![Some(true), None, Some(false), Some(true)]);
+        let mask = BoolArray::from(vec![false, true, true, true]).into_array();
+        let filtered = filter(&array.into_array(), &mask).unwrap();
+        assert_eq!(
+            (0..filtered.len())
+                .map(|i| bool::try_from(&scalar_at(&filtered, i).unwrap()).ok())
+                .collect_vec(),
+            vec![None, Some(false), Some(true)]
+        );
+    }
+}
@@ -1,23 +1,39 @@
 use crate::array::bool::BoolArray;
+use crate::compute::aggregate::AggregateFn;
 use crate::compute::as_arrow::AsArrowArray;
 use crate::compute::as_contiguous::AsContiguousFn;
+use crate::compute::boolean::{BinaryBooleanFn, NotFn};
 use crate::compute::compare::CompareFn;
 use crate::compute::fill::FillForwardFn;
+use crate::compute::filter::FilterFn;
 use crate::compute::scalar_at::ScalarAtFn;
 use crate::compute::slice::SliceFn;
+use crate::compute::sort::ArgsortFn;
 use crate::compute::take::TakeFn;
 use crate::compute::ArrayCompute;
 
+mod aggregate;
 mod as_arrow;
 mod as_contiguous;
+mod boolean;
 mod compare;
 mod fill;
+mod filter;
 mod flatten;
 mod scalar_at;
 mod slice;
+mod sort;
 mod take;
 
 impl ArrayCompute for BoolArray {
+    fn aggregate(&self) -> Option<&dyn AggregateFn> {
+        Some(self)
+    }
+
+    fn argsort(&self) -> Option<&dyn ArgsortFn> {
+        Some(self)
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         Some(self)
     }
@@ -26,6 +42,10 @@ impl ArrayCompute for BoolArray {
         Some(self)
     }
 
+    fn binary_boolean(&self) -> Option<&dyn BinaryBooleanFn> {
+        Some(self)
+    }
+
     fn compare(&self) -> Option<&dyn CompareFn> {
         Some(self)
     }
@@ -34,6 +54,14 @@ impl ArrayCompute for BoolArray {
         Some(self)
     }
 
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -0,0 +1,31 @@
+use vortex_error::VortexResult;
+
+use crate::array::bool::BoolArray;
+use crate::compute::sort::{argsort_by, with_nulls, ArgsortFn, Comparator};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayTrait};
+
+impl BoolArray {
+    pub(crate) fn comparator(
+        &self,
+        descending: bool,
+        nulls_first: bool,
+    ) -> VortexResult<Comparator> {
+        let values = self.boolean_buffer();
+        Ok(with_nulls(
+            self.logical_validity().to_null_buffer()?,
+            descending,
+            nulls_first,
+            move |a, b| values.value(a).cmp(&values.value(b)),
+        ))
+    }
+}
+
+impl ArgsortFn for BoolArray {
+    fn argsort(&self, descending: bool, nulls_first: bool) -> VortexResult<Array> {
+        Ok(argsort_by(
+            self.len(),
+            self.comparator(descending, nulls_first)?,
+        ))
+    }
+}
@@ -44,7 +44,8 @@ impl BoolArray {
                     validity: validity.to_metadata(buffer.len())?,
                     length: buffer.len(),
                 },
-                Some(Buffer::from(buffer.into_inner())),
+                // Sliced buffers may start part-way through a byte, which we don't store.
+                Some(Buffer::from(buffer.sliced())),
                 validity.into_array_data().into_iter().collect_vec().into(),
                 StatsSet::new(),
             )?,
@@ -119,6 +120,8 @@ impl EncodingCompression for BoolEncoding {}
 
 #[cfg(test)]
 mod tests {
+    use arrow_buffer::BooleanBuffer;
+
     use crate::array::bool::BoolArray;
     use crate::compute::scalar_at::scalar_at;
     use crate::IntoArray;
@@ -129,4 +132,15 @@ mod tests {
         let scalar = bool::try_from(&scalar_at(&arr, 0).unwrap()).unwrap();
         assert!(scalar);
     }
+
+    #[test]
+    fn sliced_buffer() {
+        // The slice starts part-way through the first byte of the buffer.
+        let buffer = BooleanBuffer::from(vec![true, true, true, false, true, false]).slice(3, 3);
+        let arr = BoolArray::from(buffer);
+        assert_eq!(
+            arr.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, false]
+        );
+    }
 }
@@ -0,0 +1,88 @@
+use vortex_dtype::PType;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::chunked::ChunkedArray;
+use crate::compute::aggregate::{aggregate, Aggregate, AggregateFn, SumAccumulator};
+use crate::ArrayDType;
+
+impl AggregateFn for ChunkedArray {
+    fn aggregate(&self, agg: Aggregate) -> VortexResult<Scalar> {
+        let partials = self
+            .chunks()
+            .map(|chunk| aggregate(&chunk, agg))
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        match agg {
+            Aggregate::Sum => {
+                let mut sum = SumAccumulator::new(PType::try_from(self.dtype())?)?;
+                for partial in &partials {
+                    sum.add_scalar(partial, 1)?;
+                }
+                sum.finish()
+            }
+            Aggregate::Count => {
+                let mut count = 0u64;
+                for partial in &partials {
+                    count += u64::try_from(partial)?;
+                }
+                Ok(count.into())
+            }
+            Aggregate::Min | Aggregate::Max => Ok(partials
+                .into_iter()
+                .filter(|p| !p.is_null())
+                .reduce(|best, p| {
+                    let better = if agg == Aggregate::Min {
+                        p < best
+                    } else {
+                        p > best
+                    };
+                    if better {
+                        p
+                    } else {
+                        best
+                    }
+                })
+                .unwrap_or_else(|| Scalar::null(self.dtype().as_nullable()))),
+            Aggregate::Any | Aggregate::All => {
+                let values = partials
+                    .iter()
+                    .map(bool::try_from)
+                    .collect::<VortexResult<Vec<_>>>()?;
+                Ok(if agg == Aggregate::Any {
+                    values.into_iter().any(|v| v)
+                } else {
+                    values.into_iter().all(|v| v)
+                }
+                .into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::aggregate::{count, max, sum};
+    use crate::IntoArray;
+
+    #[test]
+    fn chunked_aggregates() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1u32), None]).into_array(),
+                PrimitiveArray::from_nullable_vec(vec![None::<u32>]).into_array(),
+                PrimitiveArray::from_nullable_vec(vec![Some(9u32), Some(4)]).into_array(),
+            ],
+            DType::Primitive(PType::U32, Nullability::Nullable),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(sum(&chunked).unwrap(), Some(14u64).into());
+        assert_eq!(count(&chunked).unwrap(), 3);
+        assert_eq!(max(&chunked).unwrap(), Some(9u32).into());
+    }
+}
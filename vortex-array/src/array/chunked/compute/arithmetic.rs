@@ -0,0 +1,68 @@
+use vortex_error::VortexResult;
+
+use crate::array::chunked::ChunkedArray;
+use crate::compute::arithmetic::{binary_numeric, neg, BinaryNumericFn, NegateFn, NumericOperator};
+use crate::compute::slice::slice;
+use crate::{Array, ArrayDType, IntoArray};
+
+impl BinaryNumericFn for ChunkedArray {
+    fn binary_numeric(&self, rhs: &Array, op: NumericOperator) -> VortexResult<Array> {
+        let mut chunks = Vec::with_capacity(self.nchunks());
+        let mut offset = 0;
+        for chunk in self.chunks() {
+            let rhs_chunk = slice(rhs, offset, offset + chunk.len())?;
+            offset += chunk.len();
+            chunks.push(binary_numeric(&chunk, &rhs_chunk, op)?);
+        }
+
+        let dtype = if rhs.dtype().is_nullable() {
+            self.dtype().as_nullable()
+        } else {
+            self.dtype().clone()
+        };
+        Self::try_new(chunks, dtype).map(|a| a.into_array())
+    }
+}
+
+impl NegateFn for ChunkedArray {
+    fn negate(&self) -> VortexResult<Array> {
+        let chunks = self
+            .chunks()
+            .map(|chunk| neg(&chunk))
+            .collect::<VortexResult<Vec<_>>>()?;
+        Self::try_new(chunks, self.dtype().clone()).map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::arithmetic::{scalar_numeric, NumericOperator};
+    use crate::{ArrayDType, IntoArray};
+
+    #[test]
+    fn chunked_scalar() {
+        let a = PrimitiveArray::from(vec![1u32, 2, 3]).into_array();
+        let chunked = ChunkedArray::try_new(vec![a.clone(), a.clone()], a.dtype().clone())
+            .unwrap()
+            .into_array();
+
+        let result = ChunkedArray::try_from(
+            scalar_numeric(&chunked, &Scalar::from(Some(10u32)), NumericOperator::Mul).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(result.nchunks(), 2);
+        assert!(result.dtype().is_nullable());
+        assert_eq!(
+            result
+                .into_array()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<u32>(),
+            &[10, 20, 30, 10, 20, 30]
+        );
+    }
+}
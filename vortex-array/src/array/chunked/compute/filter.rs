@@ -0,0 +1,57 @@
+use vortex_error::VortexResult;
+
+use crate::array::chunked::ChunkedArray;
+use crate::compute::filter::{filter, FilterFn};
+use crate::compute::slice::slice;
+use crate::{Array, ArrayDType, IntoArray};
+
+impl FilterFn for ChunkedArray {
+    fn filter(&self, mask: &Array) -> VortexResult<Array> {
+        let mut chunks = Vec::with_capacity(self.nchunks());
+        let mut offset = 0;
+        for chunk in self.chunks() {
+            let chunk_mask = slice(mask, offset, offset + chunk.len())?;
+            offset += chunk.len();
+
+            let filtered = filter(&chunk, &chunk_mask)?;
+            if !filtered.is_empty() {
+                chunks.push(filtered);
+            }
+        }
+        Self::try_new(chunks, self.dtype().clone()).map(|a| a.into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::bool::BoolArray;
+    use crate::array::chunked::ChunkedArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::filter::filter;
+    use crate::{ArrayDType, IntoArray};
+
+    #[test]
+    fn filter_chunks() {
+        let a = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        let chunked =
+            ChunkedArray::try_new(vec![a.clone(), a.clone(), a.clone()], a.dtype().clone())
+                .unwrap()
+                .into_array();
+        let mask = BoolArray::from(vec![
+            false, true, false, false, false, false, true, true, false,
+        ])
+        .into_array();
+
+        let filtered = ChunkedArray::try_from(filter(&chunked, &mask).unwrap()).unwrap();
+        // The middle chunk is dropped entirely.
+        assert_eq!(filtered.nchunks(), 2);
+        assert_eq!(
+            filtered
+                .into_array()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<i32>(),
+            &[2, 1, 2]
+        );
+    }
+}
@@ -2,7 +2,10 @@ use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::array::chunked::ChunkedArray;
+use crate::compute::aggregate::AggregateFn;
+use crate::compute::arithmetic::{BinaryNumericFn, NegateFn};
 use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
+use crate::compute::filter::FilterFn;
 use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
 use crate::compute::scalar_subtract::SubtractScalarFn;
 use crate::compute::slice::SliceFn;
@@ -10,14 +13,33 @@ use crate::compute::take::TakeFn;
 use crate::compute::ArrayCompute;
 use crate::Array;
 
+mod aggregate;
+mod arithmetic;
+mod filter;
 mod slice;
 mod take;
 
 impl ArrayCompute for ChunkedArray {
+    fn aggregate(&self) -> Option<&dyn AggregateFn> {
+        Some(self)
+    }
+
     fn as_contiguous(&self) -> Option<&dyn AsContiguousFn> {
         Some(self)
     }
 
+    fn binary_numeric(&self) -> Option<&dyn BinaryNumericFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn negate(&self) -> Option<&dyn NegateFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
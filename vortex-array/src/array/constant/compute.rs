@@ -1,28 +1,98 @@
 use itertools::Itertools;
+use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_err, VortexResult};
-use vortex_scalar::Scalar;
+use vortex_scalar::{BoolScalar, Scalar};
 
 use crate::array::constant::ConstantArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::aggregate::{weighted_aggregate, Aggregate, AggregateFn};
+use crate::compute::arithmetic::{
+    scalar_binary_numeric, scalar_negate, BinaryNumericFn, NegateFn, NumericOperator,
+};
 use crate::compute::as_contiguous::AsContiguousFn;
+use crate::compute::boolean::{not, BinaryBooleanFn, BooleanOperator, NotFn};
+use crate::compute::filter::FilterFn;
 use crate::compute::scalar_at::ScalarAtFn;
+use crate::compute::slice::SliceFn;
+use crate::compute::sort::{ArgsortFn, SortFn};
 use crate::compute::take::TakeFn;
 use crate::compute::ArrayCompute;
-use crate::{Array, ArrayTrait, IntoArray};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray, ToArray};
 
 impl ArrayCompute for ConstantArray {
+    fn aggregate(&self) -> Option<&dyn AggregateFn> {
+        Some(self)
+    }
+
+    fn argsort(&self) -> Option<&dyn ArgsortFn> {
+        Some(self)
+    }
+
     fn as_contiguous(&self) -> Option<&dyn AsContiguousFn> {
         Some(self)
     }
 
+    fn binary_boolean(&self) -> Option<&dyn BinaryBooleanFn> {
+        Some(self)
+    }
+
+    fn binary_numeric(&self) -> Option<&dyn BinaryNumericFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn negate(&self) -> Option<&dyn NegateFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
 }
 
+impl AggregateFn for ConstantArray {
+    fn aggregate(&self, agg: Aggregate) -> VortexResult<Scalar> {
+        // The single value repeated len times.
+        weighted_aggregate(
+            &ConstantArray::new(self.scalar().clone(), 1).into_array(),
+            &[self.len() as u64],
+            agg,
+        )
+    }
+}
+
+impl ArgsortFn for ConstantArray {
+    fn argsort(&self, _descending: bool, _nulls_first: bool) -> VortexResult<Array> {
+        // Every value is equal, so a stable sort keeps them in place.
+        Ok(PrimitiveArray::from((0..self.len() as u64).collect::<Vec<_>>()).into_array())
+    }
+}
+
+impl SortFn for ConstantArray {
+    fn sort(&self, _descending: bool, _nulls_first: bool) -> VortexResult<Array> {
+        Ok(self.clone().into_array())
+    }
+}
+
 impl AsContiguousFn for ConstantArray {
     fn as_contiguous(&self, arrays: &[Array]) -> VortexResult<Array> {
         let chunks = arrays
@@ -45,14 +115,148 @@ impl AsContiguousFn for ConstantArray {
     }
 }
 
+impl BinaryBooleanFn for ConstantArray {
+    /// A valid constant either decides the result on its own, or passes the other side through,
+    /// possibly negated.
+    fn binary_boolean(&self, rhs: &Array, op: BooleanOperator) -> VortexResult<Array> {
+        let nullability =
+            Nullability::from(self.dtype().is_nullable() || rhs.dtype().is_nullable());
+        // The other side can only be returned as-is if it already has the result's nullability.
+        let passthrough = rhs.dtype().nullability() == nullability;
+        match (BoolScalar::try_from(self.scalar())?.value(), op) {
+            (Some(false), BooleanOperator::And | BooleanOperator::AndNot) => {
+                Ok(Self::new(Scalar::bool(false, nullability), self.len()).into_array())
+            }
+            (Some(true), BooleanOperator::Or) => {
+                Ok(Self::new(Scalar::bool(true, nullability), self.len()).into_array())
+            }
+            (Some(true), BooleanOperator::And)
+            | (Some(false), BooleanOperator::Or | BooleanOperator::Xor)
+                if passthrough =>
+            {
+                Ok(rhs.clone())
+            }
+            (Some(true), BooleanOperator::AndNot | BooleanOperator::Xor) if passthrough => not(rhs),
+            (None, BooleanOperator::Xor) => Ok(Self::new(
+                Scalar::null(DType::Bool(Nullability::Nullable)),
+                self.len(),
+            )
+            .into_array()),
+            _ => BinaryBooleanFn::binary_boolean(&self.to_array().flatten_bool()?, rhs, op),
+        }
+    }
+}
+
+impl BinaryNumericFn for ConstantArray {
+    fn binary_numeric(&self, rhs: &Array, op: NumericOperator) -> VortexResult<Array> {
+        match Self::try_from(rhs) {
+            Ok(rhs) => Ok(Self::new(
+                scalar_binary_numeric(self.scalar(), rhs.scalar(), op)?,
+                self.len(),
+            )
+            .into_array()),
+            Err(_) => {
+                BinaryNumericFn::binary_numeric(&self.to_array().flatten_primitive()?, rhs, op)
+            }
+        }
+    }
+}
+
+impl NegateFn for ConstantArray {
+    fn negate(&self) -> VortexResult<Array> {
+        Ok(Self::new(scalar_negate(self.scalar())?, self.len()).into_array())
+    }
+}
+
+impl NotFn for ConstantArray {
+    fn not(&self) -> VortexResult<Array> {
+        let scalar = match BoolScalar::try_from(self.scalar())?.value() {
+            Some(value) => Scalar::bool(!value, self.dtype().nullability()),
+            None => self.scalar().clone(),
+        };
+        Ok(Self::new(scalar, self.len()).into_array())
+    }
+}
+
+impl FilterFn for ConstantArray {
+    fn filter(&self, mask: &Array) -> VortexResult<Array> {
+        let len = mask
+            .clone()
+            .flatten_bool()?
+            .boolean_buffer()
+            .count_set_bits();
+        Ok(Self::new(self.scalar().clone(), len).into_array())
+    }
+}
+
 impl ScalarAtFn for ConstantArray {
     fn scalar_at(&self, _index: usize) -> VortexResult<Scalar> {
         Ok(self.scalar().clone())
     }
 }
 
+impl SliceFn for ConstantArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        Ok(Self::new(self.scalar().clone(), stop - start).into_array())
+    }
+}
+
 impl TakeFn for ConstantArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         Ok(Self::new(self.scalar().clone(), indices.len()).into_array())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::array::bool::{Bool, BoolArray};
+    use crate::array::constant::ConstantArray;
+    use crate::compute::arithmetic::{neg, sub};
+    use crate::compute::boolean::{and, and_not, or, xor};
+    use crate::compute::scalar_at::scalar_at;
+    use crate::{ArrayDef, IntoArray};
+
+    #[test]
+    fn boolean_fast_paths() {
+        let bools = BoolArray::from_iter(vec![Some(true), None, Some(false)]).into_array();
+        let nullable = |value| ConstantArray::new(Scalar::bool(value, Nullability::Nullable), 3);
+
+        let anded = and(&bools, &nullable(false).into_array()).unwrap();
+        assert_eq!(
+            ConstantArray::try_from(anded).unwrap().scalar(),
+            &Scalar::bool(false, Nullability::Nullable)
+        );
+        let ored = or(&nullable(false).into_array(), &bools).unwrap();
+        assert_eq!(ored.encoding().id(), Bool::ID);
+
+        // Negating the other side keeps its nulls.
+        let negated = and_not(&nullable(true).into_array(), &bools).unwrap();
+        assert!(!bool::try_from(&scalar_at(&negated, 0).unwrap()).unwrap());
+        assert!(scalar_at(&negated, 1).unwrap().is_null());
+        assert!(bool::try_from(&scalar_at(&negated, 2).unwrap()).unwrap());
+
+        // A null constant only decides an XOR.
+        let null = ConstantArray::new(Scalar::null(DType::Bool(Nullability::Nullable)), 3);
+        assert!(ConstantArray::try_from(xor(&bools, &null.clone().into_array()).unwrap()).is_ok());
+        let anded = and(&null.into_array(), &bools).unwrap();
+        assert!(scalar_at(&anded, 0).unwrap().is_null());
+        assert!(!bool::try_from(&scalar_at(&anded, 2).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn arithmetic_stays_constant() {
+        let lhs = ConstantArray::new(Scalar::from(7i32), 4).into_array();
+        let rhs = ConstantArray::new(Scalar::from(Some(10i32)), 4).into_array();
+
+        let diff = ConstantArray::try_from(sub(&lhs, &rhs).unwrap()).unwrap();
+        assert_eq!(diff.scalar(), &Scalar::from(Some(-3i32)));
+        let negated = ConstantArray::try_from(neg(&diff.into_array()).unwrap()).unwrap();
+        assert_eq!(negated.scalar(), &Scalar::from(Some(3i32)));
+
+        let overflow = ConstantArray::new(Scalar::from(i32::MIN), 4).into_array();
+        assert!(sub(&overflow, &lhs).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef as ArrowArrayRef, Date32Array, Date64Array};
+use lazy_static::lazy_static;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use serde::{Deserialize, Serialize};
+use vortex_dtype::{DType, ExtDType, ExtID, ExtMetadata, PType};
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+
+use crate::array::datetime::try_parse_unit;
+use crate::array::extension::ExtensionArray;
+use crate::compute::as_arrow::AsArrowArray;
+use crate::compute::cast::cast;
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, ArrayData, IntoArrayData};
+
+lazy_static! {
+    static ref ID: ExtID = ExtID::from(DateArray::ID);
+}
+
+/// The unit in which a date is counted from the Unix epoch.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, IntoPrimitive, TryFromPrimitive,
+)]
+#[repr(u8)]
+pub enum DateUnit {
+    Days,
+    Ms,
+}
+
+/// Calendar dates, stored as the number of days (or milliseconds) since the Unix epoch.
+pub struct DateArray {
+    ext: ExtensionArray,
+    date_unit: DateUnit,
+}
+
+impl DateArray {
+    pub const ID: &'static str = "vortex.date";
+
+    pub fn try_new(date_unit: DateUnit, dates: Array) -> VortexResult<Self> {
+        if !dates.dtype().is_int() {
+            vortex_bail!("Dates must be an integer array")
+        }
+        Ok(Self {
+            ext: ExtensionArray::new(Self::ext_dtype(date_unit), dates),
+            date_unit,
+        })
+    }
+
+    pub fn ext_dtype(date_unit: DateUnit) -> ExtDType {
+        ExtDType::new(
+            ID.clone(),
+            Some(ExtMetadata::from([date_unit.into()].as_ref())),
+        )
+    }
+
+    pub fn dtype(&self) -> &DType {
+        self.ext.dtype()
+    }
+
+    pub fn date_unit(&self) -> DateUnit {
+        self.date_unit
+    }
+
+    pub fn dates(&self) -> Array {
+        self.ext.storage()
+    }
+}
+
+impl TryFrom<&ExtensionArray> for DateArray {
+    type Error = VortexError;
+
+    fn try_from(value: &ExtensionArray) -> Result<Self, Self::Error> {
+        Self::try_new(
+            try_parse_unit(value.ext_dtype(), Self::ID)?,
+            value.storage(),
+        )
+    }
+}
+
+impl AsArrowArray for DateArray {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        Ok(match self.date_unit() {
+            DateUnit::Days => {
+                let dates = cast(&self.dates(), PType::I32.into())?.flatten_primitive()?;
+                let validity = dates.logical_validity().to_null_buffer()?;
+                Arc::new(Date32Array::new(dates.scalar_buffer::<i32>(), validity))
+            }
+            DateUnit::Ms => {
+                let dates = cast(&self.dates(), PType::I64.into())?.flatten_primitive()?;
+                let validity = dates.logical_validity().to_null_buffer()?;
+                Arc::new(Date64Array::new(dates.scalar_buffer::<i64>(), validity))
+            }
+        })
+    }
+}
+
+impl TryFrom<&Array> for DateArray {
+    type Error = VortexError;
+
+    fn try_from(value: &Array) -> Result<Self, Self::Error> {
+        Self::try_from(&ExtensionArray::try_from(value)?)
+    }
+}
+
+impl IntoArrayData for DateArray {
+    fn into_array_data(self) -> ArrayData {
+        self.ext.into_array_data()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef as ArrowArrayRef, Date32Array, Date64Array};
+    use vortex_dtype::DType;
+
+    use crate::array::constant::Constant;
+    use crate::array::datetime::{DateArray, DateUnit};
+    use crate::array::primitive::PrimitiveArray;
+    use crate::arrow::FromArrowArray;
+    use crate::compress::Compressor;
+    use crate::compute::as_arrow::as_arrow;
+    use crate::{ArrayDType, ArrayData, ArrayDef, Context, IntoArray, IntoArrayData};
+
+    #[test]
+    fn arrow_round_trip() {
+        let date32: ArrowArrayRef = Arc::new(Date32Array::from(vec![Some(19_000), None, Some(-1)]));
+        let array = ArrayData::from_arrow(date32.clone(), true).into_array();
+        assert_eq!(
+            DateArray::try_from(&array).unwrap().date_unit(),
+            DateUnit::Days
+        );
+        assert_eq!(as_arrow(&array).unwrap().as_ref(), date32.as_ref());
+
+        let date64: ArrowArrayRef = Arc::new(Date64Array::from(vec![
+            Some(1_641_600_000_000),
+            Some(0),
+            None,
+        ]));
+        let array = ArrayData::from_arrow(date64.clone(), true).into_array();
+        assert_eq!(
+            DateArray::try_from(&array).unwrap().date_unit(),
+            DateUnit::Ms
+        );
+        assert_eq!(as_arrow(&array).unwrap().as_ref(), date64.as_ref());
+    }
+
+    #[test]
+    fn compress_dates() {
+        let dates = DateArray::try_new(
+            DateUnit::Days,
+            PrimitiveArray::from(vec![19_000i32; 2048]).into_array(),
+        )
+        .unwrap()
+        .into_array_data()
+        .into_array();
+        let compressed = Compressor::new(&Context::default())
+            .compress(&dates, None)
+            .unwrap();
+        assert_eq!(compressed.dtype(), dates.dtype());
+        assert!(matches!(compressed.dtype(), DType::Extension(..)));
+
+        let compressed = DateArray::try_from(&compressed).unwrap();
+        assert_eq!(compressed.dates().encoding().id(), Constant::ID);
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use arrow_array::{
+    ArrayRef as ArrowArrayRef, DurationMicrosecondArray, DurationMillisecondArray,
+    DurationNanosecondArray, DurationSecondArray,
+};
+use lazy_static::lazy_static;
+use vortex_dtype::{DType, ExtDType, ExtID, PType};
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+
+use crate::array::datetime::{try_parse_unit, TimeUnit};
+use crate::array::extension::ExtensionArray;
+use crate::compute::as_arrow::AsArrowArray;
+use crate::compute::cast::cast;
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, ArrayData, IntoArrayData};
+
+lazy_static! {
+    static ref ID: ExtID = ExtID::from(DurationArray::ID);
+}
+
+/// Exact lengths of time, stored as a signed count of the time unit.
+pub struct DurationArray {
+    ext: ExtensionArray,
+    time_unit: TimeUnit,
+}
+
+impl DurationArray {
+    pub const ID: &'static str = "vortex.duration";
+
+    pub fn try_new(time_unit: TimeUnit, durations: Array) -> VortexResult<Self> {
+        if !durations.dtype().is_int() {
+            vortex_bail!("Durations must be an integer array")
+        }
+        Ok(Self {
+            ext: ExtensionArray::new(Self::ext_dtype(time_unit), durations),
+            time_unit,
+        })
+    }
+
+    pub fn ext_dtype(time_unit: TimeUnit) -> ExtDType {
+        ExtDType::new(ID.clone(), Some(time_unit.metadata().clone()))
+    }
+
+    pub fn dtype(&self) -> &DType {
+        self.ext.dtype()
+    }
+
+    pub fn time_unit(&self) -> TimeUnit {
+        self.time_unit
+    }
+
+    pub fn durations(&self) -> Array {
+        self.ext.storage()
+    }
+}
+
+impl TryFrom<&ExtensionArray> for DurationArray {
+    type Error = VortexError;
+
+    fn try_from(value: &ExtensionArray) -> Result<Self, Self::Error> {
+        Self::try_new(
+            try_parse_unit(value.ext_dtype(), Self::ID)?,
+            value.storage(),
+        )
+    }
+}
+
+impl AsArrowArray for DurationArray {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        let durations = cast(&self.durations(), PType::I64.into())?.flatten_primitive()?;
+        let validity = durations.logical_validity().to_null_buffer()?;
+        let buffer = durations.scalar_buffer::<i64>();
+
+        Ok(match self.time_unit() {
+            TimeUnit::Ns => Arc::new(DurationNanosecondArray::new(buffer, validity)),
+            TimeUnit::Us => Arc::new(DurationMicrosecondArray::new(buffer, validity)),
+            TimeUnit::Ms => Arc::new(DurationMillisecondArray::new(buffer, validity)),
+            TimeUnit::S => Arc::new(DurationSecondArray::new(buffer, validity)),
+        })
+    }
+}
+
+impl TryFrom<&Array> for DurationArray {
+    type Error = VortexError;
+
+    fn try_from(value: &Array) -> Result<Self, Self::Error> {
+        Self::try_from(&ExtensionArray::try_from(value)?)
+    }
+}
+
+impl IntoArrayData for DurationArray {
+    fn into_array_data(self) -> ArrayData {
+        self.ext.into_array_data()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef as ArrowArrayRef, DurationSecondArray};
+
+    use crate::array::datetime::{DurationArray, TimeUnit};
+    use crate::arrow::FromArrowArray;
+    use crate::compute::as_arrow::as_arrow;
+    use crate::{ArrayData, IntoArray};
+
+    #[test]
+    fn arrow_round_trip() {
+        let arrow: ArrowArrayRef = Arc::new(DurationSecondArray::from(vec![
+            Some(-30),
+            None,
+            Some(3_600),
+        ]));
+        let array = ArrayData::from_arrow(arrow.clone(), true).into_array();
+        assert_eq!(
+            DurationArray::try_from(&array).unwrap().time_unit(),
+            TimeUnit::S
+        );
+        assert_eq!(as_arrow(&array).unwrap().as_ref(), arrow.as_ref());
+    }
+}
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use arrow_array::types::{IntervalDayTimeType, IntervalMonthDayNanoType};
+use arrow_array::{
+    ArrayRef as ArrowArrayRef, IntervalDayTimeArray, IntervalMonthDayNanoArray,
+    IntervalYearMonthArray,
+};
+use arrow_buffer::ScalarBuffer;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use serde::{Deserialize, Serialize};
+use vortex_dtype::{DType, ExtDType, ExtID, ExtMetadata, FieldNames, PType};
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+
+use crate::array::datetime::try_parse_unit;
+use crate::array::extension::ExtensionArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::array::r#struct::StructArray;
+use crate::compute::as_arrow::AsArrowArray;
+use crate::compute::cast::cast;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, ArrayData, IntoArray, IntoArrayData};
+
+lazy_static! {
+    static ref ID: ExtID = ExtID::from(IntervalArray::ID);
+    static ref DAY_TIME_FIELDS: FieldNames = ["days".into(), "milliseconds".into()].into();
+    static ref MONTH_DAY_NANO_FIELDS: FieldNames =
+        ["months".into(), "days".into(), "nanoseconds".into()].into();
+}
+
+/// The calendar units in which an interval is measured.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, IntoPrimitive, TryFromPrimitive,
+)]
+#[repr(u8)]
+pub enum IntervalUnit {
+    /// A number of months, stored as a single integer array.
+    YearMonth,
+    /// A number of days and milliseconds, stored as a struct of `days` and `milliseconds`.
+    DayTime,
+    /// A number of months, days and nanoseconds, stored as a struct of `months`, `days` and
+    /// `nanoseconds`.
+    MonthDayNano,
+}
+
+/// Calendar intervals, whose exact length depends on the date they are applied to.
+///
+/// Multi-part intervals are stored as a struct with one field per part, so that each part can be
+/// compressed independently.
+pub struct IntervalArray {
+    ext: ExtensionArray,
+    interval_unit: IntervalUnit,
+}
+
+impl IntervalArray {
+    pub const ID: &'static str = "vortex.interval";
+
+    pub fn try_new(interval_unit: IntervalUnit, intervals: Array) -> VortexResult<Self> {
+        let fields = match interval_unit {
+            IntervalUnit::YearMonth => None,
+            IntervalUnit::DayTime => Some(&*DAY_TIME_FIELDS),
+            IntervalUnit::MonthDayNano => Some(&*MONTH_DAY_NANO_FIELDS),
+        };
+        match (fields, intervals.dtype()) {
+            (None, dtype) if dtype.is_int() => {}
+            (Some(fields), DType::Struct(st, _))
+                if st.names() == fields && st.dtypes().iter().all(|d| d.is_int()) => {}
+            (_, dtype) => vortex_bail!(
+                "Invalid storage dtype {} for {:?} intervals",
+                dtype,
+                interval_unit
+            ),
+        }
+        Ok(Self {
+            ext: ExtensionArray::new(Self::ext_dtype(interval_unit), intervals),
+            interval_unit,
+        })
+    }
+
+    /// Create day-time intervals from arrays of days and milliseconds.
+    pub fn day_time(days: Array, milliseconds: Array, validity: Validity) -> VortexResult<Self> {
+        let length = days.len();
+        Self::try_new(
+            IntervalUnit::DayTime,
+            StructArray::try_new(
+                DAY_TIME_FIELDS.clone(),
+                vec![days, milliseconds],
+                length,
+                validity,
+            )?
+            .into_array(),
+        )
+    }
+
+    /// Create month-day-nano intervals from arrays of months, days and nanoseconds.
+    pub fn month_day_nano(
+        months: Array,
+        days: Array,
+        nanoseconds: Array,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        let length = months.len();
+        Self::try_new(
+            IntervalUnit::MonthDayNano,
+            StructArray::try_new(
+                MONTH_DAY_NANO_FIELDS.clone(),
+                vec![months, days, nanoseconds],
+                length,
+                validity,
+            )?
+            .into_array(),
+        )
+    }
+
+    pub fn ext_dtype(interval_unit: IntervalUnit) -> ExtDType {
+        ExtDType::new(
+            ID.clone(),
+            Some(ExtMetadata::from([interval_unit.into()].as_ref())),
+        )
+    }
+
+    pub fn dtype(&self) -> &DType {
+        self.ext.dtype()
+    }
+
+    pub fn interval_unit(&self) -> IntervalUnit {
+        self.interval_unit
+    }
+
+    pub fn intervals(&self) -> Array {
+        self.ext.storage()
+    }
+
+    /// The parts of multi-part intervals, each flattened and cast to the given ptypes.
+    fn parts(&self, ptypes: &[PType]) -> VortexResult<Vec<PrimitiveArray>> {
+        let intervals = StructArray::try_from(self.intervals().flatten()?.into_array())?;
+        intervals
+            .children()
+            .zip_eq(ptypes)
+            .map(|(part, &ptype)| cast(&part, ptype.into())?.flatten_primitive())
+            .try_collect()
+    }
+}
+
+impl TryFrom<&ExtensionArray> for IntervalArray {
+    type Error = VortexError;
+
+    fn try_from(value: &ExtensionArray) -> Result<Self, Self::Error> {
+        Self::try_new(
+            try_parse_unit(value.ext_dtype(), Self::ID)?,
+            value.storage(),
+        )
+    }
+}
+
+impl AsArrowArray for IntervalArray {
+    /// Arrow packs the parts of each interval into a single integer.
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        let validity = self.ext.logical_validity().to_null_buffer()?;
+
+        Ok(match self.interval_unit() {
+            IntervalUnit::YearMonth => {
+                let months = cast(&self.intervals(), PType::I32.into())?.flatten_primitive()?;
+                Arc::new(IntervalYearMonthArray::new(
+                    months.scalar_buffer::<i32>(),
+                    validity,
+                ))
+            }
+            IntervalUnit::DayTime => {
+                let parts = self.parts(&[PType::I32, PType::I32])?;
+                let packed: ScalarBuffer<i64> = parts[0]
+                    .typed_data::<i32>()
+                    .iter()
+                    .zip(parts[1].typed_data::<i32>())
+                    .map(|(&d, &ms)| IntervalDayTimeType::make_value(d, ms))
+                    .collect();
+                Arc::new(IntervalDayTimeArray::new(packed, validity))
+            }
+            IntervalUnit::MonthDayNano => {
+                let parts = self.parts(&[PType::I32, PType::I32, PType::I64])?;
+                let packed: ScalarBuffer<i128> = parts[0]
+                    .typed_data::<i32>()
+                    .iter()
+                    .zip(parts[1].typed_data::<i32>())
+                    .zip(parts[2].typed_data::<i64>())
+                    .map(|((&m, &d), &ns)| IntervalMonthDayNanoType::make_value(m, d, ns))
+                    .collect();
+                Arc::new(IntervalMonthDayNanoArray::new(packed, validity))
+            }
+        })
+    }
+}
+
+impl TryFrom<&Array> for IntervalArray {
+    type Error = VortexError;
+
+    fn try_from(value: &Array) -> Result<Self, Self::Error> {
+        Self::try_from(&ExtensionArray::try_from(value)?)
+    }
+}
+
+impl IntoArrayData for IntervalArray {
+    fn into_array_data(self) -> ArrayData {
+        self.ext.into_array_data()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::types::{IntervalDayTimeType, IntervalMonthDayNanoType};
+    use arrow_array::{
+        ArrayRef as ArrowArrayRef, IntervalDayTimeArray, IntervalMonthDayNanoArray,
+        IntervalYearMonthArray,
+    };
+
+    use crate::array::datetime::{IntervalArray, IntervalUnit};
+    use crate::arrow::FromArrowArray;
+    use crate::compute::as_arrow::as_arrow;
+    use crate::compute::slice::slice;
+    use crate::{ArrayData, IntoArray};
+
+    fn round_trip(arrow: ArrowArrayRef, interval_unit: IntervalUnit) {
+        let array = ArrayData::from_arrow(arrow.clone(), true).into_array();
+        assert_eq!(
+            IntervalArray::try_from(&array).unwrap().interval_unit(),
+            interval_unit
+        );
+        assert_eq!(as_arrow(&array).unwrap().as_ref(), arrow.as_ref());
+        assert_eq!(
+            as_arrow(&slice(&array, 1, 3).unwrap()).unwrap().as_ref(),
+            arrow.slice(1, 2).as_ref()
+        );
+    }
+
+    #[test]
+    fn arrow_round_trip() {
+        round_trip(
+            Arc::new(IntervalYearMonthArray::from(vec![Some(14), None, Some(-3)])),
+            IntervalUnit::YearMonth,
+        );
+        round_trip(
+            Arc::new(IntervalDayTimeArray::from(vec![
+                Some(IntervalDayTimeType::make_value(1, -500)),
+                None,
+                Some(IntervalDayTimeType::make_value(-7, 86_399_999)),
+            ])),
+            IntervalUnit::DayTime,
+        );
+        round_trip(
+            Arc::new(IntervalMonthDayNanoArray::from(vec![
+                Some(IntervalMonthDayNanoType::make_value(1, 2, 3)),
+                Some(IntervalMonthDayNanoType::make_value(-1, 0, i64::MAX)),
+                None,
+            ])),
+            IntervalUnit::MonthDayNano,
+        );
+    }
+}
@@ -6,9 +6,9 @@ use arrow_array::{
 };
 use lazy_static::lazy_static;
 use vortex_dtype::{DType, ExtDType, ExtID, PType};
-use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_error::{vortex_bail, VortexError, VortexResult};
 
-use crate::array::datetime::TimeUnit;
+use crate::array::datetime::{try_parse_unit, TimeUnit};
 use crate::array::extension::ExtensionArray;
 use crate::compute::as_arrow::AsArrowArray;
 use crate::compute::cast::cast;
@@ -93,11 +93,6 @@ impl IntoArrayData for LocalDateTimeArray {
     }
 }
 
-fn try_parse_time_unit(ext_dtype: &ExtDType) -> VortexResult<TimeUnit> {
-    let byte: [u8; 1] = ext_dtype
-        .metadata()
-        .ok_or_else(|| vortex_err!("Missing metadata"))?
-        .as_ref()
-        .try_into()?;
-    TimeUnit::try_from(byte[0]).map_err(|_| vortex_err!("Invalid time unit in metadata"))
+pub(super) fn try_parse_time_unit(ext_dtype: &ExtDType) -> VortexResult<TimeUnit> {
+    try_parse_unit(ext_dtype, LocalDateTimeArray::ID)
 }
@@ -1,12 +1,28 @@
 use std::fmt::{Display, Formatter};
 
+use arrow_array::ArrayRef as ArrowArrayRef;
+pub use date::*;
+pub use duration::*;
+pub use interval::*;
 use lazy_static::lazy_static;
 pub use localdatetime::*;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde::{Deserialize, Serialize};
-use vortex_dtype::ExtMetadata;
+pub use time::*;
+use vortex_dtype::{ExtDType, ExtID, ExtMetadata};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_scalar::ExtScalarType;
+pub use zoneddatetime::*;
 
+use crate::array::extension::{ExtensionArray, ExtensionType};
+use crate::compute::as_arrow::AsArrowArray;
+
+mod date;
+mod duration;
+mod interval;
 mod localdatetime;
+mod time;
+mod zoneddatetime;
 
 #[derive(
     Debug,
@@ -48,6 +64,21 @@ impl TimeUnit {
     }
 }
 
+impl TryFrom<&ExtDType> for TimeUnit {
+    type Error = VortexError;
+
+    /// Parse the time unit of a local or zoned datetime extension type.
+    fn try_from(value: &ExtDType) -> VortexResult<Self> {
+        match value.id().as_ref() {
+            LocalDateTimeArray::ID => localdatetime::try_parse_time_unit(value),
+            ZonedDateTimeArray::ID => {
+                zoneddatetime::try_parse_metadata(value).map(|(unit, _)| unit)
+            }
+            _ => vortex_bail!("Extension type {} has no time unit", value.id()),
+        }
+    }
+}
+
 impl Display for TimeUnit {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -58,3 +89,54 @@ impl Display for TimeUnit {
         }
     }
 }
+
+/// Parse the single-byte unit held in the metadata of the extension type with the given id.
+pub(super) fn try_parse_unit<U: TryFrom<u8>>(ext_dtype: &ExtDType, id: &str) -> VortexResult<U> {
+    if ext_dtype.id().as_ref() != id {
+        vortex_bail!("Expected {} extension, found {}", id, ext_dtype.id());
+    }
+    let byte: [u8; 1] = ext_dtype
+        .metadata()
+        .ok_or_else(|| vortex_err!("Missing metadata"))?
+        .as_ref()
+        .try_into()?;
+    U::try_from(byte[0]).map_err(|_| vortex_err!("Invalid unit in metadata for {}", id))
+}
+
+/// The built-in temporal extension types, which convert to Arrow's temporal arrays.
+pub(crate) struct TemporalType(ExtID);
+
+impl TemporalType {
+    pub fn all() -> impl Iterator<Item = Self> {
+        [
+            LocalDateTimeArray::ID,
+            ZonedDateTimeArray::ID,
+            DateArray::ID,
+            TimeArray::ID,
+            DurationArray::ID,
+            IntervalArray::ID,
+        ]
+        .into_iter()
+        .map(|id| Self(ExtID::from(id)))
+    }
+}
+
+impl ExtScalarType for TemporalType {
+    fn id(&self) -> &ExtID {
+        &self.0
+    }
+}
+
+impl ExtensionType for TemporalType {
+    fn as_arrow(&self, array: &ExtensionArray) -> VortexResult<ArrowArrayRef> {
+        match self.0.as_ref() {
+            LocalDateTimeArray::ID => LocalDateTimeArray::try_from(array)?.as_arrow(),
+            ZonedDateTimeArray::ID => ZonedDateTimeArray::try_from(array)?.as_arrow(),
+            DateArray::ID => DateArray::try_from(array)?.as_arrow(),
+            TimeArray::ID => TimeArray::try_from(array)?.as_arrow(),
+            DurationArray::ID => DurationArray::try_from(array)?.as_arrow(),
+            IntervalArray::ID => IntervalArray::try_from(array)?.as_arrow(),
+            _ => unreachable!("Unknown temporal extension {}", self.0),
+        }
+    }
+}
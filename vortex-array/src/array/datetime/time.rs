@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use arrow_array::{
+    ArrayRef as ArrowArrayRef, Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray,
+    Time64NanosecondArray,
+};
+use lazy_static::lazy_static;
+use vortex_dtype::{DType, ExtDType, ExtID, PType};
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+
+use crate::array::datetime::{try_parse_unit, TimeUnit};
+use crate::array::extension::ExtensionArray;
+use crate::compute::as_arrow::AsArrowArray;
+use crate::compute::cast::cast;
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, ArrayData, IntoArrayData};
+
+lazy_static! {
+    static ref ID: ExtID = ExtID::from(TimeArray::ID);
+}
+
+/// Times of day, stored as the time elapsed since midnight.
+pub struct TimeArray {
+    ext: ExtensionArray,
+    time_unit: TimeUnit,
+}
+
+impl TimeArray {
+    pub const ID: &'static str = "vortex.time";
+
+    pub fn try_new(time_unit: TimeUnit, times: Array) -> VortexResult<Self> {
+        if !times.dtype().is_int() {
+            vortex_bail!("Times must be an integer array")
+        }
+        Ok(Self {
+            ext: ExtensionArray::new(Self::ext_dtype(time_unit), times),
+            time_unit,
+        })
+    }
+
+    pub fn ext_dtype(time_unit: TimeUnit) -> ExtDType {
+        ExtDType::new(ID.clone(), Some(time_unit.metadata().clone()))
+    }
+
+    pub fn dtype(&self) -> &DType {
+        self.ext.dtype()
+    }
+
+    pub fn time_unit(&self) -> TimeUnit {
+        self.time_unit
+    }
+
+    pub fn times(&self) -> Array {
+        self.ext.storage()
+    }
+}
+
+impl TryFrom<&ExtensionArray> for TimeArray {
+    type Error = VortexError;
+
+    fn try_from(value: &ExtensionArray) -> Result<Self, Self::Error> {
+        Self::try_new(
+            try_parse_unit(value.ext_dtype(), Self::ID)?,
+            value.storage(),
+        )
+    }
+}
+
+impl AsArrowArray for TimeArray {
+    /// Arrow stores seconds and milliseconds as 32-bit Time32, and finer units as 64-bit Time64.
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        let ptype = match self.time_unit() {
+            TimeUnit::S | TimeUnit::Ms => PType::I32,
+            TimeUnit::Us | TimeUnit::Ns => PType::I64,
+        };
+        let times = cast(&self.times(), ptype.into())?.flatten_primitive()?;
+        let validity = times.logical_validity().to_null_buffer()?;
+
+        Ok(match self.time_unit() {
+            TimeUnit::S => Arc::new(Time32SecondArray::new(times.scalar_buffer(), validity)),
+            TimeUnit::Ms => Arc::new(Time32MillisecondArray::new(times.scalar_buffer(), validity)),
+            TimeUnit::Us => Arc::new(Time64MicrosecondArray::new(times.scalar_buffer(), validity)),
+            TimeUnit::Ns => Arc::new(Time64NanosecondArray::new(times.scalar_buffer(), validity)),
+        })
+    }
+}
+
+impl TryFrom<&Array> for TimeArray {
+    type Error = VortexError;
+
+    fn try_from(value: &Array) -> Result<Self, Self::Error> {
+        Self::try_from(&ExtensionArray::try_from(value)?)
+    }
+}
+
+impl IntoArrayData for TimeArray {
+    fn into_array_data(self) -> ArrayData {
+        self.ext.into_array_data()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef as ArrowArrayRef, Time32MillisecondArray, Time64NanosecondArray};
+
+    use crate::array::datetime::{TimeArray, TimeUnit};
+    use crate::arrow::FromArrowArray;
+    use crate::compute::as_arrow::as_arrow;
+    use crate::{ArrayData, IntoArray};
+
+    #[test]
+    fn arrow_round_trip() {
+        let time32: ArrowArrayRef = Arc::new(Time32MillisecondArray::from(vec![
+            Some(0),
+            None,
+            Some(86_399_999),
+        ]));
+        let array = ArrayData::from_arrow(time32.clone(), true).into_array();
+        assert_eq!(
+            TimeArray::try_from(&array).unwrap().time_unit(),
+            TimeUnit::Ms
+        );
+        assert_eq!(as_arrow(&array).unwrap().as_ref(), time32.as_ref());
+
+        let time64: ArrowArrayRef = Arc::new(Time64NanosecondArray::from(vec![
+            Some(43_200_000_000_000),
+            Some(1),
+        ]));
+        let array = ArrayData::from_arrow(time64.clone(), false).into_array();
+        assert_eq!(
+            TimeArray::try_from(&array).unwrap().time_unit(),
+            TimeUnit::Ns
+        );
+        assert_eq!(as_arrow(&array).unwrap().as_ref(), time64.as_ref());
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use arrow_array::{
+    ArrayRef as ArrowArrayRef, TimestampMicrosecondArray, TimestampMillisecondArray,
+    TimestampNanosecondArray, TimestampSecondArray,
+};
+use lazy_static::lazy_static;
+use vortex_dtype::{DType, ExtDType, ExtID, ExtMetadata, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::array::datetime::TimeUnit;
+use crate::array::extension::ExtensionArray;
+use crate::compute::as_arrow::AsArrowArray;
+use crate::compute::cast::cast;
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, ArrayData, IntoArrayData};
+
+lazy_static! {
+    static ref ID: ExtID = ExtID::from(ZonedDateTimeArray::ID);
+}
+
+/// Instants in time, stored as the time since the Unix epoch in UTC, along with the timezone in
+/// which they should be displayed.
+pub struct ZonedDateTimeArray {
+    ext: ExtensionArray,
+    time_unit: TimeUnit,
+    time_zone: Arc<str>,
+}
+
+impl ZonedDateTimeArray {
+    pub const ID: &'static str = "vortex.zoneddatetime";
+
+    pub fn try_new(
+        time_unit: TimeUnit,
+        time_zone: Arc<str>,
+        timestamps: Array,
+    ) -> VortexResult<Self> {
+        if !timestamps.dtype().is_int() {
+            vortex_bail!("Timestamps must be an integer array")
+        }
+        Ok(Self {
+            ext: ExtensionArray::new(Self::ext_dtype(time_unit, &time_zone), timestamps),
+            time_unit,
+            time_zone,
+        })
+    }
+
+    /// The metadata holds the time unit, followed by the name of the timezone in UTF-8.
+    pub fn ext_dtype(time_unit: TimeUnit, time_zone: &str) -> ExtDType {
+        let mut metadata = vec![time_unit.into()];
+        metadata.extend_from_slice(time_zone.as_bytes());
+        ExtDType::new(ID.clone(), Some(ExtMetadata::from(metadata.as_slice())))
+    }
+
+    pub fn dtype(&self) -> &DType {
+        self.ext.dtype()
+    }
+
+    pub fn time_unit(&self) -> TimeUnit {
+        self.time_unit
+    }
+
+    pub fn time_zone(&self) -> &Arc<str> {
+        &self.time_zone
+    }
+
+    pub fn timestamps(&self) -> Array {
+        self.ext.storage()
+    }
+}
+
+impl TryFrom<&ExtensionArray> for ZonedDateTimeArray {
+    type Error = VortexError;
+
+    fn try_from(value: &ExtensionArray) -> Result<Self, Self::Error> {
+        let (time_unit, time_zone) = try_parse_metadata(value.ext_dtype())?;
+        Self::try_new(time_unit, time_zone, value.storage())
+    }
+}
+
+impl AsArrowArray for ZonedDateTimeArray {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        let timestamps = cast(&self.timestamps(), PType::I64.into())?.flatten_primitive()?;
+        let validity = timestamps.logical_validity().to_null_buffer()?;
+        let buffer = timestamps.scalar_buffer::<i64>();
+        let tz = self.time_zone().clone();
+
+        Ok(match self.time_unit() {
+            TimeUnit::Ns => {
+                Arc::new(TimestampNanosecondArray::new(buffer, validity).with_timezone(tz))
+            }
+            TimeUnit::Us => {
+                Arc::new(TimestampMicrosecondArray::new(buffer, validity).with_timezone(tz))
+            }
+            TimeUnit::Ms => {
+                Arc::new(TimestampMillisecondArray::new(buffer, validity).with_timezone(tz))
+            }
+            TimeUnit::S => Arc::new(TimestampSecondArray::new(buffer, validity).with_timezone(tz)),
+        })
+    }
+}
+
+impl TryFrom<&Array> for ZonedDateTimeArray {
+    type Error = VortexError;
+
+    fn try_from(value: &Array) -> Result<Self, Self::Error> {
+        Self::try_from(&ExtensionArray::try_from(value)?)
+    }
+}
+
+impl IntoArrayData for ZonedDateTimeArray {
+    fn into_array_data(self) -> ArrayData {
+        self.ext.into_array_data()
+    }
+}
+
+pub(super) fn try_parse_metadata(ext_dtype: &ExtDType) -> VortexResult<(TimeUnit, Arc<str>)> {
+    if ext_dtype.id().as_ref() != ZonedDateTimeArray::ID {
+        vortex_bail!(
+            "Expected {} extension, found {}",
+            ZonedDateTimeArray::ID,
+            ext_dtype.id()
+        );
+    }
+    let Some((&time_unit, time_zone)) = ext_dtype
+        .metadata()
+        .ok_or_else(|| vortex_err!("Missing metadata"))?
+        .as_ref()
+        .split_first()
+    else {
+        vortex_bail!("Missing time unit in metadata")
+    };
+    Ok((
+        TimeUnit::try_from(time_unit).map_err(|_| vortex_err!("Invalid time unit in metadata"))?,
+        std::str::from_utf8(time_zone)
+            .map_err(|_| vortex_err!("Invalid timezone in metadata"))?
+            .into(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef as ArrowArrayRef, TimestampMicrosecondArray};
+    use arrow_schema::{DataType, Field, TimeUnit as ArrowTimeUnit};
+    use vortex_dtype::DType;
+
+    use crate::array::datetime::{TimeUnit, ZonedDateTimeArray};
+    use crate::arrow::{FromArrowArray, FromArrowType};
+    use crate::compute::as_arrow::as_arrow;
+    use crate::{ArrayDType, ArrayData, IntoArray};
+
+    #[test]
+    fn arrow_round_trip() {
+        for tz in ["UTC", "+05:30", "America/New_York"] {
+            let arrow: ArrowArrayRef = Arc::new(
+                TimestampMicrosecondArray::from(vec![Some(1_700_000_000_000_000), None, Some(-1)])
+                    .with_timezone(tz),
+            );
+            let array = ArrayData::from_arrow(arrow.clone(), true).into_array();
+            assert_eq!(
+                array.dtype(),
+                &DType::from_arrow(&Field::new(
+                    "ts",
+                    DataType::Timestamp(ArrowTimeUnit::Microsecond, Some(tz.into())),
+                    true
+                ))
+            );
+
+            let zoned = ZonedDateTimeArray::try_from(&array).unwrap();
+            assert_eq!(zoned.time_unit(), TimeUnit::Us);
+            assert_eq!(zoned.time_zone().as_ref(), tz);
+            assert_eq!(as_arrow(&array).unwrap().as_ref(), arrow.as_ref());
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef as ArrowArrayRef, Decimal128Array};
+use arrow_buffer::ScalarBuffer;
+use itertools::Itertools;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::decimal::{from_parts, DecimalArray};
+use crate::compute::as_arrow::AsArrowArray;
+use crate::compute::as_contiguous::AsContiguousFn;
+use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
+use crate::compute::slice::{slice, SliceFn};
+use crate::compute::take::{take, TakeFn};
+use crate::compute::ArrayCompute;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, IntoArray};
+
+impl ArrayCompute for DecimalArray {
+    fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
+        Some(self)
+    }
+
+    fn as_contiguous(&self) -> Option<&dyn AsContiguousFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl AsArrowArray for DecimalArray {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        Ok(Arc::new(
+            Decimal128Array::new(
+                ScalarBuffer::from(self.unscaled_values()?),
+                self.logical_validity().to_null_buffer()?,
+            )
+            .with_precision_and_scale(self.precision(), self.scale() as i8)?,
+        ))
+    }
+}
+
+impl AsContiguousFn for DecimalArray {
+    fn as_contiguous(&self, arrays: &[Array]) -> VortexResult<Array> {
+        let decimals: Vec<Self> = arrays.iter().map(Self::try_from).try_collect()?;
+        let values = decimals
+            .iter()
+            .map(|d| d.unscaled_values())
+            .flatten_ok()
+            .try_collect()?;
+
+        let validity = if self.dtype().is_nullable() {
+            Validity::from_iter(arrays.iter().map(|a| a.with_dyn(|a| a.logical_validity())))
+        } else {
+            Validity::NonNullable
+        };
+
+        Self::from_unscaled(self.precision(), self.scale(), values, validity)
+            .map(|a| a.into_array())
+    }
+}
+
+impl ScalarAtFn for DecimalArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+        let parts: Vec<Scalar> = self
+            .unscaled()
+            .iter()
+            .map(|a| scalar_at(a, index))
+            .try_collect()?;
+        let value = match parts.as_slice() {
+            [values] => i64::try_from(values)?.into(),
+            [high, low] => from_parts(i64::try_from(high)?, u64::try_from(low)?),
+            _ => unreachable!(),
+        };
+        Ok(Scalar::decimal(
+            value,
+            self.precision(),
+            self.scale(),
+            self.dtype().nullability(),
+        ))
+    }
+}
+
+impl SliceFn for DecimalArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        self.with_unscaled(
+            self.unscaled()
+                .iter()
+                .map(|a| slice(a, start, stop))
+                .try_collect()?,
+            self.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl TakeFn for DecimalArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        self.with_unscaled(
+            self.unscaled()
+                .iter()
+                .map(|a| take(a, indices))
+                .try_collect()?,
+            self.validity().take(indices)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
@@ -0,0 +1,402 @@
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use vortex_dtype::{Nullability, PType};
+use vortex_error::vortex_bail;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::stats::ArrayStatisticsCompute;
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{impl_encoding, ArrayDType, ArrayFlatten, IntoArrayData};
+
+mod compute;
+
+impl_encoding!("vortex.decimal", Decimal);
+
+/// The dtype of the array holding the high 64 bits of each value in a wide decimal array.
+const HIGH_DTYPE: DType = DType::Primitive(PType::I64, Nullability::NonNullable);
+/// The dtype of the array holding the low 64 bits of each value in a wide decimal array.
+const LOW_DTYPE: DType = DType::Primitive(PType::U64, Nullability::NonNullable);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DecimalMetadata {
+    wide: bool,
+    validity: ValidityMetadata,
+}
+
+impl DecimalArray {
+    /// Create a decimal array from the primitive arrays holding its unscaled values, so that they
+    /// can be compressed like any other integers.
+    ///
+    /// If every value fits in 64 bits, the unscaled values are held in a single i64 array.
+    /// Otherwise, they are split into an i64 array of the high 64 bits and a u64 array of the low
+    /// 64 bits. In both cases the value of a null element is zero.
+    pub fn try_new(
+        precision: u8,
+        scale: u8,
+        unscaled: Vec<Array>,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        if precision == 0 || precision > DType::MAX_DECIMAL_PRECISION {
+            vortex_bail!(
+                "Decimal precision must be between 1 and {}, got {}",
+                DType::MAX_DECIMAL_PRECISION,
+                precision
+            );
+        }
+        if scale > precision {
+            vortex_bail!(
+                "Decimal scale {} is greater than its precision {}",
+                scale,
+                precision
+            );
+        }
+
+        let wide = match unscaled.iter().map(|a| a.dtype()).collect_vec().as_slice() {
+            [dtype] if *dtype == &HIGH_DTYPE => false,
+            [high, low] if *high == &HIGH_DTYPE && *low == &LOW_DTYPE => true,
+            dtypes => vortex_bail!(
+                "Expected unscaled decimal values as i64, or as high i64 and low u64 parts, got {}",
+                dtypes.iter().join(", ")
+            ),
+        };
+        let length = unscaled[0].len();
+        if unscaled.iter().any(|a| a.len() != length) {
+            vortex_bail!(
+                "Expected all unscaled decimal parts to have length {}",
+                length
+            );
+        }
+
+        let validity_metadata = validity.to_metadata(length)?;
+        let nullability = validity.nullability();
+        let mut children = unscaled
+            .into_iter()
+            .map(IntoArrayData::into_array_data)
+            .collect_vec();
+        children.extend(validity.into_array_data());
+
+        Self::try_from_parts(
+            DType::Decimal(precision, scale, nullability),
+            DecimalMetadata {
+                wide,
+                validity: validity_metadata,
+            },
+            children.into(),
+            StatsSet::new(),
+        )
+    }
+
+    /// Create a decimal array from its unscaled values, using the narrowest layout that fits them.
+    pub fn from_unscaled(
+        precision: u8,
+        scale: u8,
+        values: Vec<i128>,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        // Any 128-bit value fits in a precision greater than 38.
+        if let Some(max) = 10i128.checked_pow(precision as u32) {
+            if let Some(v) = values
+                .iter()
+                .find(|v| v.unsigned_abs() >= max.unsigned_abs())
+            {
+                vortex_bail!(
+                    "Unscaled value {} exceeds decimal precision {}",
+                    v,
+                    precision
+                );
+            }
+        }
+
+        let unscaled = if values.iter().all(|&v| i64::try_from(v).is_ok()) {
+            vec![
+                PrimitiveArray::from(values.into_iter().map(|v| v as i64).collect_vec())
+                    .into_array(),
+            ]
+        } else {
+            vec![
+                PrimitiveArray::from(values.iter().map(|&v| (v >> 64) as i64).collect_vec())
+                    .into_array(),
+                PrimitiveArray::from(values.iter().map(|&v| v as u64).collect_vec()).into_array(),
+            ]
+        };
+        Self::try_new(precision, scale, unscaled, validity)
+    }
+
+    pub fn precision(&self) -> u8 {
+        let DType::Decimal(precision, ..) = self.dtype() else {
+            unreachable!()
+        };
+        *precision
+    }
+
+    pub fn scale(&self) -> u8 {
+        let DType::Decimal(_, scale, _) = self.dtype() else {
+            unreachable!()
+        };
+        *scale
+    }
+
+    /// Whether the unscaled values are split into high and low 64 bits.
+    pub fn is_wide(&self) -> bool {
+        self.metadata().wide
+    }
+
+    /// The arrays holding the unscaled values, see [DecimalArray::try_new].
+    pub fn unscaled(&self) -> Vec<Array> {
+        if self.is_wide() {
+            vec![
+                self.array()
+                    .child(0, &HIGH_DTYPE)
+                    .expect("Missing high bits"),
+                self.array().child(1, &LOW_DTYPE).expect("Missing low bits"),
+            ]
+        } else {
+            vec![self.array().child(0, &HIGH_DTYPE).expect("Missing values")]
+        }
+    }
+
+    /// Read the unscaled values into memory.
+    pub fn unscaled_values(&self) -> VortexResult<Vec<i128>> {
+        let mut unscaled = self.unscaled().into_iter();
+        let high = unscaled
+            .next()
+            .expect("Missing values")
+            .flatten_primitive()?;
+        Ok(match unscaled.next() {
+            None => high
+                .typed_data::<i64>()
+                .iter()
+                .map(|&v| v as i128)
+                .collect(),
+            Some(low) => high
+                .typed_data::<i64>()
+                .iter()
+                .zip(low.flatten_primitive()?.typed_data::<u64>())
+                .map(|(&high, &low)| from_parts(high, low))
+                .collect(),
+        })
+    }
+
+    pub fn validity(&self) -> Validity {
+        let nchildren = if self.is_wide() { 2 } else { 1 };
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(nchildren, &Validity::DTYPE))
+    }
+
+    /// Create a decimal array of the same precision and scale from new unscaled parts.
+    fn with_unscaled(&self, unscaled: Vec<Array>, validity: Validity) -> VortexResult<Self> {
+        Self::try_new(self.precision(), self.scale(), unscaled, validity)
+    }
+}
+
+/// Combine the high and low 64 bits of a wide unscaled value.
+fn from_parts(high: i64, low: u64) -> i128 {
+    ((high as i128) << 64) | low as i128
+}
+
+impl ArrayFlatten for DecimalArray {
+    fn flatten(self) -> VortexResult<Flattened> {
+        Ok(Flattened::Decimal(
+            self.with_unscaled(
+                self.unscaled()
+                    .into_iter()
+                    .map(|a| a.flatten_primitive().map(IntoArray::into_array))
+                    .try_collect()?,
+                self.validity(),
+            )?,
+        ))
+    }
+}
+
+impl ArrayTrait for DecimalArray {
+    fn len(&self) -> usize {
+        self.unscaled()[0].len()
+    }
+}
+
+impl ArrayValidity for DecimalArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for DecimalArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        match self.unscaled().as_slice() {
+            [values] => visitor.visit_child("values", values)?,
+            [high, low] => {
+                visitor.visit_child("high", high)?;
+                visitor.visit_child("low", low)?;
+            }
+            _ => unreachable!(),
+        }
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for DecimalArray {}
+
+impl EncodingCompression for DecimalEncoding {}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef as ArrowArrayRef, Decimal128Array, Decimal256Array};
+    use arrow_buffer::i256;
+    use arrow_schema::{DataType, Field, Schema};
+    use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::array::constant::Constant;
+    use crate::array::decimal::DecimalArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::arrow::{FromArrowArray, TryFromArrowArray, TryFromArrowType};
+    use crate::compress::Compressor;
+    use crate::compute::as_arrow::as_arrow;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::slice::slice;
+    use crate::compute::take::take;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, ArrayData, ArrayDef, Context, IntoArray};
+
+    #[test]
+    fn narrow_and_wide() {
+        let narrow =
+            DecimalArray::from_unscaled(10, 2, vec![12345, -1, 0], Validity::NonNullable).unwrap();
+        assert!(!narrow.is_wide());
+        assert_eq!(
+            narrow.dtype(),
+            &DType::Decimal(10, 2, Nullability::NonNullable)
+        );
+
+        let big = i64::MAX as i128 * 1000;
+        let wide = DecimalArray::from_unscaled(
+            38,
+            4,
+            vec![big, -big, 7],
+            Validity::from(vec![true, true, false]),
+        )
+        .unwrap();
+        assert!(wide.is_wide());
+        assert_eq!(wide.unscaled_values().unwrap()[..2], [big, -big]);
+
+        let wide = wide.into_array();
+        assert_eq!(
+            scalar_at(&wide, 1).unwrap(),
+            Scalar::decimal(-big, 38, 4, Nullability::Nullable)
+        );
+        assert!(scalar_at(&wide, 2).unwrap().is_null());
+
+        let taken = DecimalArray::try_from(
+            take(
+                &slice(&wide, 1, 3).unwrap(),
+                &PrimitiveArray::from(vec![1u32, 0]).into_array(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(scalar_at(taken.array(), 0).unwrap().is_null());
+        assert_eq!(taken.unscaled_values().unwrap()[1], -big);
+    }
+
+    #[test]
+    fn arrow_round_trip() {
+        let arrow: ArrowArrayRef = Arc::new(
+            Decimal128Array::from(vec![Some(12345), None, Some(i128::MAX / 10)])
+                .with_precision_and_scale(38, 2)
+                .unwrap(),
+        );
+        let array = ArrayData::from_arrow(arrow.clone(), true).into_array();
+        assert_eq!(array.dtype(), &DType::Decimal(38, 2, Nullability::Nullable));
+        assert_eq!(as_arrow(&array).unwrap().as_ref(), arrow.as_ref());
+
+        // Decimal256 values that fit in a Vortex decimal import at their own precision.
+        let arrow: ArrowArrayRef = Arc::new(
+            Decimal256Array::from(vec![i256::from_i128(-7), i256::from_i128(1)])
+                .with_precision_and_scale(20, 3)
+                .unwrap(),
+        );
+        let array = ArrayData::from_arrow(arrow, false).into_array();
+        assert_eq!(
+            array.dtype(),
+            &DType::Decimal(20, 3, Nullability::NonNullable)
+        );
+        assert_eq!(
+            DecimalArray::try_from(array)
+                .unwrap()
+                .unscaled_values()
+                .unwrap(),
+            vec![-7, 1]
+        );
+    }
+
+    #[test]
+    fn arrow_wide_decimal() {
+        // Vortex decimals can't hold Decimal256 precisions beyond 38, so they're rejected rather
+        // than narrowed.
+        for (precision, scale) in [(50, 3), (76, 50), (76, 0)] {
+            let field = Field::new("d", DataType::Decimal256(precision, scale), false);
+            assert!(DType::try_from_arrow(&field).is_err());
+
+            let arrow: ArrowArrayRef = Arc::new(
+                Decimal256Array::from(vec![i256::from_i128(1)])
+                    .with_precision_and_scale(precision, scale)
+                    .unwrap(),
+            );
+            assert!(ArrayData::try_from_arrow(arrow, false).is_err());
+        }
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "d",
+            DataType::Decimal256(40, 2),
+            true,
+        )]));
+        assert!(DType::try_from_arrow(schema).is_err());
+    }
+
+    #[test]
+    fn arrow_negative_scale() {
+        let arrow: ArrowArrayRef = Arc::new(
+            Decimal128Array::from(vec![Some(12), None])
+                .with_precision_and_scale(5, -2)
+                .unwrap(),
+        );
+        let array = ArrayData::from_arrow(arrow, true).into_array();
+        assert_eq!(array.dtype(), &DType::Decimal(7, 0, Nullability::Nullable));
+        assert_eq!(
+            scalar_at(&array, 0).unwrap(),
+            Scalar::decimal(1200, 7, 0, Nullability::Nullable)
+        );
+
+        let arrow: ArrowArrayRef = Arc::new(
+            Decimal128Array::from(vec![10i128.pow(37)])
+                .with_precision_and_scale(38, -2)
+                .unwrap(),
+        );
+        assert!(ArrayData::try_from_arrow(arrow, false).is_err());
+    }
+
+    #[test]
+    fn compress_unscaled() {
+        let array =
+            DecimalArray::from_unscaled(12, 2, vec![500; 2048], Validity::NonNullable).unwrap();
+        let compressed = Compressor::new(&Context::default())
+            .compress(array.array(), None)
+            .unwrap();
+        let unscaled = DecimalArray::try_from(compressed).unwrap().unscaled();
+        assert_eq!(unscaled[0].encoding().id(), Constant::ID);
+    }
+
+    #[test]
+    fn precision_overflow() {
+        assert!(DecimalArray::from_unscaled(3, 0, vec![1000], Validity::NonNullable).is_err());
+        assert!(DecimalArray::from_unscaled(3, 4, vec![1], Validity::NonNullable).is_err());
+    }
+}
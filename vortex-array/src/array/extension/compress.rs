@@ -0,0 +1,48 @@
+use vortex_error::VortexResult;
+
+use crate::array::extension::{find_extension_type, Extension, ExtensionArray, ExtensionEncoding};
+use crate::compress::{CompressConfig, Compressor, EncodingCompression};
+use crate::{Array, ArrayDef, IntoArray};
+
+impl EncodingCompression for ExtensionEncoding {
+    fn cost(&self) -> u8 {
+        0
+    }
+
+    fn can_compress(
+        &self,
+        array: &Array,
+        _config: &CompressConfig,
+    ) -> Option<&dyn EncodingCompression> {
+        (array.encoding().id() == Extension::ID).then_some(self)
+    }
+
+    /// Extension types without a dedicated encoding are compressed through their storage.
+    fn compress(
+        &self,
+        array: &Array,
+        like: Option<&Array>,
+        ctx: Compressor,
+    ) -> VortexResult<Array> {
+        let ext = ExtensionArray::try_from(array)?;
+        let ext_like = like.map(|l| ExtensionArray::try_from(l).unwrap());
+        let storage = ext.storage();
+        let storage_like = ext_like.as_ref().map(|l| l.storage());
+        let ctx = ctx.named("storage");
+
+        let preferred = find_extension_type(ext.id())
+            .and_then(|ext_type| ext_type.preferred_encoding())
+            .and_then(|encoding| encoding.compression().can_compress(&storage, ctx.options()));
+        let compressed = match preferred {
+            Some(compression) => compression.compress(
+                &storage,
+                storage_like
+                    .as_ref()
+                    .filter(|l| l.encoding().id() == compression.id()),
+                ctx.for_encoding(compression),
+            )?,
+            None => ctx.compress(&storage, storage_like.as_ref())?,
+        };
+        Ok(ExtensionArray::new(ext.ext_dtype().clone(), compressed).into_array())
+    }
+}
@@ -1,12 +1,13 @@
 use arrow_array::ArrayRef as ArrowArrayRef;
+use vortex_dtype::DType;
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
-use crate::array::datetime::LocalDateTimeArray;
-use crate::array::extension::ExtensionArray;
+use crate::array::extension::{find_extension_type, ExtensionArray};
 use crate::compute::as_arrow::AsArrowArray;
 use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
 use crate::compute::cast::CastFn;
+use crate::compute::filter::{filter, FilterFn};
 use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
 use crate::compute::slice::{slice, SliceFn};
 use crate::compute::take::{take, TakeFn};
@@ -23,10 +24,11 @@ impl ArrayCompute for ExtensionArray {
     }
 
     fn cast(&self) -> Option<&dyn CastFn> {
-        // It's not possible to cast an extension array to another type.
-        // TODO(ngates): we should allow some extension arrays to implement a callback
-        //  to support this
-        None
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
     }
 
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
@@ -43,13 +45,21 @@ impl ArrayCompute for ExtensionArray {
 }
 
 impl AsArrowArray for ExtensionArray {
-    /// To support full compatability with Arrow, we hard-code the conversion of our datetime
-    /// arrays to Arrow's Timestamp arrays here. For all other extension arrays, we return an
-    /// Arrow extension array with the same definition.
+    /// Extension arrays are converted to Arrow by their registered [ExtensionType], which for
+    /// our datetime arrays produces Arrow's temporal arrays.
     fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
-        match self.id().as_ref() {
-            "vortex.localdatetime" => LocalDateTimeArray::try_from(self)?.as_arrow(),
-            _ => vortex_bail!("Arrow extension arrays not yet supported"),
+        match find_extension_type(self.id()) {
+            Some(ext_type) => ext_type.as_arrow(self),
+            None => vortex_bail!("No extension type registered for {}", self.id()),
+        }
+    }
+}
+
+impl CastFn for ExtensionArray {
+    fn cast(&self, dtype: &DType) -> VortexResult<Array> {
+        match find_extension_type(self.id()) {
+            Some(ext_type) => ext_type.cast(self, dtype),
+            None => vortex_bail!("No extension type registered for {}", self.id()),
         }
     }
 }
@@ -65,6 +75,12 @@ impl AsContiguousFn for ExtensionArray {
     }
 }
 
+impl FilterFn for ExtensionArray {
+    fn filter(&self, mask: &Array) -> VortexResult<Array> {
+        Ok(Self::new(self.ext_dtype().clone(), filter(&self.storage(), mask)?).into_array())
+    }
+}
+
 impl ScalarAtFn for ExtensionArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         Ok(Scalar::extension(
@@ -1,5 +1,8 @@
+mod compress;
 mod compute;
+mod registry;
 
+pub use registry::*;
 use serde::{Deserialize, Serialize};
 use vortex_dtype::{ExtDType, ExtID};
 
@@ -34,7 +37,6 @@ impl ExtensionArray {
             .expect("Missing storage array")
     }
 
-    #[allow(dead_code)]
     #[inline]
     pub fn id(&self) -> &ExtID {
         self.ext_dtype().id()
@@ -80,5 +82,3 @@ impl ArrayTrait for ExtensionArray {
         self.storage().len()
     }
 }
-
-impl EncodingCompression for ExtensionEncoding {}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use arrow_array::ArrayRef as ArrowArrayRef;
+use lazy_static::lazy_static;
+use vortex_dtype::{DType, ExtID};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::{register_ext_scalar_type, ExtScalarType};
+
+use crate::array::datetime::TemporalType;
+use crate::array::extension::ExtensionArray;
+use crate::compute::cast::cast;
+use crate::encoding::EncodingRef;
+use crate::{Array, ArrayDType};
+
+/// The behavior of an extension type, which lets it convert, cast and compress like a built-in
+/// type.
+///
+/// Extension types are registered once with [register_extension_type], after which every
+/// [ExtensionArray] and extension scalar of that id dispatches through the registered hooks.
+pub trait ExtensionType: ExtScalarType {
+    /// Convert an array of this extension type to Arrow.
+    fn as_arrow(&self, array: &ExtensionArray) -> VortexResult<ArrowArrayRef> {
+        vortex_bail!(NotImplemented: "as_arrow", array.ext_dtype().id())
+    }
+
+    /// Cast an array of this extension type to another dtype.
+    ///
+    /// By default, arrays can only be cast to the dtype of their storage.
+    fn cast(&self, array: &ExtensionArray, dtype: &DType) -> VortexResult<Array> {
+        let storage = array.storage();
+        if !storage.dtype().eq_ignore_nullability(dtype) {
+            vortex_bail!("Can't cast {} to {}", array.dtype(), dtype);
+        }
+        cast(&storage, dtype)
+    }
+
+    /// The encoding to compress the storage with, instead of sampling every encoding.
+    ///
+    /// The preferred encoding is only used when it can compress the storage.
+    fn preferred_encoding(&self) -> Option<EncodingRef> {
+        None
+    }
+}
+
+pub type ExtensionTypeRef = Arc<dyn ExtensionType>;
+
+lazy_static! {
+    static ref EXTENSION_TYPES: RwLock<HashMap<ExtID, ExtensionTypeRef>> = {
+        let mut types = HashMap::new();
+        for ext_type in TemporalType::all() {
+            let ext_type = Arc::new(ext_type);
+            register_ext_scalar_type(ext_type.clone());
+            types.insert(ext_type.id().clone(), ext_type as ExtensionTypeRef);
+        }
+        RwLock::new(types)
+    };
+}
+
+/// Register an extension type, replacing any previous registration for the same id.
+///
+/// This also registers the scalar behavior of the type with vortex-scalar.
+pub fn register_extension_type<T: ExtensionType + 'static>(ext_type: T) {
+    let ext_type = Arc::new(ext_type);
+    register_ext_scalar_type(ext_type.clone());
+    EXTENSION_TYPES
+        .write()
+        .expect("extension registry poisoned")
+        .insert(ext_type.id().clone(), ext_type);
+}
+
+pub fn find_extension_type(id: &ExtID) -> Option<ExtensionTypeRef> {
+    EXTENSION_TYPES
+        .read()
+        .expect("extension registry poisoned")
+        .get(id)
+        .cloned()
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::{Formatter, Result as FmtResult};
+    use std::sync::Arc;
+
+    use arrow_array::{Array as ArrowArray, ArrayRef as ArrowArrayRef, Decimal128Array};
+    use vortex_dtype::{DType, ExtDType, ExtID, PType};
+    use vortex_error::VortexResult;
+    use vortex_scalar::{ExtScalar, ExtScalarType};
+
+    use crate::array::extension::{register_extension_type, ExtensionArray, ExtensionType};
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::as_arrow::as_arrow;
+    use crate::compute::cast::cast;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::validity::ArrayValidity;
+    use crate::IntoArray;
+
+    /// Amounts of money, stored as an integer number of cents.
+    struct Money(ExtID);
+
+    impl ExtScalarType for Money {
+        fn id(&self) -> &ExtID {
+            &self.0
+        }
+
+        fn fmt_scalar(&self, scalar: &ExtScalar, f: &mut Formatter<'_>) -> FmtResult {
+            let cents = i64::try_from(scalar.value().as_pvalue().unwrap().unwrap()).unwrap();
+            write!(f, "${}.{:02}", cents / 100, cents % 100)
+        }
+    }
+
+    impl ExtensionType for Money {
+        fn as_arrow(&self, array: &ExtensionArray) -> VortexResult<ArrowArrayRef> {
+            let cents = array.storage().flatten_primitive()?;
+            Ok(Arc::new(
+                Decimal128Array::new(
+                    cents
+                        .typed_data::<i64>()
+                        .iter()
+                        .map(|&c| c as i128)
+                        .collect(),
+                    cents.logical_validity().to_null_buffer()?,
+                )
+                .with_precision_and_scale(18, 2)?,
+            ))
+        }
+    }
+
+    #[test]
+    fn registered_money() {
+        let ext_dtype = ExtDType::new("test.money".into(), None);
+        register_extension_type(Money(ext_dtype.id().clone()));
+
+        let money = ExtensionArray::new(
+            ext_dtype,
+            PrimitiveArray::from_nullable_vec(vec![Some(1234i64), None, Some(-5)]).into_array(),
+        )
+        .into_array();
+        assert_eq!(scalar_at(&money, 0).unwrap().to_string(), "$12.34");
+        assert_eq!(scalar_at(&money, 1).unwrap().to_string(), "null");
+
+        let arrow = as_arrow(&money).unwrap();
+        let decimals = arrow.as_any().downcast_ref::<Decimal128Array>().unwrap();
+        assert_eq!(decimals.value_as_string(0), "12.34");
+        assert!(decimals.is_null(1));
+
+        // Without a custom cast, money can only be cast to its storage.
+        let cents = cast(&money, &DType::Primitive(PType::I64, true.into())).unwrap();
+        assert_eq!(
+            cents.flatten_primitive().unwrap().typed_data::<i64>(),
+            &[1234, 0, -5]
+        );
+        assert!(cast(&money, &PType::F64.into()).is_err());
+    }
+}
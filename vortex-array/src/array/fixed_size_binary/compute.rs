@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef as ArrowArrayRef, FixedSizeBinaryArray as ArrowFixedSizeBinaryArray};
+use arrow_buffer::Buffer as ArrowBuffer;
+use itertools::Itertools;
+use vortex_dtype::PType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::{Scalar, ScalarValue};
+
+use crate::array::fixed_size_binary::FixedSizeBinaryArray;
+use crate::compute::as_arrow::AsArrowArray;
+use crate::compute::as_contiguous::AsContiguousFn;
+use crate::compute::cast::cast;
+use crate::compute::scalar_at::ScalarAtFn;
+use crate::compute::slice::SliceFn;
+use crate::compute::take::TakeFn;
+use crate::compute::ArrayCompute;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray};
+
+impl ArrayCompute for FixedSizeBinaryArray {
+    fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
+        Some(self)
+    }
+
+    fn as_contiguous(&self) -> Option<&dyn AsContiguousFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl AsArrowArray for FixedSizeBinaryArray {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        // Arrow derives the length from the bytes, which it can't do for zero-width values.
+        if self.byte_width() == 0 {
+            return Ok(Arc::new(
+                ArrowFixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                    (0..self.len()).map(|i| self.is_valid(i).then_some([0u8; 0])),
+                    0,
+                )?,
+            ));
+        }
+        Ok(Arc::new(ArrowFixedSizeBinaryArray::try_new(
+            self.byte_width() as i32,
+            ArrowBuffer::from(self.bytes()),
+            self.logical_validity().to_null_buffer()?,
+        )?))
+    }
+}
+
+impl AsContiguousFn for FixedSizeBinaryArray {
+    fn as_contiguous(&self, arrays: &[Array]) -> VortexResult<Array> {
+        let arrays: Vec<Self> = arrays.iter().map(Self::try_from).try_collect()?;
+        let bytes = arrays
+            .iter()
+            .flat_map(|a| a.bytes().as_slice().iter().copied())
+            .collect_vec();
+
+        let validity = if self.dtype().is_nullable() {
+            Validity::from_iter(arrays.iter().map(|a| a.logical_validity()))
+        } else {
+            Validity::NonNullable
+        };
+
+        Self::try_new(
+            self.byte_width(),
+            bytes.into(),
+            arrays.iter().map(|a| a.len()).sum(),
+            validity,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl ScalarAtFn for FixedSizeBinaryArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+        Ok(Scalar::new(
+            self.dtype().clone(),
+            ScalarValue::Buffer(self.bytes_at(index)),
+        ))
+    }
+}
+
+impl SliceFn for FixedSizeBinaryArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        let width = self.byte_width() as usize;
+        Self::try_new(
+            self.byte_width(),
+            self.bytes().slice(start * width..stop * width),
+            stop - start,
+            self.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl TakeFn for FixedSizeBinaryArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let indices_u64 = cast(indices, PType::U64.into())?.flatten_primitive()?;
+        let bytes = self.bytes().as_slice();
+        let width = self.byte_width() as usize;
+
+        let mut taken = Vec::with_capacity(indices.len() * width);
+        for &idx in indices_u64.typed_data::<u64>() {
+            let idx = idx as usize;
+            if idx >= self.len() {
+                vortex_bail!(OutOfBounds: idx, 0, self.len());
+            }
+            taken.extend_from_slice(&bytes[idx * width..(idx + 1) * width]);
+        }
+
+        Self::try_new(
+            self.byte_width(),
+            taken.into(),
+            indices.len(),
+            self.validity().take(indices)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
@@ -0,0 +1,184 @@
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use vortex_buffer::Buffer;
+use vortex_error::vortex_bail;
+
+use crate::stats::ArrayStatisticsCompute;
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{impl_encoding, ArrayDType, ArrayFlatten};
+
+mod compute;
+
+impl_encoding!("vortex.fixed_size_binary", FixedSizeBinary);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FixedSizeBinaryMetadata {
+    length: usize,
+    validity: ValidityMetadata,
+}
+
+impl FixedSizeBinaryArray {
+    /// Create an array of `length` values of `byte_width` bytes each, stored back to back in
+    /// a single buffer.
+    pub fn try_new(
+        byte_width: u32,
+        bytes: Buffer,
+        length: usize,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        if bytes.len() != length * byte_width as usize {
+            vortex_bail!(
+                "Expected {} bytes for {} values of width {}, got {}",
+                length * byte_width as usize,
+                length,
+                byte_width,
+                bytes.len()
+            );
+        }
+
+        Ok(Self {
+            typed: TypedArray::try_from_parts(
+                DType::FixedSizeBinary(byte_width, validity.nullability()),
+                FixedSizeBinaryMetadata {
+                    length,
+                    validity: validity.to_metadata(length)?,
+                },
+                Some(bytes),
+                validity.into_array_data().into_iter().collect_vec().into(),
+                StatsSet::new(),
+            )?,
+        })
+    }
+
+    pub fn byte_width(&self) -> u32 {
+        let DType::FixedSizeBinary(byte_width, _) = self.dtype() else {
+            unreachable!()
+        };
+        *byte_width
+    }
+
+    pub fn bytes(&self) -> &Buffer {
+        self.array().buffer().expect("missing bytes")
+    }
+
+    /// The bytes of the value at the given index.
+    pub fn bytes_at(&self, index: usize) -> Buffer {
+        let width = self.byte_width() as usize;
+        self.bytes().slice(index * width..(index + 1) * width)
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(0, &Validity::DTYPE))
+    }
+}
+
+impl ArrayFlatten for FixedSizeBinaryArray {
+    fn flatten(self) -> VortexResult<Flattened> {
+        Ok(Flattened::FixedSizeBinary(self))
+    }
+}
+
+impl ArrayTrait for FixedSizeBinaryArray {
+    fn len(&self) -> usize {
+        self.metadata().length
+    }
+}
+
+impl ArrayValidity for FixedSizeBinaryArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for FixedSizeBinaryArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_buffer(self.bytes())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for FixedSizeBinaryArray {}
+
+impl EncodingCompression for FixedSizeBinaryEncoding {}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{
+        Array as _, ArrayRef as ArrowArrayRef, FixedSizeBinaryArray as ArrowFixedSizeBinaryArray,
+    };
+    use vortex_buffer::Buffer;
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::fixed_size_binary::FixedSizeBinaryArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::arrow::FromArrowArray;
+    use crate::compute::as_arrow::as_arrow;
+    use crate::compute::as_contiguous::as_contiguous;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::slice::slice;
+    use crate::compute::take::take;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, ArrayData, IntoArray};
+
+    #[test]
+    fn zero_width() {
+        // Zero-width values have no bytes, so the length has to come from the array itself.
+        let array = FixedSizeBinaryArray::try_new(
+            0,
+            Buffer::from(Vec::<u8>::new()),
+            3,
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(array.len(), 3);
+        assert_eq!(
+            Buffer::try_from(&scalar_at(&array, 0).unwrap()).unwrap(),
+            Buffer::from(Vec::<u8>::new())
+        );
+        assert!(scalar_at(&array, 1).unwrap().is_null());
+
+        let taken = take(
+            &slice(&array, 1, 3).unwrap(),
+            &PrimitiveArray::from(vec![1u32, 0, 1, 1]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(taken.len(), 4);
+        assert!(scalar_at(&taken, 1).unwrap().is_null());
+        assert!(!scalar_at(&taken, 3).unwrap().is_null());
+        assert_eq!(as_contiguous(&[array.clone(), taken]).unwrap().len(), 7);
+
+        let arrow: ArrowArrayRef = Arc::new(
+            ArrowFixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                vec![Some([0u8; 0]), None, Some([])].into_iter(),
+                0,
+            )
+            .unwrap(),
+        );
+        assert_eq!(as_arrow(&array).unwrap().as_ref(), arrow.as_ref());
+        let imported = ArrayData::from_arrow(arrow, true).into_array();
+        assert_eq!(
+            imported.dtype(),
+            &DType::FixedSizeBinary(0, Nullability::Nullable)
+        );
+        assert_eq!(imported.len(), 3);
+        assert_eq!(as_arrow(&imported).unwrap().null_count(), 1);
+
+        assert!(FixedSizeBinaryArray::try_new(
+            0,
+            Buffer::from(vec![1u8]),
+            1,
+            Validity::NonNullable
+        )
+        .is_err());
+    }
+}
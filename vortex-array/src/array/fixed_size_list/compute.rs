@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef as ArrowArrayRef, FixedSizeListArray as ArrowFixedSizeListArray};
+use arrow_schema::Field;
+use itertools::Itertools;
+use vortex_dtype::PType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::{Scalar, ScalarValue};
+
+use crate::array::fixed_size_list::FixedSizeListArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::as_arrow::{as_arrow, AsArrowArray};
+use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
+use crate::compute::cast::cast;
+use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
+use crate::compute::slice::{slice, SliceFn};
+use crate::compute::take::{take, TakeFn};
+use crate::compute::ArrayCompute;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray};
+
+impl ArrayCompute for FixedSizeListArray {
+    fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
+        Some(self)
+    }
+
+    fn as_contiguous(&self) -> Option<&dyn AsContiguousFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl AsArrowArray for FixedSizeListArray {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        let values = as_arrow(&self.elements())?;
+        let field = Arc::new(Field::new_list_field(
+            values.data_type().clone(),
+            self.element_dtype().is_nullable(),
+        ));
+        Ok(Arc::new(ArrowFixedSizeListArray::try_new(
+            field,
+            self.list_size() as i32,
+            values,
+            self.logical_validity().to_null_buffer()?,
+        )?))
+    }
+}
+
+impl AsContiguousFn for FixedSizeListArray {
+    fn as_contiguous(&self, arrays: &[Array]) -> VortexResult<Array> {
+        let lists: Vec<Self> = arrays.iter().map(Self::try_from).try_collect()?;
+        let elements = as_contiguous(&lists.iter().map(|l| l.elements()).collect_vec())?;
+
+        let validity = if self.dtype().is_nullable() {
+            Validity::from_iter(lists.iter().map(|l| l.logical_validity()))
+        } else {
+            Validity::NonNullable
+        };
+
+        Self::try_new(
+            elements,
+            self.list_size(),
+            lists.iter().map(|l| l.len()).sum(),
+            validity,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl ScalarAtFn for FixedSizeListArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+        let elements = self.elements_at(index)?;
+        let values: Vec<ScalarValue> = (0..elements.len())
+            .map(|i| scalar_at(&elements, i).map(|s| s.into_value()))
+            .try_collect()?;
+        Ok(Scalar::new(
+            self.dtype().clone(),
+            ScalarValue::List(values.into()),
+        ))
+    }
+}
+
+impl SliceFn for FixedSizeListArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        let size = self.list_size() as usize;
+        Self::try_new(
+            slice(&self.elements(), start * size, stop * size)?,
+            self.list_size(),
+            stop - start,
+            self.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl TakeFn for FixedSizeListArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let indices_u64 = cast(indices, PType::U64.into())?.flatten_primitive()?;
+        let size = self.list_size() as u64;
+
+        let mut element_indices = Vec::with_capacity(indices.len() * size as usize);
+        for &idx in indices_u64.typed_data::<u64>() {
+            if idx as usize >= self.len() {
+                vortex_bail!(OutOfBounds: idx as usize, 0, self.len());
+            }
+            element_indices.extend(idx * size..(idx + 1) * size);
+        }
+
+        Self::try_new(
+            take(
+                &self.elements(),
+                &PrimitiveArray::from(element_indices).into_array(),
+            )?,
+            self.list_size(),
+            indices.len(),
+            self.validity().take(indices)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
@@ -0,0 +1,226 @@
+use serde::{Deserialize, Serialize};
+use vortex_error::vortex_bail;
+
+use crate::compute::slice::slice;
+use crate::stats::ArrayStatisticsCompute;
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{impl_encoding, ArrayDType, ArrayFlatten, IntoArrayData};
+
+mod compute;
+
+impl_encoding!("vortex.fixed_size_list", FixedSizeList);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FixedSizeListMetadata {
+    length: usize,
+    validity: ValidityMetadata,
+}
+
+impl FixedSizeListArray {
+    /// Create an array of `length` lists of `list_size` elements each, where the elements of the
+    /// list at index `i` are `elements[i * list_size..(i + 1) * list_size]`.
+    pub fn try_new(
+        elements: Array,
+        list_size: u32,
+        length: usize,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        if elements.len() != length * list_size as usize {
+            vortex_bail!(
+                "Expected {} elements for {} lists of size {}, got {}",
+                length * list_size as usize,
+                length,
+                list_size,
+                elements.len()
+            );
+        }
+
+        let metadata = FixedSizeListMetadata {
+            length,
+            validity: validity.to_metadata(length)?,
+        };
+        let dtype = DType::FixedSizeList(
+            Arc::new(elements.dtype().clone()),
+            list_size,
+            validity.nullability(),
+        );
+
+        let mut children = Vec::with_capacity(2);
+        children.push(elements.into_array_data());
+        if let Some(a) = validity.into_array_data() {
+            children.push(a)
+        }
+
+        Self::try_from_parts(dtype, metadata, children.into(), StatsSet::new())
+    }
+
+    #[inline]
+    pub fn elements(&self) -> Array {
+        self.array()
+            .child(0, self.element_dtype())
+            .expect("missing elements")
+    }
+
+    pub fn element_dtype(&self) -> &DType {
+        let DType::FixedSizeList(element_dtype, ..) = self.dtype() else {
+            unreachable!()
+        };
+        element_dtype
+    }
+
+    pub fn list_size(&self) -> u32 {
+        let DType::FixedSizeList(_, list_size, _) = self.dtype() else {
+            unreachable!()
+        };
+        *list_size
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(1, &Validity::DTYPE))
+    }
+
+    /// The elements of the list at the given index.
+    pub fn elements_at(&self, index: usize) -> VortexResult<Array> {
+        let size = self.list_size() as usize;
+        slice(&self.elements(), index * size, (index + 1) * size)
+    }
+}
+
+impl ArrayFlatten for FixedSizeListArray {
+    fn flatten(self) -> VortexResult<Flattened> {
+        Ok(Flattened::FixedSizeList(Self::try_new(
+            self.elements().flatten()?.into_array(),
+            self.list_size(),
+            self.len(),
+            self.validity(),
+        )?))
+    }
+}
+
+impl ArrayTrait for FixedSizeListArray {
+    fn len(&self) -> usize {
+        self.metadata().length
+    }
+}
+
+impl ArrayValidity for FixedSizeListArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for FixedSizeListArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("elements", &self.elements())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for FixedSizeListArray {}
+
+impl EncodingCompression for FixedSizeListEncoding {}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float32Type;
+    use arrow_array::{Array as _, ArrayRef as ArrowArrayRef};
+
+    use crate::array::fixed_size_list::FixedSizeListArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::arrow::FromArrowArray;
+    use crate::compute::as_arrow::as_arrow;
+    use crate::compute::as_contiguous::as_contiguous;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::slice::slice;
+    use crate::compute::take::take;
+    use crate::validity::Validity;
+    use crate::{Array, ArrayData, IntoArray};
+
+    /// The elements of the list at the given index, whether or not the list itself is null.
+    fn elements(array: &Array, index: usize) -> Vec<Option<f32>> {
+        let elements = FixedSizeListArray::try_from(array)
+            .unwrap()
+            .elements_at(index)
+            .unwrap();
+        (0..elements.len())
+            .map(|i| {
+                let scalar = scalar_at(&elements, i).unwrap();
+                (!scalar.is_null()).then(|| f32::try_from(&scalar).unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn validity_disagrees_with_elements() {
+        // The second list is null although its elements aren't, and the third list is valid
+        // although one of its elements is null.
+        let list = FixedSizeListArray::try_new(
+            PrimitiveArray::from_nullable_vec(vec![
+                Some(1.0f32),
+                Some(2.0),
+                Some(3.0),
+                Some(4.0),
+                Some(5.0),
+                None,
+            ])
+            .into_array(),
+            2,
+            3,
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap()
+        .into_array();
+        assert!(scalar_at(&list, 1).unwrap().is_null());
+        assert_eq!(elements(&list, 1), vec![Some(3.0), Some(4.0)]);
+        assert!(!scalar_at(&list, 2).unwrap().is_null());
+        assert_eq!(elements(&list, 2), vec![Some(5.0), None]);
+
+        // The list validity follows the lists through slices, takes and concatenation.
+        let taken = take(
+            &slice(&list, 1, 3).unwrap(),
+            &PrimitiveArray::from(vec![1u32, 0, 0]).into_array(),
+        )
+        .unwrap();
+        let concatenated = as_contiguous(&[list.clone(), taken]).unwrap();
+        assert_eq!(
+            (0..concatenated.len())
+                .map(|i| concatenated.with_dyn(|a| a.is_valid(i)))
+                .collect::<Vec<_>>(),
+            vec![true, false, true, true, false, false]
+        );
+        assert_eq!(elements(&concatenated, 3), vec![Some(5.0), None]);
+        assert_eq!(elements(&concatenated, 4), vec![Some(3.0), Some(4.0)]);
+
+        // Arrow keeps the elements of a null list, and its own nulls are independent of them.
+        let arrow: ArrowArrayRef = as_arrow(&list).unwrap();
+        let arrow_list = arrow.as_fixed_size_list();
+        assert!(arrow_list.is_null(1));
+        assert!(arrow_list.is_valid(2));
+        assert_eq!(
+            arrow_list
+                .values()
+                .as_primitive::<Float32Type>()
+                .null_count(),
+            1
+        );
+        let round_tripped = ArrayData::from_arrow(arrow.clone(), true).into_array();
+        assert_eq!(elements(&round_tripped, 1), vec![Some(3.0), Some(4.0)]);
+        assert_eq!(as_arrow(&round_tripped).unwrap().as_ref(), arrow.as_ref());
+
+        assert!(FixedSizeListArray::try_new(
+            PrimitiveArray::from(vec![1.0f32; 5]).into_array(),
+            3,
+            2,
+            Validity::NonNullable,
+        )
+        .is_err());
+    }
+}
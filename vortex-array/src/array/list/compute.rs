@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef as ArrowArrayRef, GenericListArray, OffsetSizeTrait};
+use arrow_buffer::NullBuffer;
+use arrow_schema::Field;
+use itertools::Itertools;
+use vortex_dtype::{NativePType, PType};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::{Scalar, ScalarValue};
+
+use crate::array::list::ListArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::arrow::wrappers::as_offset_buffer;
+use crate::compute::as_arrow::{as_arrow, AsArrowArray};
+use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
+use crate::compute::cast::cast;
+use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
+use crate::compute::slice::{slice, SliceFn};
+use crate::compute::take::{take, TakeFn};
+use crate::compute::ArrayCompute;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray};
+
+impl ArrayCompute for ListArray {
+    fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
+        Some(self)
+    }
+
+    fn as_contiguous(&self) -> Option<&dyn AsContiguousFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl AsArrowArray for ListArray {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        // Ensure the offsets are either i32 or i64
+        let offsets = self.offsets().flatten_primitive()?;
+        let offsets = match offsets.ptype() {
+            PType::I32 | PType::I64 => offsets,
+            PType::U64 => cast(&offsets.into_array(), PType::I64.into())?.flatten_primitive()?,
+            _ => cast(&offsets.into_array(), PType::I32.into())?.flatten_primitive()?,
+        };
+        let values = as_arrow(&self.elements())?;
+        let field = Arc::new(Field::new_list_field(
+            values.data_type().clone(),
+            self.element_dtype().is_nullable(),
+        ));
+        let nulls = self.logical_validity().to_null_buffer()?;
+
+        Ok(match offsets.ptype() {
+            PType::I32 => list_array::<i32>(field, offsets, values, nulls)?,
+            PType::I64 => list_array::<i64>(field, offsets, values, nulls)?,
+            _ => unreachable!(),
+        })
+    }
+}
+
+fn list_array<O: NativePType + OffsetSizeTrait>(
+    field: Arc<Field>,
+    offsets: PrimitiveArray,
+    values: ArrowArrayRef,
+    nulls: Option<NullBuffer>,
+) -> VortexResult<ArrowArrayRef> {
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        field,
+        as_offset_buffer::<O>(offsets),
+        values,
+        nulls,
+    )?))
+}
+
+impl AsContiguousFn for ListArray {
+    fn as_contiguous(&self, arrays: &[Array]) -> VortexResult<Array> {
+        let lists: Vec<Self> = arrays.iter().map(Self::try_from).try_collect()?;
+        let elements: Vec<Array> = lists.iter().map(|l| l.sliced_elements()).try_collect()?;
+        let elements = as_contiguous(&elements)?;
+
+        let validity = if self.dtype().is_nullable() {
+            Validity::from_iter(arrays.iter().map(|a| a.with_dyn(|a| a.logical_validity())))
+        } else {
+            Validity::NonNullable
+        };
+
+        let mut offsets = vec![0u64];
+        for list in &lists {
+            let list_offsets = cast(&list.offsets(), PType::U64.into())?.flatten_primitive()?;
+            let list_offsets = list_offsets.typed_data::<u64>();
+            let shift = offsets.last().copied().unwrap_or(0);
+            offsets.extend(
+                list_offsets
+                    .iter()
+                    .skip(1) // Ignore the first offset of each array
+                    .map(|o| o + shift - list_offsets[0]),
+            );
+        }
+
+        Self::try_new(
+            PrimitiveArray::from(offsets).into_array(),
+            elements,
+            validity,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl ScalarAtFn for ListArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+        let elements = self.elements_at(index)?;
+        let values: Vec<ScalarValue> = (0..elements.len())
+            .map(|i| scalar_at(&elements, i).map(|s| s.into_value()))
+            .try_collect()?;
+        Ok(Scalar::new(
+            self.dtype().clone(),
+            ScalarValue::List(values.into()),
+        ))
+    }
+}
+
+impl SliceFn for ListArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        Self::try_new(
+            slice(&self.offsets(), start, stop + 1)?,
+            self.elements(),
+            self.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl TakeFn for ListArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let offsets = cast(&self.offsets(), PType::U64.into())?.flatten_primitive()?;
+        let offsets = offsets.typed_data::<u64>();
+        let indices_u64 = cast(indices, PType::U64.into())?.flatten_primitive()?;
+
+        let mut new_offsets = Vec::with_capacity(indices.len() + 1);
+        new_offsets.push(0u64);
+        let mut element_indices = Vec::new();
+        for &idx in indices_u64.typed_data::<u64>() {
+            let idx = idx as usize;
+            if idx >= self.len() {
+                vortex_bail!(OutOfBounds: idx, 0, self.len());
+            }
+            element_indices.extend(offsets[idx]..offsets[idx + 1]);
+            new_offsets.push(element_indices.len() as u64);
+        }
+
+        Self::try_new(
+            PrimitiveArray::from(new_offsets).into_array(),
+            take(
+                &self.elements(),
+                &PrimitiveArray::from(element_indices).into_array(),
+            )?,
+            self.validity().take(indices)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use vortex_error::vortex_bail;
+
+use crate::compute::scalar_at::scalar_at;
+use crate::compute::slice::slice;
+use crate::stats::ArrayStatisticsCompute;
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{impl_encoding, ArrayDType, ArrayFlatten, IntoArrayData};
+
+mod compute;
+
+impl_encoding!("vortex.list", List);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ListMetadata {
+    validity: ValidityMetadata,
+    offsets_dtype: DType,
+}
+
+impl ListArray {
+    /// Create a list array, where the elements of the list at index `i` are the elements between
+    /// `offsets[i]` and `offsets[i + 1]`.
+    pub fn try_new(offsets: Array, elements: Array, validity: Validity) -> VortexResult<Self> {
+        if !offsets.dtype().is_int() || offsets.dtype().is_nullable() {
+            vortex_bail!(MismatchedTypes: "non nullable int", offsets.dtype());
+        }
+        if offsets.is_empty() {
+            vortex_bail!("List offsets must contain at least one offset");
+        }
+
+        let metadata = ListMetadata {
+            validity: validity.to_metadata(offsets.len() - 1)?,
+            offsets_dtype: offsets.dtype().clone(),
+        };
+        let dtype = DType::List(Arc::new(elements.dtype().clone()), validity.nullability());
+
+        let mut children = Vec::with_capacity(3);
+        children.push(offsets.into_array_data());
+        children.push(elements.into_array_data());
+        if let Some(a) = validity.into_array_data() {
+            children.push(a)
+        }
+
+        Self::try_from_parts(dtype, metadata, children.into(), StatsSet::new())
+    }
+
+    #[inline]
+    pub fn offsets(&self) -> Array {
+        self.array()
+            .child(0, &self.metadata().offsets_dtype)
+            .expect("missing offsets")
+    }
+
+    /// The elements of all of the lists, including any that aren't referenced by the offsets.
+    #[inline]
+    pub fn elements(&self) -> Array {
+        self.array()
+            .child(1, self.element_dtype())
+            .expect("missing elements")
+    }
+
+    pub fn element_dtype(&self) -> &DType {
+        let DType::List(element_dtype, _) = self.dtype() else {
+            unreachable!()
+        };
+        element_dtype
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(2, &Validity::DTYPE))
+    }
+
+    pub fn offset_at(&self, index: usize) -> VortexResult<usize> {
+        scalar_at(&self.offsets(), index)?.as_ref().try_into()
+    }
+
+    /// The elements of the list at the given index.
+    pub fn elements_at(&self, index: usize) -> VortexResult<Array> {
+        slice(
+            &self.elements(),
+            self.offset_at(index)?,
+            self.offset_at(index + 1)?,
+        )
+    }
+
+    /// The elements referenced by the offsets of this array.
+    pub fn sliced_elements(&self) -> VortexResult<Array> {
+        slice(
+            &self.elements(),
+            self.offset_at(0)?,
+            self.offset_at(self.len())?,
+        )
+    }
+}
+
+impl ArrayFlatten for ListArray {
+    fn flatten(self) -> VortexResult<Flattened> {
+        Ok(Flattened::List(Self::try_new(
+            self.offsets().flatten_primitive()?.into_array(),
+            self.elements().flatten()?.into_array(),
+            self.validity(),
+        )?))
+    }
+}
+
+impl ArrayTrait for ListArray {
+    fn len(&self) -> usize {
+        self.offsets().len() - 1
+    }
+}
+
+impl ArrayValidity for ListArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for ListArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("offsets", &self.offsets())?;
+        visitor.visit_child("elements", &self.elements())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for ListArray {}
+
+impl EncodingCompression for ListEncoding {}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Array as _, ArrayRef as ArrowArrayRef, LargeListArray};
+
+    use crate::array::list::ListArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::arrow::FromArrowArray;
+    use crate::compute::as_arrow::as_arrow;
+    use crate::compute::as_contiguous::as_contiguous;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::slice::slice;
+    use crate::compute::take::take;
+    use crate::validity::Validity;
+    use crate::{Array, ArrayData, IntoArray};
+
+    fn elements(array: &Array, index: usize) -> Option<Vec<i32>> {
+        let scalar = scalar_at(array, index).unwrap();
+        (!scalar.is_null()).then(|| Vec::<i32>::try_from(&scalar).unwrap())
+    }
+
+    #[test]
+    fn unreferenced_elements() {
+        // [[2, 3], null, [6]], where the offsets skip the first two and last two elements and
+        // the null list still spans [4, 5].
+        let list = ListArray::try_new(
+            PrimitiveArray::from(vec![2u32, 4, 6, 7]).into_array(),
+            PrimitiveArray::from((0..9).collect::<Vec<i32>>()).into_array(),
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap();
+        assert_eq!(list.elements().len(), 9);
+        assert_eq!(list.sliced_elements().unwrap().len(), 5);
+        let list = list.into_array();
+        assert_eq!(elements(&list, 0), Some(vec![2, 3]));
+        assert_eq!(elements(&list, 1), None);
+        assert_eq!(elements(&list, 2), Some(vec![6]));
+
+        // Slices share the elements, whereas takes and concatenation only keep the referenced
+        // ones and rebase the offsets to zero.
+        let sliced = ListArray::try_from(slice(&list, 2, 3).unwrap()).unwrap();
+        assert_eq!(sliced.offset_at(0).unwrap(), 6);
+        assert_eq!(sliced.elements().len(), 9);
+
+        let taken = ListArray::try_from(
+            take(&list, &PrimitiveArray::from(vec![2u64, 0]).into_array()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(taken.offset_at(0).unwrap(), 0);
+        assert_eq!(taken.elements().len(), 3);
+        assert_eq!(elements(taken.array(), 1), Some(vec![2, 3]));
+
+        let concatenated =
+            ListArray::try_from(as_contiguous(&[sliced.into_array(), list.clone()]).unwrap())
+                .unwrap();
+        assert_eq!(concatenated.elements().len(), 6);
+        assert_eq!(elements(concatenated.array(), 0), Some(vec![6]));
+        assert_eq!(elements(concatenated.array(), 1), Some(vec![2, 3]));
+        assert_eq!(elements(concatenated.array(), 2), None);
+        assert_eq!(elements(concatenated.array(), 3), Some(vec![6]));
+
+        // Arrow lists may also start past the first element, so the offsets are kept as they are.
+        let arrow = as_arrow(&list).unwrap();
+        let arrow_list = arrow.as_list::<i32>();
+        assert_eq!(arrow_list.value_offsets(), &[2, 4, 6, 7]);
+        assert_eq!(arrow_list.values().len(), 9);
+        assert!(arrow_list.is_null(1));
+        assert_eq!(
+            arrow_list.value(2).as_primitive::<Int32Type>().values(),
+            &[6]
+        );
+        let round_tripped = ArrayData::from_arrow(arrow.clone(), true).into_array();
+        assert_eq!(elements(&round_tripped, 0), Some(vec![2, 3]));
+        assert_eq!(as_arrow(&round_tripped).unwrap().as_ref(), arrow.as_ref());
+
+        // Sliced Arrow lists are imported with offsets into their unsliced values.
+        let arrow: ArrowArrayRef = Arc::new(
+            LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                Some(vec![Some(1)]),
+                Some(vec![Some(2), Some(3)]),
+                Some(vec![Some(4)]),
+            ])
+            .slice(1, 2),
+        );
+        let imported =
+            ListArray::try_from(ArrayData::from_arrow(arrow.clone(), false).into_array()).unwrap();
+        assert_eq!(imported.offset_at(0).unwrap(), 1);
+        assert_eq!(elements(imported.array(), 0), Some(vec![2, 3]));
+        assert_eq!(as_arrow(imported.array()).unwrap().as_ref(), arrow.as_ref());
+    }
+}
@@ -0,0 +1,203 @@
+use std::sync::Arc;
+
+use arrow_array::StructArray as ArrowStructArray;
+use arrow_array::{ArrayRef as ArrowArrayRef, MapArray as ArrowMapArray, UInt64Array};
+use arrow_schema::{DataType, Field, Fields};
+use itertools::Itertools;
+use vortex_dtype::PType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::{Scalar, ScalarValue};
+
+use crate::array::map::MapArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::arrow::wrappers::as_offset_buffer;
+use crate::arrow::FromArrowArray;
+use crate::compute::as_arrow::{as_arrow, AsArrowArray};
+use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
+use crate::compute::cast::cast;
+use crate::compute::extract_key::ExtractKeyFn;
+use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
+use crate::compute::slice::{slice, SliceFn};
+use crate::compute::take::{take, TakeFn};
+use crate::compute::ArrayCompute;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, ArrayData, ArrayTrait, IntoArray};
+
+impl ArrayCompute for MapArray {
+    fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
+        Some(self)
+    }
+
+    fn as_contiguous(&self) -> Option<&dyn AsContiguousFn> {
+        Some(self)
+    }
+
+    fn extract_key(&self) -> Option<&dyn ExtractKeyFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl AsArrowArray for MapArray {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        // Arrow maps only support i32 offsets
+        let offsets = cast(&self.offsets(), PType::I32.into())?.flatten_primitive()?;
+        let keys = as_arrow(&self.keys())?;
+        let values = as_arrow(&self.values())?;
+        let fields = Fields::from(vec![
+            Field::new("keys", keys.data_type().clone(), false),
+            Field::new(
+                "values",
+                values.data_type().clone(),
+                self.value_dtype().is_nullable(),
+            ),
+        ]);
+        let entries = ArrowStructArray::try_new(fields.clone(), vec![keys, values], None)?;
+
+        Ok(Arc::new(ArrowMapArray::try_new(
+            Arc::new(Field::new("entries", DataType::Struct(fields), false)),
+            as_offset_buffer::<i32>(offsets),
+            entries,
+            self.logical_validity().to_null_buffer()?,
+            false,
+        )?))
+    }
+}
+
+impl AsContiguousFn for MapArray {
+    fn as_contiguous(&self, arrays: &[Array]) -> VortexResult<Array> {
+        let maps: Vec<Self> = arrays.iter().map(Self::try_from).try_collect()?;
+
+        let mut offsets = vec![0u64];
+        let mut keys = Vec::with_capacity(maps.len());
+        let mut values = Vec::with_capacity(maps.len());
+        for map in &maps {
+            let map_offsets = cast(&map.offsets(), PType::U64.into())?.flatten_primitive()?;
+            let map_offsets = map_offsets.typed_data::<u64>();
+            let (start, stop) = (map_offsets[0], map_offsets[map.len()]);
+            let shift = offsets.last().copied().unwrap_or(0);
+            offsets.extend(map_offsets.iter().skip(1).map(|o| o + shift - start));
+            keys.push(slice(&map.keys(), start as usize, stop as usize)?);
+            values.push(slice(&map.values(), start as usize, stop as usize)?);
+        }
+
+        let validity = if self.dtype().is_nullable() {
+            Validity::from_iter(maps.iter().map(|m| m.logical_validity()))
+        } else {
+            Validity::NonNullable
+        };
+
+        Self::try_new(
+            PrimitiveArray::from(offsets).into_array(),
+            as_contiguous(&keys)?,
+            as_contiguous(&values)?,
+            validity,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl ExtractKeyFn for MapArray {
+    fn extract_key(&self, key: &Scalar) -> VortexResult<Array> {
+        if !key.dtype().eq_ignore_nullability(self.key_dtype()) {
+            vortex_bail!(MismatchedTypes: self.key_dtype(), key.dtype());
+        }
+
+        let offsets = cast(&self.offsets(), PType::U64.into())?.flatten_primitive()?;
+        let offsets = offsets.typed_data::<u64>();
+        let keys = self.keys().flatten()?.into_array();
+
+        // The index of the first entry of each map with the given key, or null if there is none.
+        let mut indices = Vec::with_capacity(self.len());
+        for i in 0..self.len() {
+            let mut found = None;
+            if self.is_valid(i) {
+                for entry in offsets[i]..offsets[i + 1] {
+                    if scalar_at(&keys, entry as usize)?.value() == key.value() {
+                        found = Some(entry);
+                        break;
+                    }
+                }
+            }
+            indices.push(found);
+        }
+
+        // Arrow's take kernel turns the null indices into null values for any value type.
+        let values = arrow_select::take::take(
+            as_arrow(&self.values())?.as_ref(),
+            &UInt64Array::from(indices),
+            None,
+        )?;
+        Ok(ArrayData::from_arrow(values, true).into_array())
+    }
+}
+
+impl ScalarAtFn for MapArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+        let (keys, values) = self.entries_at(index)?;
+        let entries: Vec<(ScalarValue, ScalarValue)> = (0..keys.len())
+            .map(|i| {
+                Ok::<_, vortex_error::VortexError>((
+                    scalar_at(&keys, i)?.into_value(),
+                    scalar_at(&values, i)?.into_value(),
+                ))
+            })
+            .try_collect()?;
+        Ok(Scalar::map(self.dtype().clone(), entries))
+    }
+}
+
+impl SliceFn for MapArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        Self::try_new(
+            slice(&self.offsets(), start, stop + 1)?,
+            self.keys(),
+            self.values(),
+            self.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl TakeFn for MapArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let offsets = cast(&self.offsets(), PType::U64.into())?.flatten_primitive()?;
+        let offsets = offsets.typed_data::<u64>();
+        let indices_u64 = cast(indices, PType::U64.into())?.flatten_primitive()?;
+
+        let mut new_offsets = Vec::with_capacity(indices.len() + 1);
+        new_offsets.push(0u64);
+        let mut entry_indices = Vec::new();
+        for &idx in indices_u64.typed_data::<u64>() {
+            let idx = idx as usize;
+            if idx >= self.len() {
+                vortex_bail!(OutOfBounds: idx, 0, self.len());
+            }
+            entry_indices.extend(offsets[idx]..offsets[idx + 1]);
+            new_offsets.push(entry_indices.len() as u64);
+        }
+
+        let entry_indices = PrimitiveArray::from(entry_indices).into_array();
+        Self::try_new(
+            PrimitiveArray::from(new_offsets).into_array(),
+            take(&self.keys(), &entry_indices)?,
+            take(&self.values(), &entry_indices)?,
+            self.validity().take(indices)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
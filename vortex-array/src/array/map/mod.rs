@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+use vortex_error::vortex_bail;
+
+use crate::compute::scalar_at::scalar_at;
+use crate::compute::slice::slice;
+use crate::stats::ArrayStatisticsCompute;
+use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{impl_encoding, ArrayDType, ArrayFlatten, IntoArrayData};
+
+mod compute;
+
+impl_encoding!("vortex.map", Map);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MapMetadata {
+    validity: ValidityMetadata,
+    offsets_dtype: DType,
+}
+
+impl MapArray {
+    /// Create a map array, where the entries of the map at index `i` are the keys and values
+    /// between `offsets[i]` and `offsets[i + 1]`.
+    pub fn try_new(
+        offsets: Array,
+        keys: Array,
+        values: Array,
+        validity: Validity,
+    ) -> VortexResult<Self> {
+        if !offsets.dtype().is_int() || offsets.dtype().is_nullable() {
+            vortex_bail!(MismatchedTypes: "non nullable int", offsets.dtype());
+        }
+        if offsets.is_empty() {
+            vortex_bail!("Map offsets must contain at least one offset");
+        }
+        if keys.dtype().is_nullable() {
+            vortex_bail!("Map keys must be non-nullable, found {}", keys.dtype());
+        }
+        if keys.len() != values.len() {
+            vortex_bail!("Map has {} keys but {} values", keys.len(), values.len());
+        }
+
+        let metadata = MapMetadata {
+            validity: validity.to_metadata(offsets.len() - 1)?,
+            offsets_dtype: offsets.dtype().clone(),
+        };
+        let dtype = DType::Map(
+            Arc::new(keys.dtype().clone()),
+            Arc::new(values.dtype().clone()),
+            validity.nullability(),
+        );
+
+        let mut children = Vec::with_capacity(4);
+        children.push(offsets.into_array_data());
+        children.push(keys.into_array_data());
+        children.push(values.into_array_data());
+        if let Some(a) = validity.into_array_data() {
+            children.push(a)
+        }
+
+        Self::try_from_parts(dtype, metadata, children.into(), StatsSet::new())
+    }
+
+    #[inline]
+    pub fn offsets(&self) -> Array {
+        self.array()
+            .child(0, &self.metadata().offsets_dtype)
+            .expect("missing offsets")
+    }
+
+    /// The keys of all of the maps, including any that aren't referenced by the offsets.
+    #[inline]
+    pub fn keys(&self) -> Array {
+        self.array()
+            .child(1, self.key_dtype())
+            .expect("missing keys")
+    }
+
+    /// The values of all of the maps, including any that aren't referenced by the offsets.
+    #[inline]
+    pub fn values(&self) -> Array {
+        self.array()
+            .child(2, self.value_dtype())
+            .expect("missing values")
+    }
+
+    pub fn key_dtype(&self) -> &DType {
+        let DType::Map(key_dtype, ..) = self.dtype() else {
+            unreachable!()
+        };
+        key_dtype
+    }
+
+    pub fn value_dtype(&self) -> &DType {
+        let DType::Map(_, value_dtype, _) = self.dtype() else {
+            unreachable!()
+        };
+        value_dtype
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(3, &Validity::DTYPE))
+    }
+
+    pub fn offset_at(&self, index: usize) -> VortexResult<usize> {
+        scalar_at(&self.offsets(), index)?.as_ref().try_into()
+    }
+
+    /// The keys and values of the map at the given index.
+    pub fn entries_at(&self, index: usize) -> VortexResult<(Array, Array)> {
+        let start = self.offset_at(index)?;
+        let stop = self.offset_at(index + 1)?;
+        Ok((
+            slice(&self.keys(), start, stop)?,
+            slice(&self.values(), start, stop)?,
+        ))
+    }
+}
+
+impl ArrayFlatten for MapArray {
+    fn flatten(self) -> VortexResult<Flattened> {
+        Ok(Flattened::Map(Self::try_new(
+            self.offsets().flatten_primitive()?.into_array(),
+            self.keys().flatten()?.into_array(),
+            self.values().flatten()?.into_array(),
+            self.validity(),
+        )?))
+    }
+}
+
+impl ArrayTrait for MapArray {
+    fn len(&self) -> usize {
+        self.offsets().len() - 1
+    }
+}
+
+impl ArrayValidity for MapArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for MapArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("offsets", &self.offsets())?;
+        visitor.visit_child("keys", &self.keys())?;
+        visitor.visit_child("values", &self.values())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for MapArray {}
+
+impl EncodingCompression for MapEncoding {}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Array as _, ArrayRef as ArrowArrayRef};
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::{MapScalar, Scalar};
+
+    use crate::array::map::MapArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::arrow::{FromArrowArray, TryFromArrowArray};
+    use crate::compute::as_arrow::as_arrow;
+    use crate::compute::extract_key::extract_key;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::take::take;
+    use crate::validity::Validity;
+    use crate::{Array, ArrayDType, ArrayData, IntoArray};
+
+    /// [{a: 1, b: 2}, null, {}, {b: 3, c: null}]
+    fn map() -> Array {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.append(true).unwrap();
+        builder.keys().append_value("b");
+        builder.values().append_value(3);
+        builder.keys().append_value("c");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        ArrayData::try_from_arrow(&builder.finish(), true)
+            .unwrap()
+            .into_array()
+    }
+
+    fn get(array: &Array, index: usize, key: &str) -> Option<i32> {
+        let scalar = scalar_at(array, index).unwrap();
+        MapScalar::try_from(&scalar)
+            .unwrap()
+            .get(&Scalar::from(key))
+            .and_then(|v| (&v).try_into().ok())
+    }
+
+    #[test]
+    fn extract_values_of_key() {
+        let map = map();
+        let b = extract_key(&map, &Scalar::from("b")).unwrap();
+        assert_eq!(
+            b.dtype(),
+            &DType::Primitive(PType::I32, Nullability::Nullable)
+        );
+        let b = b.flatten_primitive().unwrap();
+        assert_eq!(b.typed_data::<i32>()[0], 2);
+        assert_eq!(b.typed_data::<i32>()[3], 3);
+        assert!(!b.array().with_dyn(|a| a.is_valid(1)));
+        assert!(!b.array().with_dyn(|a| a.is_valid(2)));
+
+        // A key whose value is null produces a null.
+        let c = extract_key(&map, &Scalar::from("c")).unwrap();
+        assert!(scalar_at(&c, 3).unwrap().is_null());
+
+        assert!(extract_key(&map, &Scalar::from(1i32)).is_err());
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        // [{a: 1, a: 2, b: 3}, null, {a: 4}], where the null map also spans an entry.
+        let map = MapArray::try_new(
+            PrimitiveArray::from(vec![0u32, 3, 4, 5]).into_array(),
+            VarBinArray::from(vec!["a", "a", "b", "a", "a"]).into_array(),
+            PrimitiveArray::from(vec![1i32, 2, 3, 9, 4]).into_array(),
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap()
+        .into_array();
+
+        // Maps keep every entry, and lookups find the first one with the key.
+        let scalar = scalar_at(&map, 0).unwrap();
+        assert_eq!(MapScalar::try_from(&scalar).unwrap().entries().count(), 3);
+        assert_eq!(get(&map, 0, "a"), Some(1));
+        let a = extract_key(&map, &Scalar::from("a"))
+            .unwrap()
+            .flatten_primitive()
+            .unwrap();
+        assert_eq!(a.typed_data::<i32>()[0], 1);
+        assert!(!a.array().with_dyn(|a| a.is_valid(1)));
+        assert_eq!(a.typed_data::<i32>()[2], 4);
+
+        let taken = take(&map, &PrimitiveArray::from(vec![0u64, 0]).into_array()).unwrap();
+        let scalar = scalar_at(&taken, 1).unwrap();
+        assert_eq!(MapScalar::try_from(&scalar).unwrap().entries().count(), 3);
+        assert_eq!(get(&taken, 1, "a"), Some(1));
+
+        // Arrow doesn't require map keys to be unique either, so the duplicates survive export.
+        let arrow: ArrowArrayRef = as_arrow(&map).unwrap();
+        let arrow_map = arrow.as_map();
+        assert_eq!(arrow_map.value_length(0), 3);
+        assert_eq!(
+            arrow_map
+                .keys()
+                .as_string::<i32>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some("a"), Some("a"), Some("b"), Some("a"), Some("a")]
+        );
+        assert!(arrow_map.is_null(1));
+        let round_tripped = ArrayData::from_arrow(arrow.clone(), true).into_array();
+        assert_eq!(round_tripped.dtype(), map.dtype());
+        assert_eq!(get(&round_tripped, 0, "a"), Some(1));
+        assert_eq!(as_arrow(&round_tripped).unwrap().as_ref(), arrow.as_ref());
+    }
+}
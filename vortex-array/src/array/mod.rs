@@ -2,9 +2,15 @@ pub mod bool;
 pub mod chunked;
 pub mod constant;
 pub mod datetime;
+pub mod decimal;
 pub mod extension;
+pub mod fixed_size_binary;
+pub mod fixed_size_list;
+pub mod list;
+pub mod map;
 pub mod primitive;
 pub mod sparse;
 pub mod r#struct;
+pub mod union;
 pub mod varbin;
 pub mod varbinview;
@@ -0,0 +1,38 @@
+use vortex_dtype::{match_each_native_ptype, Nullability};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::aggregate::{sum_primitive, valid_count, Aggregate, AggregateFn};
+use crate::validity::ArrayValidity;
+use crate::ArrayDType;
+
+impl AggregateFn for PrimitiveArray {
+    fn aggregate(&self, agg: Aggregate) -> VortexResult<Scalar> {
+        match agg {
+            Aggregate::Sum => sum_primitive(self, |_| 1),
+            Aggregate::Count => Ok((valid_count(self.logical_validity())? as u64).into()),
+            Aggregate::Min | Aggregate::Max => {
+                let valid = self.logical_validity().to_null_buffer()?;
+                match_each_native_ptype!(self.ptype(), |$T| {
+                    let extreme = self
+                        .typed_data::<$T>()
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| valid.as_ref().map(|n| n.is_valid(*i)).unwrap_or(true))
+                        .map(|(_, &v)| v)
+                        .reduce(|best, v| {
+                            let better = if agg == Aggregate::Min { v < best } else { v > best };
+                            if better { v } else { best }
+                        });
+                    Ok(extreme
+                        .map(|v| Scalar::primitive(v, Nullability::Nullable))
+                        .unwrap_or_else(|| Scalar::null(self.dtype().as_nullable())))
+                })
+            }
+            Aggregate::Any | Aggregate::All => {
+                vortex_bail!("{:?} is not supported for {}", agg, self.dtype())
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use arrow_buffer::BooleanBuffer;
+use vortex_dtype::{match_each_float_ptype, match_each_integer_ptype, NativePType, Nullability};
+use vortex_error::{vortex_err, VortexError, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::array::constant::ConstantArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::arithmetic::{
+    checked_int_op, float_op, overflow_error, BinaryNumericFn, NegateFn, NumericOperator,
+};
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray};
+
+/// The right-hand side of an operation, where constants are applied without materializing them.
+enum Operand {
+    Scalar(Scalar),
+    Array(PrimitiveArray),
+}
+
+impl BinaryNumericFn for PrimitiveArray {
+    fn binary_numeric(&self, rhs: &Array, op: NumericOperator) -> VortexResult<Array> {
+        let nullability =
+            Nullability::from(self.dtype().is_nullable() || rhs.dtype().is_nullable());
+        let (rhs, rhs_valid) = match ConstantArray::try_from(rhs) {
+            Ok(constant) if constant.scalar().is_null() => {
+                return Ok(ConstantArray::new(
+                    Scalar::null(self.dtype().as_nullable()),
+                    self.len(),
+                )
+                .into_array());
+            }
+            Ok(constant) => (Operand::Scalar(constant.scalar().clone()), None),
+            Err(_) => {
+                let flat = rhs.clone().flatten_primitive()?;
+                let valid = flat.logical_validity().to_null_buffer()?;
+                (Operand::Array(flat), valid.map(|n| n.into_inner()))
+            }
+        };
+
+        // Only valid positions are computed, so garbage values behind nulls can't fail.
+        let valid = match (self.logical_validity().to_null_buffer()?, rhs_valid) {
+            (Some(l), Some(r)) => Some(l.inner() & &r),
+            (Some(l), None) => Some(l.into_inner()),
+            (None, r) => r,
+        };
+        let validity = match (nullability, &valid) {
+            (Nullability::NonNullable, _) => Validity::NonNullable,
+            (Nullability::Nullable, None) => Validity::AllValid,
+            (Nullability::Nullable, Some(v)) => Validity::from(v.clone()),
+        };
+
+        if self.ptype().is_int() {
+            match_each_integer_ptype!(self.ptype(), |$T| {
+                let f = checked_int_op::<$T>(op);
+                let values = binary_values(self.typed_data::<$T>(), &rhs, valid.as_ref(), |l, r| {
+                    f(l, r).ok_or_else(|| overflow_error(l, op, r))
+                })?;
+                Ok(PrimitiveArray::from_vec(values, validity).into_array())
+            })
+        } else {
+            match_each_float_ptype!(self.ptype(), |$T| {
+                let f = float_op::<$T>(op);
+                let values = binary_values(self.typed_data::<$T>(), &rhs, valid.as_ref(), |l, r| {
+                    Ok(f(l, r))
+                })?;
+                Ok(PrimitiveArray::from_vec(values, validity).into_array())
+            })
+        }
+    }
+}
+
+fn binary_values<T: NativePType + TryFrom<vortex_scalar::PValue, Error = VortexError>>(
+    lhs: &[T],
+    rhs: &Operand,
+    valid: Option<&BooleanBuffer>,
+    f: impl Fn(T, T) -> VortexResult<T>,
+) -> VortexResult<Vec<T>> {
+    let is_valid = |i: usize| valid.map(|v| v.value(i)).unwrap_or(true);
+    match rhs {
+        Operand::Scalar(scalar) => {
+            let r = PrimitiveScalar::try_from(scalar)?
+                .typed_value::<T>()
+                .ok_or_else(|| vortex_err!("Expected a non-null scalar"))?;
+            lhs.iter()
+                .enumerate()
+                .map(|(i, &l)| {
+                    if is_valid(i) {
+                        f(l, r)
+                    } else {
+                        Ok(T::default())
+                    }
+                })
+                .collect()
+        }
+        Operand::Array(array) => lhs
+            .iter()
+            .zip(array.typed_data::<T>())
+            .enumerate()
+            .map(|(i, (&l, &r))| {
+                if is_valid(i) {
+                    f(l, r)
+                } else {
+                    Ok(T::default())
+                }
+            })
+            .collect(),
+    }
+}
+
+impl NegateFn for PrimitiveArray {
+    fn negate(&self) -> VortexResult<Array> {
+        let valid = self.logical_validity().to_null_buffer()?;
+        let is_valid = |i: usize| valid.as_ref().map(|v| v.is_valid(i)).unwrap_or(true);
+        if self.ptype().is_int() {
+            match_each_integer_ptype!(self.ptype(), |$T| {
+                let values = self
+                    .typed_data::<$T>()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &v)| {
+                        if !is_valid(i) {
+                            return Ok(<$T>::default());
+                        }
+                        v.checked_neg()
+                            .ok_or_else(|| vortex_err!("Can't negate {} of type {}", v, self.ptype()))
+                    })
+                    .collect::<VortexResult<Vec<_>>>()?;
+                Ok(PrimitiveArray::from_vec(values, self.validity()).into_array())
+            })
+        } else {
+            match_each_float_ptype!(self.ptype(), |$T| {
+                let values = self.typed_data::<$T>().iter().map(|&v| -v).collect::<Vec<_>>();
+                Ok(PrimitiveArray::from_vec(values, self.validity()).into_array())
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use vortex_scalar::Scalar;
+
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::arithmetic::{
+        add, div, mul, neg, rem, scalar_numeric, sub, NumericOperator,
+    };
+    use crate::validity::ArrayValidity;
+    use crate::IntoArray;
+
+    #[test]
+    fn array_array() {
+        let lhs = PrimitiveArray::from(vec![10i32, 20, 30, 40]).into_array();
+        let rhs = PrimitiveArray::from_nullable_vec(vec![Some(1i32), Some(3), None, Some(-7)])
+            .into_array();
+
+        let typed = |result| PrimitiveArray::try_from(result).unwrap();
+        assert_eq!(typed(add(&lhs, &rhs).unwrap()).typed_data::<i32>()[3], 33);
+        assert_eq!(typed(sub(&lhs, &rhs).unwrap()).typed_data::<i32>()[1], 17);
+        assert_eq!(typed(mul(&lhs, &rhs).unwrap()).typed_data::<i32>()[3], -280);
+        assert_eq!(typed(div(&lhs, &rhs).unwrap()).typed_data::<i32>()[1], 6);
+
+        let remainder = typed(rem(&lhs, &rhs).unwrap());
+        assert_eq!(remainder.typed_data::<i32>()[0], 0);
+        assert_eq!(remainder.typed_data::<i32>()[1], 2);
+        assert!(remainder.is_valid(0));
+        assert!(!remainder.is_valid(2));
+    }
+
+    #[test]
+    fn array_scalar() {
+        let values =
+            PrimitiveArray::from_nullable_vec(vec![Some(1.5f64), None, Some(-2.0)]).into_array();
+        let result = PrimitiveArray::try_from(
+            scalar_numeric(&values, &2.0f64.into(), NumericOperator::Mul).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(result.typed_data::<f64>()[0], 3.0);
+        assert_eq!(result.typed_data::<f64>()[2], -4.0);
+        assert!(!result.is_valid(1));
+
+        // A null scalar makes every value null.
+        let nulls =
+            scalar_numeric(&values, &Scalar::from(None::<f64>), NumericOperator::Add).unwrap();
+        assert!((0..3).all(|i| !nulls.with_dyn(|a| a.is_valid(i))));
+    }
+
+    #[test]
+    fn overflow() {
+        let values = PrimitiveArray::from(vec![i8::MAX, 0]).into_array();
+        assert!(scalar_numeric(&values, &1i8.into(), NumericOperator::Add).is_err());
+        assert!(scalar_numeric(&values, &0i8.into(), NumericOperator::Div).is_err());
+        assert!(neg(&PrimitiveArray::from(vec![i8::MIN]).into_array()).is_err());
+        assert!(neg(&PrimitiveArray::from(vec![1u8]).into_array()).is_err());
+
+        // Values behind nulls are never computed.
+        let nullable = PrimitiveArray::from_nullable_vec(vec![None, Some(1u8)]).into_array();
+        let divisor = PrimitiveArray::from(vec![0u8, 1]).into_array();
+        assert!(div(&nullable, &divisor).is_ok());
+        // Mismatched types are rejected up front.
+        assert!(scalar_numeric(&divisor, &1.0f32.into(), NumericOperator::Add).is_err());
+
+        assert_eq!(
+            PrimitiveArray::try_from(
+                neg(&PrimitiveArray::from(vec![1i64, -2]).into_array()).unwrap()
+            )
+            .unwrap()
+            .typed_data::<i64>()
+            .iter()
+            .collect_vec(),
+            vec![&-1, &2]
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use arrow_buffer::BooleanBuffer;
+use vortex_dtype::{match_each_native_ptype, NativePType};
+use vortex_error::VortexResult;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::filter::FilterFn;
+use crate::{Array, IntoArray};
+
+impl FilterFn for PrimitiveArray {
+    fn filter(&self, mask: &Array) -> VortexResult<Array> {
+        let validity = self.validity().filter(mask)?;
+        let mask = mask.clone().flatten_bool()?.boolean_buffer();
+        match_each_native_ptype!(self.ptype(), |$T| {
+            Ok(PrimitiveArray::from_vec(
+                filter_primitive(self.typed_data::<$T>(), &mask),
+                validity,
+            ).into_array())
+        })
+    }
+}
+
+fn filter_primitive<T: NativePType>(values: &[T], mask: &BooleanBuffer) -> Vec<T> {
+    let mut filtered = Vec::with_capacity(mask.count_set_bits());
+    // Copy runs of set bits at once, rather than one value at a time.
+    for (start, end) in mask.set_slices() {
+        filtered.extend_from_slice(&values[start..end]);
+    }
+    filtered
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::bool::BoolArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::filter::filter;
+    use crate::validity::ArrayValidity;
+    use crate::IntoArray;
+
+    #[test]
+    fn filter_nullable() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(1i64), None, Some(3), Some(4), None])
+                .into_array();
+        let mask = BoolArray::from(vec![true, true, false, true, false]).into_array();
+        let filtered = filter(&array, &mask).unwrap().flatten_primitive().unwrap();
+        assert_eq!(filtered.typed_data::<i64>(), &[1, 0, 4]);
+        assert!(filtered.is_valid(0));
+        assert!(!filtered.is_valid(1));
+        assert!(filtered.is_valid(2));
+    }
+}
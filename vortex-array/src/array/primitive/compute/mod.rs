@@ -1,30 +1,46 @@
 use crate::array::primitive::PrimitiveArray;
+use crate::compute::aggregate::AggregateFn;
+use crate::compute::arithmetic::{BinaryNumericFn, NegateFn};
 use crate::compute::as_arrow::AsArrowArray;
 use crate::compute::as_contiguous::AsContiguousFn;
 use crate::compute::cast::CastFn;
 use crate::compute::compare::CompareFn;
 use crate::compute::fill::FillForwardFn;
+use crate::compute::filter::FilterFn;
 use crate::compute::filter_indices::FilterIndicesFn;
 use crate::compute::scalar_at::ScalarAtFn;
 use crate::compute::scalar_subtract::SubtractScalarFn;
 use crate::compute::search_sorted::SearchSortedFn;
 use crate::compute::slice::SliceFn;
+use crate::compute::sort::ArgsortFn;
 use crate::compute::take::TakeFn;
 use crate::compute::ArrayCompute;
 
+mod aggregate;
+mod arithmetic;
 mod as_arrow;
 mod as_contiguous;
 mod cast;
 mod compare;
 mod fill;
+mod filter;
 mod filter_indices;
 mod scalar_at;
 mod search_sorted;
 mod slice;
+mod sort;
 mod subtract_scalar;
 mod take;
 
 impl ArrayCompute for PrimitiveArray {
+    fn aggregate(&self) -> Option<&dyn AggregateFn> {
+        Some(self)
+    }
+
+    fn argsort(&self) -> Option<&dyn ArgsortFn> {
+        Some(self)
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         Some(self)
     }
@@ -33,6 +49,10 @@ impl ArrayCompute for PrimitiveArray {
         Some(self)
     }
 
+    fn binary_numeric(&self) -> Option<&dyn BinaryNumericFn> {
+        Some(self)
+    }
+
     fn cast(&self) -> Option<&dyn CastFn> {
         Some(self)
     }
@@ -44,10 +64,19 @@ impl ArrayCompute for PrimitiveArray {
     fn fill_forward(&self) -> Option<&dyn FillForwardFn> {
         Some(self)
     }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn filter_indices(&self) -> Option<&dyn FilterIndicesFn> {
         Some(self)
     }
 
+    fn negate(&self) -> Option<&dyn NegateFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
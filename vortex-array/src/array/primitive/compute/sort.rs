@@ -0,0 +1,82 @@
+use vortex_dtype::{match_each_float_ptype, match_each_integer_ptype};
+use vortex_error::VortexResult;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::sort::{argsort_by, with_nulls, ArgsortFn, Comparator};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayTrait};
+
+impl PrimitiveArray {
+    pub(crate) fn comparator(
+        &self,
+        descending: bool,
+        nulls_first: bool,
+    ) -> VortexResult<Comparator> {
+        let nulls = self.logical_validity().to_null_buffer()?;
+        let array = self.clone();
+        Ok(if self.ptype().is_int() {
+            match_each_integer_ptype!(self.ptype(), |$T| {
+                with_nulls(nulls, descending, nulls_first, move |a, b| {
+                    let values = array.typed_data::<$T>();
+                    values[a].cmp(&values[b])
+                })
+            })
+        } else {
+            // Floats use the IEEE 754 total order, which places NaN after infinity.
+            match_each_float_ptype!(self.ptype(), |$T| {
+                with_nulls(nulls, descending, nulls_first, move |a, b| {
+                    let values = array.typed_data::<$T>();
+                    values[a].total_cmp(&values[b])
+                })
+            })
+        })
+    }
+}
+
+impl ArgsortFn for PrimitiveArray {
+    fn argsort(&self, descending: bool, nulls_first: bool) -> VortexResult<Array> {
+        Ok(argsort_by(
+            self.len(),
+            self.comparator(descending, nulls_first)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::sort::{argsort, sort};
+    use crate::validity::ArrayValidity;
+    use crate::IntoArray;
+
+    #[test]
+    fn sort_nulls() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(-1), Some(3), Some(7)])
+                .into_array();
+
+        let indices = argsort(&array, false, true).unwrap();
+        assert_eq!(
+            indices.flatten_primitive().unwrap().typed_data::<u64>(),
+            &[1, 2, 0, 3, 4]
+        );
+
+        let sorted = sort(&array, true, false)
+            .unwrap()
+            .flatten_primitive()
+            .unwrap();
+        assert_eq!(&sorted.typed_data::<i32>()[..4], &[7, 3, 3, -1]);
+        assert!(!sorted.is_valid(4));
+    }
+
+    #[test]
+    fn sort_floats() {
+        let array =
+            PrimitiveArray::from(vec![2.5f64, f64::NAN, -0.0, f64::NEG_INFINITY]).into_array();
+        let indices = argsort(&array, false, false).unwrap();
+        assert_eq!(
+            indices.flatten_primitive().unwrap().typed_data::<u64>(),
+            &[3, 2, 0, 1]
+        );
+    }
+}
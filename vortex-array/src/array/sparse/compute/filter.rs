@@ -0,0 +1,81 @@
+use vortex_error::VortexResult;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::array::sparse::SparseArray;
+use crate::compute::filter::FilterFn;
+use crate::compute::take::take;
+use crate::{Array, IntoArray};
+
+impl FilterFn for SparseArray {
+    /// Only the patches are filtered, with each kept patch moved to the number of set mask
+    /// values before it.
+    fn filter(&self, mask: &Array) -> VortexResult<Array> {
+        let mask = mask.clone().flatten_bool()?.boolean_buffer();
+
+        let mut positions = Vec::new();
+        let mut patch_indices = Vec::new();
+        let mut rank = 0;
+        let mut prev = 0;
+        for (patch_idx, idx) in self.resolved_indices().into_iter().enumerate() {
+            rank += mask.slice(prev, idx - prev).count_set_bits();
+            prev = idx;
+            if mask.value(idx) {
+                positions.push(rank as u64);
+                patch_indices.push(patch_idx as u64);
+            }
+        }
+
+        Ok(Self::try_new(
+            PrimitiveArray::from(positions).into_array(),
+            take(
+                &self.values(),
+                &PrimitiveArray::from(patch_indices).into_array(),
+            )?,
+            mask.count_set_bits(),
+            self.fill_value().clone(),
+        )?
+        .into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::bool::BoolArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::sparse::SparseArray;
+    use crate::compute::filter::filter;
+    use crate::compute::slice::slice;
+    use crate::validity::Validity;
+    use crate::{ArrayTrait, IntoArray};
+
+    #[test]
+    fn filter_sliced_patches() {
+        let sparse = SparseArray::new(
+            PrimitiveArray::from(vec![2u64, 5, 6, 9]).into_array(),
+            PrimitiveArray::from_vec(vec![1.5f64, 2.5, 3.5, 4.5], Validity::AllValid).into_array(),
+            10,
+            Scalar::null(DType::Primitive(PType::F64, Nullability::Nullable)),
+        )
+        .into_array();
+        // Positions 1..10 of the original array, keeping original positions 2, 3, 6 and 9.
+        let sliced = slice(&sparse, 1, 10).unwrap();
+        let mask = BoolArray::from(vec![
+            false, true, true, false, false, true, false, false, true,
+        ])
+        .into_array();
+
+        let filtered = SparseArray::try_from(filter(&sliced, &mask).unwrap()).unwrap();
+        assert_eq!(filtered.len(), 4);
+        assert_eq!(
+            filtered.indices().into_primitive().typed_data::<u64>(),
+            [0, 2, 3]
+        );
+        assert_eq!(
+            filtered.values().into_primitive().typed_data::<f64>(),
+            [1.5, 3.5, 4.5]
+        );
+    }
+}
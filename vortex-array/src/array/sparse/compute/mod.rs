@@ -8,12 +8,14 @@ use vortex_scalar::Scalar;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::sparse::SparseArray;
 use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
+use crate::compute::filter::FilterFn;
 use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
 use crate::compute::slice::SliceFn;
 use crate::compute::take::{take, TakeFn};
 use crate::compute::ArrayCompute;
 use crate::{Array, ArrayDType, ArrayTrait, IntoArray};
 
+mod filter;
 mod slice;
 
 impl ArrayCompute for SparseArray {
@@ -21,6 +23,10 @@ impl ArrayCompute for SparseArray {
         Some(self)
     }
 
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -1,25 +1,38 @@
+use std::cmp::Ordering;
+use std::ops::{BitAnd, BitOr};
 use std::sync::Arc;
 
 use arrow_array::{
     Array as ArrowArray, ArrayRef as ArrowArrayRef, StructArray as ArrowStructArray,
 };
+use arrow_buffer::BooleanBuffer;
 use arrow_schema::{Field, Fields};
 use itertools::Itertools;
-use vortex_error::VortexResult;
+use vortex_dtype::field_paths::FieldIdentifier;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_expr::expressions::{Conjunction, Disjunction, Predicate};
 use vortex_scalar::Scalar;
 
+use crate::array::bool::BoolArray;
 use crate::array::r#struct::StructArray;
 use crate::compute::as_arrow::{as_arrow, AsArrowArray};
 use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
+use crate::compute::filter::{filter, FilterFn};
+use crate::compute::filter_indices::{filter_indices, FilterIndicesFn};
 use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
 use crate::compute::slice::{slice, SliceFn};
+use crate::compute::sort::{argsort_by, comparator, with_nulls, ArgsortFn, Comparator};
 use crate::compute::take::{take, TakeFn};
 use crate::compute::ArrayCompute;
-use crate::validity::Validity;
+use crate::validity::{ArrayValidity, Validity};
 use crate::ArrayTrait;
 use crate::{Array, ArrayDType, IntoArray};
 
 impl ArrayCompute for StructArray {
+    fn argsort(&self) -> Option<&dyn ArgsortFn> {
+        Some(self)
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         Some(self)
     }
@@ -28,6 +41,14 @@ impl ArrayCompute for StructArray {
         Some(self)
     }
 
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn filter_indices(&self) -> Option<&dyn FilterIndicesFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -41,6 +62,42 @@ impl ArrayCompute for StructArray {
     }
 }
 
+impl StructArray {
+    pub(crate) fn comparator(
+        &self,
+        descending: bool,
+        nulls_first: bool,
+    ) -> VortexResult<Comparator> {
+        // Fields are compared in order, each one breaking the ties of those before it.
+        let fields = self
+            .children()
+            .map(|f| comparator(&f, descending, nulls_first))
+            .collect::<VortexResult<Vec<_>>>()?;
+        // The fields already account for the direction of the sort.
+        Ok(with_nulls(
+            self.logical_validity().to_null_buffer()?,
+            false,
+            nulls_first,
+            move |a, b| {
+                fields
+                    .iter()
+                    .map(|cmp| cmp(a, b))
+                    .find(|o| o.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            },
+        ))
+    }
+}
+
+impl ArgsortFn for StructArray {
+    fn argsort(&self, descending: bool, nulls_first: bool) -> VortexResult<Array> {
+        Ok(argsort_by(
+            self.len(),
+            self.comparator(descending, nulls_first)?,
+        ))
+    }
+}
+
 impl AsArrowArray for StructArray {
     fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
         let field_arrays: Vec<ArrowArrayRef> =
@@ -101,6 +158,78 @@ impl AsContiguousFn for StructArray {
     }
 }
 
+impl FilterFn for StructArray {
+    fn filter(&self, mask: &Array) -> VortexResult<Array> {
+        let len = mask
+            .clone()
+            .flatten_bool()?
+            .boolean_buffer()
+            .count_set_bits();
+        Self::try_new(
+            self.names().clone(),
+            self.children()
+                .map(|field| filter(&field, mask))
+                .try_collect()?,
+            len,
+            self.validity().filter(mask)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl FilterIndicesFn for StructArray {
+    fn filter_indices(&self, predicate: &Disjunction) -> VortexResult<Array> {
+        let conjunction_indices = predicate.conjunctions.iter().map(|conj| {
+            conj.predicates
+                .iter()
+                .map(|pred| indices_matching_predicate(self, pred))
+                .reduce(|a, b| Ok(a?.bitand(&b?)))
+                .unwrap()
+        });
+        let present_buf = self
+            .validity()
+            .to_logical(self.len())
+            .to_present_null_buffer()?
+            .into_inner();
+
+        let bitset: VortexResult<BooleanBuffer> = conjunction_indices
+            .reduce(|a, b| Ok(a?.bitor(&b?)))
+            .map(|bitset| Ok(bitset?.bitand(&present_buf)))
+            .unwrap_or_else(|| Ok(BooleanBuffer::new_set(self.len())));
+
+        Ok(BoolArray::from(bitset?).into_array())
+    }
+}
+
+/// Evaluate the predicate against the field it references, with the field's own path.
+fn indices_matching_predicate(
+    arr: &StructArray,
+    predicate: &Predicate,
+) -> VortexResult<BooleanBuffer> {
+    let Some(FieldIdentifier::Name(name)) = predicate.left.head() else {
+        vortex_bail!("Invalid path {} for struct array", predicate.left)
+    };
+    let field = arr
+        .names()
+        .iter()
+        .position(|n| n.as_ref() == name)
+        .and_then(|idx| arr.field(idx))
+        .ok_or_else(|| vortex_err!("Field {} not found in struct array", name))?;
+
+    let field_predicate = Disjunction {
+        conjunctions: vec![Conjunction {
+            predicates: vec![Predicate {
+                left: predicate.left.tail().unwrap(),
+                op: predicate.op,
+                right: predicate.right.clone(),
+            }],
+        }],
+    };
+    Ok(filter_indices(&field, &field_predicate)?
+        .flatten_bool()?
+        .boolean_buffer())
+}
+
 impl ScalarAtFn for StructArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         Ok(Scalar::r#struct(
@@ -141,3 +270,147 @@ impl SliceFn for StructArray {
         .map(|a| a.into_array())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use vortex_dtype::field_paths::{field, FieldPath};
+    use vortex_dtype::FieldName;
+    use vortex_expr::expressions::{lit, Conjunction, Disjunction};
+    use vortex_expr::field_paths::FieldPathOperations;
+    use vortex_scalar::Scalar;
+
+    use crate::array::bool::BoolArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::r#struct::StructArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::compute::filter::filter;
+    use crate::compute::filter_indices::filter_indices;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::sort::argsort;
+    use crate::validity::{ArrayValidity, Validity};
+    use crate::{ArrayTrait, IntoArray};
+
+    #[test]
+    fn test_filter() {
+        let array = StructArray::try_new(
+            vec![FieldName::from("a"), FieldName::from("b")].into(),
+            vec![
+                PrimitiveArray::from(vec![1i32, 2, 3, 4]).into_array(),
+                VarBinArray::from(vec!["w", "x", "y", "z"]).into_array(),
+            ],
+            4,
+            Validity::from(vec![true, false, true, true]),
+        )
+        .unwrap()
+        .into_array();
+        let mask = BoolArray::from(vec![false, true, true, false]).into_array();
+
+        let filtered = StructArray::try_from(filter(&array, &mask).unwrap()).unwrap();
+        assert_eq!(filtered.len(), 2);
+        assert!(!filtered.is_valid(0));
+        assert!(filtered.is_valid(1));
+        assert_eq!(
+            filtered
+                .field(0)
+                .unwrap()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<i32>(),
+            &[2, 3]
+        );
+        assert_eq!(
+            scalar_at(&filtered.field(1).unwrap(), 1).unwrap(),
+            Scalar::from("y")
+        );
+    }
+
+    #[test]
+    fn test_filter_indices() {
+        let nested = StructArray::try_new(
+            vec![FieldName::from("y")].into(),
+            vec![PrimitiveArray::from(vec![10u16, 20, 30, 40, 50]).into_array()],
+            5,
+            Validity::NonNullable,
+        )
+        .unwrap();
+        let array = StructArray::try_new(
+            vec![FieldName::from("a"), FieldName::from("b")].into(),
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![
+                    Some(1i32),
+                    None,
+                    Some(3),
+                    Some(4),
+                    Some(5),
+                ])
+                .into_array(),
+                nested.into_array(),
+            ],
+            5,
+            Validity::from(vec![true, true, true, false, true]),
+        )
+        .unwrap()
+        .into_array();
+
+        let predicate = Disjunction {
+            conjunctions: vec![
+                Conjunction {
+                    predicates: vec![field("a").gt(lit(1i32)), field("a").lt(lit(5i32))],
+                },
+                Conjunction {
+                    predicates: vec![FieldPath::builder()
+                        .join("b")
+                        .join("y")
+                        .build()
+                        .gte(lit(50u16))],
+                },
+            ],
+        };
+        let matches = filter_indices(&array, &predicate)
+            .unwrap()
+            .flatten_bool()
+            .unwrap()
+            .boolean_buffer()
+            .set_indices()
+            .collect_vec();
+        // Row 1 has a null field and row 3 is a null struct.
+        assert_eq!(matches, vec![2, 4]);
+
+        assert!(filter_indices(
+            &array,
+            &Disjunction {
+                conjunctions: vec![Conjunction {
+                    predicates: vec![field("missing").eq(lit(1i32))],
+                }],
+            }
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_argsort() {
+        let array = StructArray::try_new(
+            vec![FieldName::from("a"), FieldName::from("b")].into(),
+            vec![
+                PrimitiveArray::from(vec![2i32, 1, 2, 1, 0]).into_array(),
+                VarBinArray::from(vec!["x", "z", "w", "y", "v"]).into_array(),
+            ],
+            5,
+            Validity::from(vec![true, true, true, true, false]),
+        )
+        .unwrap()
+        .into_array();
+
+        let indices = |descending, nulls_first| {
+            argsort(&array, descending, nulls_first)
+                .unwrap()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<u64>()
+                .to_vec()
+        };
+        assert_eq!(indices(false, false), vec![3, 1, 2, 0, 4]);
+        assert_eq!(indices(true, true), vec![4, 0, 2, 1, 3]);
+    }
+}
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use vortex_dtype::{FieldNames, Nullability, StructDType};
+use vortex_dtype::{FieldNames, StructDType};
 use vortex_error::vortex_bail;
 
 use crate::stats::ArrayStatisticsCompute;
@@ -75,6 +75,7 @@ impl StructArray {
 
         let field_dtypes: Vec<_> = fields.iter().map(|d| d.dtype()).cloned().collect();
 
+        let nullability = validity.nullability();
         let validity_metadata = validity.to_metadata(length)?;
 
         let mut children = vec![];
@@ -84,10 +85,7 @@ impl StructArray {
         }
 
         Self::try_from_parts(
-            DType::Struct(
-                StructDType::new(names, field_dtypes),
-                Nullability::NonNullable,
-            ),
+            DType::Struct(StructDType::new(names, field_dtypes), nullability),
             StructMetadata {
                 length,
                 validity: validity_metadata,
@@ -123,12 +121,12 @@ impl ArrayTrait for StructArray {
 }
 
 impl ArrayValidity for StructArray {
-    fn is_valid(&self, _index: usize) -> bool {
-        todo!()
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
     }
 
     fn logical_validity(&self) -> LogicalValidity {
-        todo!()
+        self.validity().to_logical(self.len())
     }
 }
 
@@ -145,3 +143,42 @@ impl AcceptArrayVisitor for StructArray {
 impl ArrayStatisticsCompute for StructArray {}
 
 impl EncodingCompression for StructEncoding {}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, FieldName, Nullability};
+
+    use crate::array::bool::BoolArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::r#struct::StructArray;
+    use crate::validity::Validity;
+    use crate::{ArrayDType, IntoArray};
+
+    #[test]
+    fn nullability_from_validity() {
+        let nullability = |validity| {
+            let array = StructArray::try_new(
+                vec![FieldName::from("a")].into(),
+                vec![PrimitiveArray::from(vec![1i32, 2, 3]).into_array()],
+                3,
+                validity,
+            )
+            .unwrap();
+            let DType::Struct(_, n) = array.dtype() else {
+                unreachable!()
+            };
+            assert_eq!(array.dtype().is_nullable(), *n == Nullability::Nullable);
+            *n
+        };
+
+        assert_eq!(nullability(Validity::NonNullable), Nullability::NonNullable);
+        assert_eq!(nullability(Validity::AllValid), Nullability::Nullable);
+        assert_eq!(nullability(Validity::AllInvalid), Nullability::Nullable);
+        assert_eq!(
+            nullability(Validity::Array(
+                BoolArray::from(vec![true, false, true]).into_array()
+            )),
+            Nullability::Nullable
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef as ArrowArrayRef, UnionArray as ArrowUnionArray};
+use arrow_buffer::Buffer;
+use arrow_schema::Field;
+use itertools::Itertools;
+use vortex_dtype::PType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::array::union::{UnionArray, UnionMode};
+use crate::compute::as_arrow::{as_arrow, AsArrowArray};
+use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
+use crate::compute::cast::cast;
+use crate::compute::scalar_at::{scalar_at, ScalarAtFn};
+use crate::compute::slice::{slice, SliceFn};
+use crate::compute::take::{take, TakeFn};
+use crate::compute::ArrayCompute;
+use crate::{Array, ArrayDType, IntoArray};
+
+impl ArrayCompute for UnionArray {
+    fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
+        Some(self)
+    }
+
+    fn as_contiguous(&self) -> Option<&dyn AsContiguousFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl AsArrowArray for UnionArray {
+    fn as_arrow(&self) -> VortexResult<ArrowArrayRef> {
+        let type_ids = self.type_ids().flatten_primitive()?;
+        let type_ids = Buffer::from_iter(type_ids.typed_data::<u8>().iter().map(|&t| t as i8));
+        let offsets = self
+            .offsets()
+            .map(|offsets| {
+                cast(&offsets, PType::I32.into())?
+                    .flatten_primitive()
+                    .map(|o| o.scalar_buffer::<i32>().into_inner())
+            })
+            .transpose()?;
+        let children = self
+            .names()
+            .iter()
+            .zip(self.variants())
+            .map(|(name, variant)| {
+                let child = as_arrow(&variant)?;
+                let field = Field::new(
+                    name.as_ref(),
+                    child.data_type().clone(),
+                    variant.dtype().is_nullable(),
+                );
+                Ok((field, child))
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        Ok(Arc::new(ArrowUnionArray::try_new(
+            &(0..self.nvariants() as i8).collect_vec(),
+            type_ids,
+            offsets,
+            children,
+        )?))
+    }
+}
+
+impl AsContiguousFn for UnionArray {
+    fn as_contiguous(&self, arrays: &[Array]) -> VortexResult<Array> {
+        let unions: Vec<Self> = arrays.iter().map(Self::try_from).try_collect()?;
+        if unions.iter().any(|u| u.mode() != self.mode()) {
+            vortex_bail!("Can't concatenate sparse and dense unions");
+        }
+
+        let type_ids = as_contiguous(&unions.iter().map(|u| u.type_ids()).collect_vec())?;
+        let variants = (0..self.nvariants())
+            .map(|idx| as_contiguous(&unions.iter().map(|u| u.variant(idx).unwrap()).collect_vec()))
+            .try_collect()?;
+
+        let offsets = match self.mode() {
+            UnionMode::Sparse => None,
+            UnionMode::Dense => {
+                // The offsets of each union are shifted past the values of the preceding unions.
+                let mut variant_starts = vec![0u32; self.nvariants()];
+                let mut offsets = Vec::with_capacity(type_ids.len());
+                for union in &unions {
+                    let type_ids = union.type_ids().flatten_primitive()?;
+                    let union_offsets =
+                        cast(&union.offsets().unwrap(), PType::U32.into())?.flatten_primitive()?;
+                    offsets.extend(
+                        type_ids
+                            .typed_data::<u8>()
+                            .iter()
+                            .zip(union_offsets.typed_data::<u32>())
+                            .map(|(&t, &o)| variant_starts[t as usize] + o),
+                    );
+                    for (start, variant) in variant_starts.iter_mut().zip(union.variants()) {
+                        *start += variant.len() as u32;
+                    }
+                }
+                Some(PrimitiveArray::from(offsets).into_array())
+            }
+        };
+
+        Self::try_new(
+            self.names().clone(),
+            type_ids,
+            offsets,
+            variants,
+            self.dtype().nullability(),
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl ScalarAtFn for UnionArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        let type_id = self.type_id_at(index)?;
+        let Some(variant) = self.variant(type_id as usize) else {
+            vortex_bail!("Invalid type id {} at index {}", type_id, index);
+        };
+        let value = scalar_at(&variant, self.value_index(index)?)?;
+        Ok(Scalar::union(
+            self.dtype().clone(),
+            type_id,
+            value.into_value(),
+        ))
+    }
+}
+
+impl SliceFn for UnionArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        let type_ids = slice(&self.type_ids(), start, stop)?;
+        match self.offsets() {
+            // Sparse variants line up with the type ids, so they're sliced along with them.
+            None => Self::try_new(
+                self.names().clone(),
+                type_ids,
+                None,
+                self.variants()
+                    .map(|v| slice(&v, start, stop))
+                    .try_collect()?,
+                self.dtype().nullability(),
+            ),
+            Some(offsets) => Self::try_new(
+                self.names().clone(),
+                type_ids,
+                Some(slice(&offsets, start, stop)?),
+                self.variants().collect(),
+                self.dtype().nullability(),
+            ),
+        }
+        .map(|a| a.into_array())
+    }
+}
+
+impl TakeFn for UnionArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let type_ids = take(&self.type_ids(), indices)?;
+        match self.offsets() {
+            None => Self::try_new(
+                self.names().clone(),
+                type_ids,
+                None,
+                self.variants().map(|v| take(&v, indices)).try_collect()?,
+                self.dtype().nullability(),
+            ),
+            Some(offsets) => Self::try_new(
+                self.names().clone(),
+                type_ids,
+                Some(take(&offsets, indices)?),
+                self.variants().collect(),
+                self.dtype().nullability(),
+            ),
+        }
+        .map(|a| a.into_array())
+    }
+}
@@ -0,0 +1,442 @@
+use serde::{Deserialize, Serialize};
+use vortex_dtype::{FieldNames, Nullability, PType, UnionDType};
+use vortex_error::vortex_bail;
+
+use crate::compute::scalar_at::scalar_at;
+use crate::stats::ArrayStatisticsCompute;
+use crate::validity::{ArrayValidity, LogicalValidity, Validity};
+use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::{impl_encoding, ArrayDType, ArrayFlatten, IntoArrayData};
+
+mod compute;
+
+impl_encoding!("vortex.union", Union);
+
+/// How the values of each variant are laid out, following the Arrow union layouts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnionMode {
+    /// Every variant has a value for every row, and only the value of the row's variant is used.
+    Sparse,
+    /// Each variant only holds the values of its own rows, which are found through the offsets.
+    Dense,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UnionMetadata {
+    mode: UnionMode,
+    offsets_dtype: Option<DType>,
+}
+
+impl UnionArray {
+    /// The dtype of the type ids, which hold the index of each row's variant.
+    pub const TYPE_IDS_DTYPE: DType = DType::Primitive(PType::U8, Nullability::NonNullable);
+
+    /// Unions are limited to the number of variants an Arrow union can hold.
+    pub const MAX_VARIANTS: usize = 128;
+
+    /// Create a union array, where the value at index `i` belongs to the variant
+    /// `type_ids[i]`. Dense unions pass the offset of each value within its variant, while sparse
+    /// unions pass `None` and have variants with one value per row.
+    ///
+    /// A value is null when the value of its variant is null, so only nullable unions may have
+    /// nullable variants.
+    pub fn try_new(
+        names: FieldNames,
+        type_ids: Array,
+        offsets: Option<Array>,
+        variants: Vec<Array>,
+        nullability: Nullability,
+    ) -> VortexResult<Self> {
+        if names.len() != variants.len() {
+            vortex_bail!("Got {} names and {} variants", names.len(), variants.len());
+        }
+        if nullability == Nullability::NonNullable
+            && variants.iter().any(|v| v.dtype().is_nullable())
+        {
+            vortex_bail!("A non-nullable union can't have nullable variants");
+        }
+        if variants.len() > Self::MAX_VARIANTS {
+            vortex_bail!(
+                "Unions support at most {} variants, got {}",
+                Self::MAX_VARIANTS,
+                variants.len()
+            );
+        }
+        if type_ids.dtype() != &Self::TYPE_IDS_DTYPE {
+            vortex_bail!(MismatchedTypes: Self::TYPE_IDS_DTYPE, type_ids.dtype());
+        }
+
+        let length = type_ids.len();
+        let mode = match &offsets {
+            None => {
+                if variants.iter().any(|v| v.len() != length) {
+                    vortex_bail!(
+                        "Expected all sparse union variants to have length {}",
+                        length
+                    );
+                }
+                UnionMode::Sparse
+            }
+            Some(offsets) => {
+                if !offsets.dtype().is_int() || offsets.dtype().is_nullable() {
+                    vortex_bail!(MismatchedTypes: "non nullable int", offsets.dtype());
+                }
+                if offsets.len() != length {
+                    vortex_bail!(
+                        "Expected {} dense union offsets, got {}",
+                        length,
+                        offsets.len()
+                    );
+                }
+                UnionMode::Dense
+            }
+        };
+
+        let metadata = UnionMetadata {
+            mode,
+            offsets_dtype: offsets.as_ref().map(|o| o.dtype().clone()),
+        };
+        let dtype = DType::Union(
+            UnionDType::new(names, variants.iter().map(|v| v.dtype().clone()).collect()),
+            nullability,
+        );
+
+        let mut children = Vec::with_capacity(variants.len() + 2);
+        children.push(type_ids.into_array_data());
+        if let Some(offsets) = offsets {
+            children.push(offsets.into_array_data());
+        }
+        children.extend(variants.into_iter().map(|v| v.into_array_data()));
+
+        Self::try_from_parts(dtype, metadata, children.into(), StatsSet::new())
+    }
+
+    pub fn union_dtype(&self) -> &UnionDType {
+        let DType::Union(union_dtype, _) = self.dtype() else {
+            unreachable!()
+        };
+        union_dtype
+    }
+
+    pub fn names(&self) -> &FieldNames {
+        self.union_dtype().names()
+    }
+
+    pub fn nvariants(&self) -> usize {
+        self.union_dtype().dtypes().len()
+    }
+
+    #[inline]
+    pub fn mode(&self) -> UnionMode {
+        self.metadata().mode
+    }
+
+    #[inline]
+    pub fn type_ids(&self) -> Array {
+        self.array()
+            .child(0, &Self::TYPE_IDS_DTYPE)
+            .expect("missing type ids")
+    }
+
+    /// The offset of each value within its variant, only present for dense unions.
+    #[inline]
+    pub fn offsets(&self) -> Option<Array> {
+        self.metadata()
+            .offsets_dtype
+            .as_ref()
+            .map(|dtype| self.array().child(1, dtype).expect("missing offsets"))
+    }
+
+    pub fn variant(&self, idx: usize) -> Option<Array> {
+        let dtype = self.union_dtype().dtypes().get(idx)?;
+        let first_variant = match self.mode() {
+            UnionMode::Sparse => 1,
+            UnionMode::Dense => 2,
+        };
+        self.array().child(first_variant + idx, dtype)
+    }
+
+    pub fn variants(&self) -> impl Iterator<Item = Array> + '_ {
+        (0..self.nvariants()).map(move |idx| self.variant(idx).unwrap())
+    }
+
+    pub fn type_id_at(&self, index: usize) -> VortexResult<u8> {
+        scalar_at(&self.type_ids(), index)?.as_ref().try_into()
+    }
+
+    /// The index of the value at the given index within its variant.
+    pub fn value_index(&self, index: usize) -> VortexResult<usize> {
+        match self.offsets() {
+            None => Ok(index),
+            Some(offsets) => scalar_at(&offsets, index)?.as_ref().try_into(),
+        }
+    }
+}
+
+impl ArrayFlatten for UnionArray {
+    fn flatten(self) -> VortexResult<Flattened> {
+        Ok(Flattened::Union(Self::try_new(
+            self.names().clone(),
+            self.type_ids().flatten_primitive()?.into_array(),
+            self.offsets()
+                .map(|o| o.flatten_primitive().map(|o| o.into_array()))
+                .transpose()?,
+            self.variants()
+                .map(|v| v.flatten().map(|v| v.into_array()))
+                .collect::<VortexResult<Vec<_>>>()?,
+            self.dtype().nullability(),
+        )?))
+    }
+}
+
+impl ArrayTrait for UnionArray {
+    fn len(&self) -> usize {
+        self.type_ids().len()
+    }
+}
+
+impl ArrayValidity for UnionArray {
+    fn is_valid(&self, index: usize) -> bool {
+        if !self.dtype().is_nullable() {
+            return true;
+        }
+        let type_id = self.type_id_at(index).expect("invalid type id");
+        let value_index = self.value_index(index).expect("invalid offset");
+        self.variant(type_id as usize)
+            .expect("invalid type id")
+            .with_dyn(|a| a.is_valid(value_index))
+    }
+
+    /// A value of a nullable union is valid when the value of its variant is valid.
+    fn logical_validity(&self) -> LogicalValidity {
+        if !self.dtype().is_nullable() {
+            return LogicalValidity::AllValid(self.len());
+        }
+        Validity::from(
+            (0..self.len())
+                .map(|i| self.is_valid(i))
+                .collect::<Vec<_>>(),
+        )
+        .to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for UnionArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("type_ids", &self.type_ids())?;
+        if let Some(offsets) = self.offsets() {
+            visitor.visit_child("offsets", &offsets)?;
+        }
+        for (name, variant) in self.names().iter().zip(self.variants()) {
+            visitor.visit_child(&format!("\"{}\"", name), &variant)?;
+        }
+        Ok(())
+    }
+}
+
+impl ArrayStatisticsCompute for UnionArray {}
+
+impl EncodingCompression for UnionEncoding {}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef as ArrowArrayRef, Int32Array};
+    use arrow_array::{Float64Array, UnionArray as ArrowUnionArray};
+    use arrow_buffer::Buffer;
+    use arrow_schema::{DataType, Field};
+    use vortex_dtype::Nullability;
+    use vortex_scalar::{Scalar, UnionScalar};
+
+    use crate::array::primitive::PrimitiveArray;
+    use crate::array::union::{UnionArray, UnionMode};
+    use crate::array::varbin::VarBinArray;
+    use crate::arrow::{FromArrowArray, TryFromArrowArray};
+    use crate::compute::as_arrow::as_arrow;
+    use crate::compute::as_contiguous::as_contiguous;
+    use crate::compute::scalar_at::scalar_at;
+    use crate::compute::slice::slice;
+    use crate::compute::take::take;
+    use crate::validity::ArrayValidity;
+    use crate::{Array, ArrayDType, ArrayData, IntoArray};
+
+    fn variant(array: &Array, index: usize) -> (u8, Scalar) {
+        let scalar = scalar_at(array, index).unwrap();
+        let union = UnionScalar::try_from(&scalar).unwrap();
+        (union.variant_index().unwrap(), union.value().unwrap())
+    }
+
+    fn dense() -> Array {
+        UnionArray::try_new(
+            vec!["int".into(), "str".into()].into(),
+            PrimitiveArray::from(vec![0u8, 1, 1, 0]).into_array(),
+            Some(PrimitiveArray::from(vec![0u32, 0, 1, 1]).into_array()),
+            vec![
+                PrimitiveArray::from(vec![1i32, 2]).into_array(),
+                VarBinArray::from(vec!["a", "b"]).into_array(),
+            ],
+            Nullability::NonNullable,
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    #[test]
+    fn nullability() {
+        let union = dense();
+        assert!(!union.dtype().is_nullable());
+        assert!(Scalar::null(union.dtype().as_nullable()).is_null());
+
+        let variants = || {
+            vec![
+                PrimitiveArray::from_nullable_vec(vec![Some(1i32), None]).into_array(),
+                VarBinArray::from(vec!["a", "b"]).into_array(),
+            ]
+        };
+        let type_ids = PrimitiveArray::from(vec![0u8, 0, 1]).into_array();
+        let offsets = PrimitiveArray::from(vec![0u32, 1, 0]).into_array();
+        assert!(UnionArray::try_new(
+            vec!["int".into(), "str".into()].into(),
+            type_ids.clone(),
+            Some(offsets.clone()),
+            variants(),
+            Nullability::NonNullable,
+        )
+        .is_err());
+
+        let nullable = UnionArray::try_new(
+            vec!["int".into(), "str".into()].into(),
+            type_ids,
+            Some(offsets),
+            variants(),
+            Nullability::Nullable,
+        )
+        .unwrap();
+        assert!(nullable.dtype().is_nullable());
+        assert_eq!(
+            nullable
+                .logical_validity()
+                .to_null_buffer()
+                .unwrap()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+    }
+
+    #[test]
+    fn dense_out_of_order_offsets() {
+        // [2, "b", 1, "a"], where each variant's values are read back to front.
+        let union = UnionArray::try_new(
+            vec!["int".into(), "str".into()].into(),
+            PrimitiveArray::from(vec![0u8, 1, 0, 1]).into_array(),
+            Some(PrimitiveArray::from(vec![1u32, 1, 0, 0]).into_array()),
+            vec![
+                PrimitiveArray::from(vec![1i32, 2]).into_array(),
+                VarBinArray::from(vec!["a", "b"]).into_array(),
+            ],
+            Nullability::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        assert_eq!(variant(&union, 0), (0, Scalar::from(2i32)));
+        assert_eq!(variant(&union, 1), (1, Scalar::from("b")));
+        assert_eq!(variant(&union, 3), (1, Scalar::from("a")));
+
+        let sliced = slice(&union, 1, 4).unwrap();
+        assert_eq!(variant(&sliced, 1), (0, Scalar::from(1i32)));
+        let taken = take(&sliced, &PrimitiveArray::from(vec![2u32, 0]).into_array()).unwrap();
+        assert_eq!(variant(&taken, 0), (1, Scalar::from("a")));
+        assert_eq!(variant(&taken, 1), (1, Scalar::from("b")));
+
+        // Concatenation shifts the offsets past the variants of the preceding unions.
+        let concatenated = as_contiguous(&[union.clone(), taken]).unwrap();
+        assert_eq!(
+            UnionArray::try_from(&concatenated)
+                .unwrap()
+                .offsets()
+                .unwrap()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<u32>(),
+            &[1, 1, 0, 0, 2, 3]
+        );
+        assert_eq!(variant(&concatenated, 4), (1, Scalar::from("a")));
+        assert_eq!(variant(&concatenated, 5), (1, Scalar::from("b")));
+
+        // Arrow doesn't require dense offsets to be ordered, so they're passed through as they are.
+        let arrow: ArrowArrayRef = as_arrow(&union).unwrap();
+        let arrow_union = arrow.as_any().downcast_ref::<ArrowUnionArray>().unwrap();
+        assert_eq!(arrow_union.offsets().unwrap().as_ref(), &[1, 1, 0, 0]);
+        let round_tripped = ArrayData::from_arrow(arrow.clone(), false).into_array();
+        assert_eq!(variant(&round_tripped, 0), (0, Scalar::from(2i32)));
+        assert_eq!(as_arrow(&round_tripped).unwrap().as_ref(), arrow.as_ref());
+    }
+
+    #[test]
+    fn arrow_sparse_type_ids() {
+        let children: Vec<(Field, ArrowArrayRef)> = vec![
+            (
+                Field::new("int", DataType::Int32, true),
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+            ),
+            (
+                Field::new("float", DataType::Float64, false),
+                Arc::new(Float64Array::from(vec![1.5, 2.5, 3.5])),
+            ),
+        ];
+        let sparse: ArrowArrayRef = Arc::new(
+            ArrowUnionArray::try_new(&[3, 7], Buffer::from_slice_ref([3i8, 7, 3]), None, children)
+                .unwrap(),
+        );
+        let array = ArrayData::from_arrow(sparse.clone(), true).into_array();
+        let union = UnionArray::try_from(&array).unwrap();
+        assert_eq!(union.mode(), UnionMode::Sparse);
+        assert_eq!(variant(&array, 1), (1, Scalar::from(2.5f64)));
+        // Arrow type ids are re-numbered, so compare the exported values instead.
+        let exported = as_arrow(&array).unwrap();
+        let exported = exported.as_any().downcast_ref::<ArrowUnionArray>().unwrap();
+        assert_eq!(exported.type_ids().as_ref(), &[0, 1, 0]);
+        assert_eq!(
+            exported.child(0).as_ref(),
+            sparse_child(&sparse, 3).as_ref()
+        );
+    }
+
+    #[test]
+    fn arrow_invalid_type_ids() {
+        for type_id in [-1i8, 5] {
+            let children: Vec<(Field, ArrowArrayRef)> = vec![
+                (
+                    Field::new("int", DataType::Int32, false),
+                    Arc::new(Int32Array::from(vec![1, 2])),
+                ),
+                (
+                    Field::new("float", DataType::Float64, false),
+                    Arc::new(Float64Array::from(vec![1.5, 2.5])),
+                ),
+            ];
+            // Arrow only validates the type ids of a union when it's built with `try_new`.
+            let arrow: ArrowArrayRef = Arc::new(unsafe {
+                ArrowUnionArray::new_unchecked(
+                    &[0, 1],
+                    Buffer::from_slice_ref([0i8, type_id]),
+                    None,
+                    children,
+                )
+            });
+            assert!(ArrayData::try_from_arrow(arrow, false).is_err());
+        }
+    }
+
+    fn sparse_child(array: &ArrowArrayRef, type_id: i8) -> ArrowArrayRef {
+        array
+            .as_any()
+            .downcast_ref::<ArrowUnionArray>()
+            .unwrap()
+            .child(type_id)
+            .clone()
+    }
+}
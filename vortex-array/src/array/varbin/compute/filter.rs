@@ -0,0 +1,85 @@
+use arrow_buffer::BooleanBuffer;
+use vortex_dtype::{match_each_integer_ptype, NativePType};
+use vortex_error::VortexResult;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::array::varbin::VarBinArray;
+use crate::compute::filter::FilterFn;
+use crate::{Array, ArrayDType, IntoArray};
+
+impl FilterFn for VarBinArray {
+    fn filter(&self, mask: &Array) -> VortexResult<Array> {
+        let validity = self.validity().filter(mask)?;
+        let mask = mask.clone().flatten_bool()?.boolean_buffer();
+        let offsets = self.offsets().flatten_primitive()?;
+        let data = self.bytes().flatten_primitive()?;
+        match_each_integer_ptype!(offsets.ptype(), |$O| {
+            let (offsets, data) = filter_varbin(offsets.typed_data::<$O>(), data.typed_data::<u8>(), &mask);
+            Self::try_new(
+                PrimitiveArray::from(offsets).into_array(),
+                PrimitiveArray::from(data).into_array(),
+                self.dtype().clone(),
+                validity,
+            ).map(|a| a.into_array())
+        })
+    }
+}
+
+/// Filter the offsets and bytes of a varbin array, copying the bytes of each run of set bits
+/// at once.
+fn filter_varbin<O: NativePType>(
+    offsets: &[O],
+    data: &[u8],
+    mask: &BooleanBuffer,
+) -> (Vec<O>, Vec<u8>) {
+    let mut filtered_offsets = Vec::with_capacity(mask.count_set_bits() + 1);
+    filtered_offsets.push(O::zero());
+    let mut filtered_data = Vec::new();
+    for (start, end) in mask.set_slices() {
+        let base = filtered_data.len();
+        let first = offsets[start].to_usize().unwrap();
+        filtered_data.extend_from_slice(&data[first..offsets[end].to_usize().unwrap()]);
+        filtered_offsets.extend(
+            offsets[start + 1..=end]
+                .iter()
+                .map(|o| O::from(base + o.to_usize().unwrap() - first).unwrap()),
+        );
+    }
+    (filtered_offsets, filtered_data)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::bool::BoolArray;
+    use crate::array::varbin::VarBinArray;
+    use crate::compute::filter::filter;
+    use crate::compute::slice::slice;
+    use crate::validity::ArrayValidity;
+    use crate::{ArrayTrait, IntoArray};
+
+    #[test]
+    fn filter_sliced() {
+        let array = VarBinArray::from_iter(
+            vec![
+                Some("a"),
+                Some("bc"),
+                None,
+                Some("def"),
+                Some("g"),
+                Some("hi"),
+            ],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array();
+        let sliced = slice(&array, 1, 6).unwrap();
+        let mask = BoolArray::from(vec![true, true, false, true, true]).into_array();
+        let filtered = VarBinArray::try_from(filter(&sliced, &mask).unwrap()).unwrap();
+        assert_eq!(filtered.len(), 4);
+        assert!(!filtered.is_valid(1));
+        for (idx, value) in [(0, "bc"), (2, "g"), (3, "hi")] {
+            assert_eq!(filtered.bytes_at(idx).unwrap().as_slice(), value.as_bytes());
+        }
+    }
+}
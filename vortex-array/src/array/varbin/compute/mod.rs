@@ -15,17 +15,25 @@ use crate::arrow::wrappers::as_offset_buffer;
 use crate::compute::as_arrow::AsArrowArray;
 use crate::compute::as_contiguous::{as_contiguous, AsContiguousFn};
 use crate::compute::cast::cast;
+use crate::compute::filter::FilterFn;
 use crate::compute::scalar_at::ScalarAtFn;
 use crate::compute::slice::SliceFn;
+use crate::compute::sort::ArgsortFn;
 use crate::compute::take::TakeFn;
 use crate::compute::ArrayCompute;
 use crate::validity::{ArrayValidity, Validity};
 use crate::{Array, ArrayDType, IntoArray, ToArray};
 
+mod filter;
 mod slice;
+mod sort;
 mod take;
 
 impl ArrayCompute for VarBinArray {
+    fn argsort(&self) -> Option<&dyn ArgsortFn> {
+        Some(self)
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         Some(self)
     }
@@ -34,6 +42,10 @@ impl ArrayCompute for VarBinArray {
         Some(self)
     }
 
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
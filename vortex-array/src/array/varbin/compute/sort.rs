@@ -0,0 +1,71 @@
+use num_traits::AsPrimitive;
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::VortexResult;
+
+use crate::array::varbin::VarBinArray;
+use crate::compute::sort::{argsort_by, with_nulls, ArgsortFn, Comparator};
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayTrait};
+
+impl VarBinArray {
+    pub(crate) fn comparator(
+        &self,
+        descending: bool,
+        nulls_first: bool,
+    ) -> VortexResult<Comparator> {
+        let offsets = self.offsets().flatten_primitive()?;
+        let offsets: Vec<usize> = match_each_integer_ptype!(offsets.ptype(), |$O| {
+            offsets.typed_data::<$O>().iter().map(|o| o.as_()).collect()
+        });
+        let bytes = self.bytes().flatten_primitive()?;
+        Ok(with_nulls(
+            self.logical_validity().to_null_buffer()?,
+            descending,
+            nulls_first,
+            move |a, b| {
+                let data = bytes.typed_data::<u8>();
+                data[offsets[a]..offsets[a + 1]].cmp(&data[offsets[b]..offsets[b + 1]])
+            },
+        ))
+    }
+}
+
+impl ArgsortFn for VarBinArray {
+    fn argsort(&self, descending: bool, nulls_first: bool) -> VortexResult<Array> {
+        Ok(argsort_by(
+            self.len(),
+            self.comparator(descending, nulls_first)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::varbin::VarBinArray;
+    use crate::compute::sort::sort;
+    use crate::IntoArray;
+
+    #[test]
+    fn sort_strings() {
+        let array = VarBinArray::from_iter(
+            vec![Some("pear"), None, Some("apple"), Some("fig"), Some("")],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array();
+        let sorted = sort(&array, false, true).unwrap().flatten_varbin().unwrap();
+        let values = (1..5)
+            .map(|i| sorted.bytes_at(i).unwrap().as_slice().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![
+                b"".to_vec(),
+                b"apple".to_vec(),
+                b"fig".to_vec(),
+                b"pear".to_vec()
+            ]
+        );
+    }
+}
@@ -5,20 +5,27 @@ use arrow_buffer::Buffer as ArrowBuffer;
 use arrow_buffer::ScalarBuffer;
 use itertools::Itertools;
 use vortex_dtype::DType;
-use vortex_dtype::PType;
+use vortex_dtype::{match_each_integer_ptype, PType};
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
+use crate::array::primitive::PrimitiveArray;
 use crate::array::varbin::varbin_scalar;
 use crate::array::varbinview::{VarBinViewArray, VIEW_SIZE};
 use crate::compute::as_arrow::AsArrowArray;
 use crate::compute::scalar_at::ScalarAtFn;
 use crate::compute::slice::{slice, SliceFn};
+use crate::compute::sort::{argsort_by, with_nulls, ArgsortFn, Comparator};
+use crate::compute::take::TakeFn;
 use crate::compute::ArrayCompute;
 use crate::validity::ArrayValidity;
-use crate::{Array, ArrayDType, IntoArray, IntoArrayData};
+use crate::{Array, ArrayDType, ArrayTrait, IntoArray, IntoArrayData};
 
 impl ArrayCompute for VarBinViewArray {
+    fn argsort(&self) -> Option<&dyn ArgsortFn> {
+        Some(self)
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         Some(self)
     }
@@ -30,6 +37,67 @@ impl ArrayCompute for VarBinViewArray {
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl VarBinViewArray {
+    pub(crate) fn comparator(
+        &self,
+        descending: bool,
+        nulls_first: bool,
+    ) -> VortexResult<Comparator> {
+        let nulls = self.logical_validity().to_null_buffer()?;
+        let values = (0..self.len())
+            .map(|i| {
+                if nulls.as_ref().map(|n| n.is_valid(i)).unwrap_or(true) {
+                    self.bytes_at(i)
+                } else {
+                    Ok(Vec::new())
+                }
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(with_nulls(nulls, descending, nulls_first, move |a, b| {
+            values[a].cmp(&values[b])
+        }))
+    }
+}
+
+impl ArgsortFn for VarBinViewArray {
+    fn argsort(&self, descending: bool, nulls_first: bool) -> VortexResult<Array> {
+        Ok(argsort_by(
+            self.len(),
+            self.comparator(descending, nulls_first)?,
+        ))
+    }
+}
+
+impl TakeFn for VarBinViewArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        // Only the views are gathered, the data buffers they point into are kept as they are.
+        let views = self.views().flatten_primitive()?;
+        let views = views.typed_data::<u8>();
+        let primitive_indices = indices.clone().flatten_primitive()?;
+        let taken = match_each_integer_ptype!(primitive_indices.ptype(), |$I| {
+            primitive_indices
+                .typed_data::<$I>()
+                .iter()
+                .flat_map(|&i| &views[i as usize * VIEW_SIZE..(i as usize + 1) * VIEW_SIZE])
+                .copied()
+                .collect::<Vec<u8>>()
+        });
+        Self::try_new(
+            PrimitiveArray::from(taken).into_array(),
+            (0..self.metadata().n_children)
+                .map(|i| self.bytes(i))
+                .collect::<Vec<_>>(),
+            self.dtype().clone(),
+            self.validity().take(indices)?,
+        )
+        .map(|a| a.into_array())
+    }
 }
 
 impl ScalarAtFn for VarBinViewArray {
@@ -308,6 +308,7 @@ mod test {
     use crate::compute::as_arrow::as_arrow;
     use crate::compute::scalar_at::scalar_at;
     use crate::compute::slice::slice;
+    use crate::compute::sort::sort;
     use crate::{ArrayTrait, IntoArray};
 
     #[test]
@@ -357,4 +358,29 @@ mod test {
                 .collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    pub fn sort_views() {
+        let sorted = sort(
+            &VarBinViewArray::from(vec![
+                "b",
+                "hello world this is a long string",
+                "hello world",
+            ])
+            .into_array(),
+            true,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            (0..3)
+                .map(|i| scalar_at(&sorted, i).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Scalar::from("hello world this is a long string"),
+                Scalar::from("hello world"),
+                Scalar::from("b")
+            ]
+        );
+    }
 }
@@ -1,15 +1,19 @@
 use arrow_array::array::{
     Array as ArrowArray, ArrayRef as ArrowArrayRef, BooleanArray as ArrowBooleanArray,
-    GenericByteArray, NullArray as ArrowNullArray, PrimitiveArray as ArrowPrimitiveArray,
-    StructArray as ArrowStructArray,
+    FixedSizeBinaryArray as ArrowFixedSizeBinaryArray,
+    FixedSizeListArray as ArrowFixedSizeListArray, GenericByteArray, GenericListArray,
+    MapArray as ArrowMapArray, NullArray as ArrowNullArray, PrimitiveArray as ArrowPrimitiveArray,
+    StructArray as ArrowStructArray, UnionArray as ArrowUnionArray,
 };
 use arrow_array::array::{ArrowPrimitiveType, OffsetSizeTrait};
 use arrow_array::cast::{as_null_array, AsArray};
 use arrow_array::types::{
-    ByteArrayType, ByteViewType, Date32Type, Date64Type, DurationMicrosecondType,
-    DurationMillisecondType, DurationNanosecondType, DurationSecondType, Time32MillisecondType,
-    Time32SecondType, Time64MicrosecondType, Time64NanosecondType, TimestampMicrosecondType,
-    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType,
+    ByteArrayType, ByteViewType, Date32Type, Date64Type, Decimal128Type, Decimal256Type,
+    DecimalType, DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType,
+    DurationSecondType, IntervalDayTimeType, IntervalMonthDayNanoType, IntervalYearMonthType,
+    Time32MillisecondType, Time32SecondType, Time64MicrosecondType, Time64NanosecondType,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType,
 };
 use arrow_array::types::{
     Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
@@ -18,20 +22,31 @@ use arrow_array::types::{
 use arrow_array::{BinaryViewArray, GenericByteViewArray, StringViewArray};
 use arrow_buffer::buffer::{NullBuffer, OffsetBuffer};
 use arrow_buffer::{ArrowNativeType, Buffer, ScalarBuffer};
-use arrow_schema::{DataType, TimeUnit};
+use arrow_schema::{DataType, IntervalUnit, TimeUnit};
 use itertools::Itertools;
 use vortex_dtype::DType;
 use vortex_dtype::NativePType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::array::bool::BoolArray;
 use crate::array::constant::ConstantArray;
-use crate::array::datetime::LocalDateTimeArray;
+use crate::array::datetime::{
+    DateArray, DateUnit, DurationArray, IntervalArray, LocalDateTimeArray, TimeArray,
+    ZonedDateTimeArray,
+};
+use crate::array::decimal::DecimalArray;
+use crate::array::fixed_size_binary::FixedSizeBinaryArray;
+use crate::array::fixed_size_list::FixedSizeListArray;
+use crate::array::list::ListArray;
+use crate::array::map::MapArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::r#struct::StructArray;
+use crate::array::union::UnionArray;
 use crate::array::varbin::VarBinArray;
 use crate::array::varbinview::VarBinViewArray;
-use crate::arrow::FromArrowArray;
+use crate::arrow::dtype::decimal_precision_and_scale;
+use crate::arrow::{FromArrowArray, TryFromArrowArray};
 use crate::stats::{Stat, Statistics};
 use crate::validity::Validity;
 use crate::{ArrayData, IntoArray, IntoArrayData};
@@ -96,21 +111,45 @@ where
             return arr;
         }
 
-        match T::DATA_TYPE {
+        // The timezone of a timestamp is only known from the array's data type.
+        match value.data_type() {
             DataType::Timestamp(time_unit, tz) => match tz {
                 // A timestamp with no timezone is the equivalent of an "unknown" timezone.
                 // Therefore, we must treat it as a LocalDateTime and not an Instant.
-                None => LocalDateTimeArray::try_new((&time_unit).into(), arr.into_array())
+                None => LocalDateTimeArray::try_new(time_unit.into(), arr.into_array())
                     .expect("Invalid LocalDateTimeArray")
                     .into_array_data(),
-                Some(_tz) => todo!(),
+                Some(tz) => {
+                    ZonedDateTimeArray::try_new(time_unit.into(), tz.clone(), arr.into_array())
+                        .expect("Invalid ZonedDateTimeArray")
+                        .into_array_data()
+                }
             },
-            DataType::Date32 => todo!(),
-            DataType::Date64 => todo!(),
-            DataType::Time32(_) => todo!(),
-            DataType::Time64(_) => todo!(),
-            DataType::Duration(_) => todo!(),
-            DataType::Interval(_) => todo!(),
+            DataType::Date32 => DateArray::try_new(DateUnit::Days, arr.into_array())
+                .expect("Invalid DateArray")
+                .into_array_data(),
+            DataType::Date64 => DateArray::try_new(DateUnit::Ms, arr.into_array())
+                .expect("Invalid DateArray")
+                .into_array_data(),
+            DataType::Time32(time_unit) | DataType::Time64(time_unit) => {
+                TimeArray::try_new(time_unit.into(), arr.into_array())
+                    .expect("Invalid TimeArray")
+                    .into_array_data()
+            }
+            DataType::Duration(time_unit) => {
+                DurationArray::try_new(time_unit.into(), arr.into_array())
+                    .expect("Invalid DurationArray")
+                    .into_array_data()
+            }
+            DataType::Interval(interval_unit @ IntervalUnit::YearMonth) => {
+                IntervalArray::try_new(interval_unit.into(), arr.into_array())
+                    .expect("Invalid IntervalArray")
+                    .into_array_data()
+            }
+            DataType::Interval(IntervalUnit::DayTime) => interval_day_time(
+                arr.into_array().as_primitive().typed_data::<i64>(),
+                nulls(value.nulls(), nullable),
+            ),
             _ => panic!("Invalid data type for PrimitiveArray"),
         }
     }
@@ -167,8 +206,8 @@ impl FromArrowArray<&ArrowBooleanArray> for ArrayData {
     }
 }
 
-impl FromArrowArray<&ArrowStructArray> for ArrayData {
-    fn from_arrow(value: &ArrowStructArray, nullable: bool) -> Self {
+impl TryFromArrowArray<&ArrowStructArray> for ArrayData {
+    fn try_from_arrow(value: &ArrowStructArray, nullable: bool) -> VortexResult<Self> {
         // TODO(ngates): how should we deal with Arrow "logical nulls"?
         assert!(!nullable);
         StructArray::try_new(
@@ -182,8 +221,121 @@ impl FromArrowArray<&ArrowStructArray> for ArrayData {
                 .columns()
                 .iter()
                 .zip(value.fields())
-                .map(|(c, field)| Self::from_arrow(c.clone(), field.is_nullable()).into_array())
-                .collect(),
+                .map(|(c, field)| {
+                    Self::try_from_arrow(c.clone(), field.is_nullable()).map(|a| a.into_array())
+                })
+                .try_collect()?,
+            value.len(),
+            nulls(value.nulls(), nullable),
+        )
+        .map(IntoArrayData::into_array_data)
+    }
+}
+
+impl<O: NativePType + OffsetSizeTrait> TryFromArrowArray<&GenericListArray<O>> for ArrayData {
+    fn try_from_arrow(value: &GenericListArray<O>, nullable: bool) -> VortexResult<Self> {
+        let element_nullable = match value.data_type() {
+            DataType::List(field) | DataType::LargeList(field) => field.is_nullable(),
+            _ => panic!("Invalid data type for ListArray"),
+        };
+        ListArray::try_new(
+            value.offsets().clone().into_array_data().into_array(),
+            Self::try_from_arrow(value.values().clone(), element_nullable)?.into_array(),
+            nulls(value.nulls(), nullable),
+        )
+        .map(IntoArrayData::into_array_data)
+    }
+}
+
+impl TryFromArrowArray<&ArrowMapArray> for ArrayData {
+    fn try_from_arrow(value: &ArrowMapArray, nullable: bool) -> VortexResult<Self> {
+        let entries = value.entries();
+        let [key_field, value_field] = entries.fields().iter().collect_vec()[..] else {
+            panic!("Invalid entries for MapArray");
+        };
+        MapArray::try_new(
+            value.offsets().clone().into_array_data().into_array(),
+            Self::try_from_arrow(value.keys().clone(), key_field.is_nullable())?.into_array(),
+            Self::try_from_arrow(value.values().clone(), value_field.is_nullable())?.into_array(),
+            nulls(value.nulls(), nullable),
+        )
+        .map(IntoArrayData::into_array_data)
+    }
+}
+
+impl TryFromArrowArray<&ArrowUnionArray> for ArrayData {
+    fn try_from_arrow(value: &ArrowUnionArray, nullable: bool) -> VortexResult<Self> {
+        let DataType::Union(fields, _) = value.data_type() else {
+            panic!("Invalid data type for UnionArray");
+        };
+        // Arrow type ids can be sparse, whereas Vortex identifies variants by their position.
+        let mut variant_indices = [None; 128];
+        for (idx, (type_id, _)) in fields.iter().enumerate() {
+            let Some(slot) = usize::try_from(type_id)
+                .ok()
+                .and_then(|t| variant_indices.get_mut(t))
+            else {
+                vortex_bail!("Invalid Arrow union type id {}", type_id);
+            };
+            *slot = Some(idx as u8);
+        }
+        let type_ids: Vec<u8> = value
+            .type_ids()
+            .iter()
+            .map(|&t| {
+                usize::try_from(t)
+                    .ok()
+                    .and_then(|t| variant_indices.get(t).copied().flatten())
+                    .ok_or_else(|| {
+                        vortex_err!("Arrow union type id {} isn't one of its variants", t)
+                    })
+            })
+            .try_collect()?;
+
+        UnionArray::try_new(
+            fields
+                .iter()
+                .map(|(_, f)| f.name().as_str().into())
+                .collect_vec()
+                .into(),
+            PrimitiveArray::from(type_ids).into_array(),
+            value
+                .offsets()
+                .map(|offsets| offsets.clone().into_array_data().into_array()),
+            fields
+                .iter()
+                .map(|(type_id, f)| {
+                    Self::try_from_arrow(value.child(type_id).clone(), f.is_nullable())
+                        .map(|a| a.into_array())
+                })
+                .try_collect()?,
+            // Arrow unions have no validity of their own, so any nullable variant makes them nullable.
+            (nullable || fields.iter().any(|(_, f)| f.is_nullable())).into(),
+        )
+        .map(IntoArrayData::into_array_data)
+    }
+}
+
+impl TryFromArrowArray<&ArrowFixedSizeListArray> for ArrayData {
+    fn try_from_arrow(value: &ArrowFixedSizeListArray, nullable: bool) -> VortexResult<Self> {
+        let DataType::FixedSizeList(field, list_size) = value.data_type() else {
+            panic!("Invalid data type for FixedSizeListArray");
+        };
+        FixedSizeListArray::try_new(
+            Self::try_from_arrow(value.values().clone(), field.is_nullable())?.into_array(),
+            *list_size as u32,
+            value.len(),
+            nulls(value.nulls(), nullable),
+        )
+        .map(IntoArrayData::into_array_data)
+    }
+}
+
+impl FromArrowArray<&ArrowFixedSizeBinaryArray> for ArrayData {
+    fn from_arrow(value: &ArrowFixedSizeBinaryArray, nullable: bool) -> Self {
+        FixedSizeBinaryArray::try_new(
+            value.value_length() as u32,
+            value.values().clone().into(),
             value.len(),
             nulls(value.nulls(), nullable),
         )
@@ -199,6 +351,69 @@ impl FromArrowArray<&ArrowNullArray> for ArrayData {
     }
 }
 
+/// Import an Arrow decimal array, failing on values that don't fit in a Vortex decimal.
+fn decimal<T: DecimalType>(
+    array: &ArrowPrimitiveArray<T>,
+    nullable: bool,
+    to_i128: impl Fn(T::Native) -> Option<i128>,
+) -> VortexResult<ArrayData> {
+    let (precision, scale) = decimal_precision_and_scale(array.precision(), array.scale())?;
+    // A negative scale is multiplied out to a scale of zero.
+    let multiplier = 10i128.checked_pow(array.scale().min(0).unsigned_abs() as u32);
+    let max = 10u128.pow(precision as u32);
+    let values = array
+        .iter()
+        .map(|v| match v {
+            // The value of a null element is zero.
+            None => Ok(0),
+            Some(v) => to_i128(v)
+                .zip(multiplier)
+                .and_then(|(v, m)| v.checked_mul(m))
+                .filter(|v| v.unsigned_abs() < max)
+                .ok_or_else(|| {
+                    vortex_err!(
+                        "Decimal value {} doesn't fit in a decimal of precision {}",
+                        T::format_decimal(v, array.precision(), array.scale()),
+                        precision
+                    )
+                }),
+        })
+        .try_collect()?;
+    DecimalArray::from_unscaled(precision, scale, values, nulls(array.nulls(), nullable))
+        .map(IntoArrayData::into_array_data)
+}
+
+/// Split Arrow's packed day-time intervals into their days and milliseconds.
+fn interval_day_time(packed: &[i64], validity: Validity) -> ArrayData {
+    let (days, milliseconds): (Vec<i32>, Vec<i32>) = packed
+        .iter()
+        .map(|&v| IntervalDayTimeType::to_parts(v))
+        .unzip();
+    IntervalArray::day_time(
+        PrimitiveArray::from(days).into_array(),
+        PrimitiveArray::from(milliseconds).into_array(),
+        validity,
+    )
+    .unwrap()
+    .into_array_data()
+}
+
+/// Split Arrow's packed month-day-nano intervals into their months, days and nanoseconds.
+fn interval_month_day_nano(packed: &[i128], validity: Validity) -> ArrayData {
+    let (months, days, nanoseconds): (Vec<i32>, Vec<i32>, Vec<i64>) = packed
+        .iter()
+        .map(|&v| IntervalMonthDayNanoType::to_parts(v))
+        .multiunzip();
+    IntervalArray::month_day_nano(
+        PrimitiveArray::from(months).into_array(),
+        PrimitiveArray::from(days).into_array(),
+        PrimitiveArray::from(nanoseconds).into_array(),
+        validity,
+    )
+    .unwrap()
+    .into_array_data()
+}
+
 fn nulls(nulls: Option<&NullBuffer>, nullable: bool) -> Validity {
     if nullable {
         nulls
@@ -218,7 +433,13 @@ fn nulls(nulls: Option<&NullBuffer>, nullable: bool) -> Validity {
 
 impl FromArrowArray<ArrowArrayRef> for ArrayData {
     fn from_arrow(array: ArrowArrayRef, nullable: bool) -> Self {
-        match array.data_type() {
+        Self::try_from_arrow(array, nullable).unwrap()
+    }
+}
+
+impl TryFromArrowArray<ArrowArrayRef> for ArrayData {
+    fn try_from_arrow(array: ArrowArrayRef, nullable: bool) -> VortexResult<Self> {
+        Ok(match array.data_type() {
             DataType::Boolean => Self::from_arrow(array.as_boolean(), nullable),
             DataType::UInt8 => Self::from_arrow(array.as_primitive::<UInt8Type>(), nullable),
             DataType::UInt16 => Self::from_arrow(array.as_primitive::<UInt16Type>(), nullable),
@@ -231,6 +452,14 @@ impl FromArrowArray<ArrowArrayRef> for ArrayData {
             DataType::Float16 => Self::from_arrow(array.as_primitive::<Float16Type>(), nullable),
             DataType::Float32 => Self::from_arrow(array.as_primitive::<Float32Type>(), nullable),
             DataType::Float64 => Self::from_arrow(array.as_primitive::<Float64Type>(), nullable),
+            DataType::Decimal128(..) => {
+                decimal(array.as_primitive::<Decimal128Type>(), nullable, Some)?
+            }
+            DataType::Decimal256(..) => {
+                decimal(array.as_primitive::<Decimal256Type>(), nullable, |v| {
+                    v.to_i128()
+                })?
+            }
             DataType::Utf8 => Self::from_arrow(array.as_string::<i32>(), nullable),
             DataType::LargeUtf8 => Self::from_arrow(array.as_string::<i64>(), nullable),
             DataType::Binary => Self::from_arrow(array.as_binary::<i32>(), nullable),
@@ -243,7 +472,20 @@ impl FromArrowArray<ArrowArrayRef> for ArrayData {
                 array.as_any().downcast_ref::<StringViewArray>().unwrap(),
                 nullable,
             ),
-            DataType::Struct(_) => Self::from_arrow(array.as_struct(), nullable),
+            DataType::Struct(_) => Self::try_from_arrow(array.as_struct(), nullable)?,
+            DataType::List(_) => Self::try_from_arrow(array.as_list::<i32>(), nullable)?,
+            DataType::LargeList(_) => Self::try_from_arrow(array.as_list::<i64>(), nullable)?,
+            DataType::FixedSizeList(..) => {
+                Self::try_from_arrow(array.as_fixed_size_list(), nullable)?
+            }
+            DataType::Map(..) => Self::try_from_arrow(array.as_map(), nullable)?,
+            DataType::Union(..) => Self::try_from_arrow(
+                array.as_any().downcast_ref::<ArrowUnionArray>().unwrap(),
+                nullable,
+            )?,
+            DataType::FixedSizeBinary(_) => {
+                Self::from_arrow(array.as_fixed_size_binary(), nullable)
+            }
             DataType::Null => Self::from_arrow(as_null_array(&array), nullable),
             DataType::Timestamp(u, _) => match u {
                 TimeUnit::Second => {
@@ -293,10 +535,22 @@ impl FromArrowArray<ArrowArrayRef> for ArrayData {
                     Self::from_arrow(array.as_primitive::<DurationNanosecondType>(), nullable)
                 }
             },
-            _ => panic!(
+            DataType::Interval(u) => match u {
+                IntervalUnit::YearMonth => {
+                    Self::from_arrow(array.as_primitive::<IntervalYearMonthType>(), nullable)
+                }
+                IntervalUnit::DayTime => {
+                    Self::from_arrow(array.as_primitive::<IntervalDayTimeType>(), nullable)
+                }
+                IntervalUnit::MonthDayNano => {
+                    let array = array.as_primitive::<IntervalMonthDayNanoType>();
+                    interval_month_day_nano(array.values(), nulls(array.nulls(), nullable))
+                }
+            },
+            _ => vortex_bail!(
                 "TODO(robert): Missing array encoding for dtype {}",
                 array.data_type().clone()
             ),
-        }
+        })
     }
 }
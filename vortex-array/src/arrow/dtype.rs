@@ -1,13 +1,16 @@
 use std::sync::Arc;
 
-use arrow_schema::TimeUnit as ArrowTimeUnit;
 use arrow_schema::{DataType, Field, SchemaRef};
+use arrow_schema::{IntervalUnit as ArrowIntervalUnit, TimeUnit as ArrowTimeUnit};
 use itertools::Itertools;
 use vortex_dtype::{DType, Nullability};
-use vortex_dtype::{PType, StructDType};
-use vortex_error::{vortex_err, VortexResult};
+use vortex_dtype::{PType, StructDType, UnionDType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
-use crate::array::datetime::{LocalDateTimeArray, TimeUnit};
+use crate::array::datetime::{
+    DateArray, DateUnit, DurationArray, IntervalArray, IntervalUnit, LocalDateTimeArray, TimeArray,
+    TimeUnit, ZonedDateTimeArray,
+};
 use crate::arrow::{FromArrowType, TryFromArrowType};
 
 impl TryFromArrowType<&DataType> for PType {
@@ -34,7 +37,13 @@ impl TryFromArrowType<&DataType> for PType {
 
 impl FromArrowType<SchemaRef> for DType {
     fn from_arrow(value: SchemaRef) -> Self {
-        Self::Struct(
+        Self::try_from_arrow(value).unwrap()
+    }
+}
+
+impl TryFromArrowType<SchemaRef> for DType {
+    fn try_from_arrow(value: SchemaRef) -> VortexResult<Self> {
+        Ok(Self::Struct(
             StructDType::new(
                 value
                     .fields()
@@ -45,55 +54,127 @@ impl FromArrowType<SchemaRef> for DType {
                 value
                     .fields()
                     .iter()
-                    .map(|f| Self::from_arrow(f.as_ref()))
-                    .collect_vec(),
+                    .map(|f| Self::try_from_arrow(f.as_ref()))
+                    .try_collect()?,
             ),
             Nullability::NonNullable,
-        )
+        ))
     }
 }
 
 impl FromArrowType<&Field> for DType {
     fn from_arrow(field: &Field) -> Self {
+        Self::try_from_arrow(field).unwrap()
+    }
+}
+
+impl TryFromArrowType<&Field> for DType {
+    fn try_from_arrow(field: &Field) -> VortexResult<Self> {
         use vortex_dtype::DType::*;
 
         let nullability: Nullability = field.is_nullable().into();
 
         if let Ok(ptype) = PType::try_from_arrow(field.data_type()) {
-            return Primitive(ptype, nullability);
+            return Ok(Primitive(ptype, nullability));
         }
 
-        match field.data_type() {
+        Ok(match field.data_type() {
             DataType::Null => Null,
             DataType::Boolean => Bool(nullability),
+            DataType::Decimal128(p, s) | DataType::Decimal256(p, s) => {
+                let (p, s) = decimal_precision_and_scale(*p, *s)?;
+                Decimal(p, s, nullability)
+            }
             DataType::Utf8 | DataType::LargeUtf8 => Utf8(nullability),
             DataType::Binary | DataType::LargeBinary => Binary(nullability),
             DataType::Timestamp(time_unit, tz) => match tz {
                 None => Extension(LocalDateTimeArray::ext_dtype(time_unit.into()), nullability),
-                Some(_) => unimplemented!("Timezone not yet supported"),
+                Some(tz) => Extension(
+                    ZonedDateTimeArray::ext_dtype(time_unit.into(), tz),
+                    nullability,
+                ),
             },
-            // DataType::Date32 => localdate(IntWidth::_32, nullability),
-            // DataType::Date64 => localdate(IntWidth::_64, nullability),
-            // DataType::Time32(u) => localtime(u.into(), IntWidth::_32, nullability),
-            // DataType::Time64(u) => localtime(u.into(), IntWidth::_64, nullability),
+            DataType::Date32 => Extension(DateArray::ext_dtype(DateUnit::Days), nullability),
+            DataType::Date64 => Extension(DateArray::ext_dtype(DateUnit::Ms), nullability),
+            DataType::Time32(u) | DataType::Time64(u) => {
+                Extension(TimeArray::ext_dtype(u.into()), nullability)
+            }
+            DataType::Duration(u) => Extension(DurationArray::ext_dtype(u.into()), nullability),
+            DataType::Interval(u) => Extension(IntervalArray::ext_dtype(u.into()), nullability),
             DataType::List(e) | DataType::LargeList(e) => {
-                List(Arc::new(Self::from_arrow(e.as_ref())), nullability)
+                List(Arc::new(Self::try_from_arrow(e.as_ref())?), nullability)
             }
+            DataType::FixedSizeList(e, size) => FixedSizeList(
+                Arc::new(Self::try_from_arrow(e.as_ref())?),
+                *size as u32,
+                nullability,
+            ),
+            DataType::FixedSizeBinary(size) => FixedSizeBinary(*size as u32, nullability),
+            DataType::Map(entries, _) => match entries.data_type() {
+                DataType::Struct(f) if f.len() == 2 => Map(
+                    Arc::new(Self::try_from_arrow(f[0].as_ref())?),
+                    Arc::new(Self::try_from_arrow(f[1].as_ref())?),
+                    nullability,
+                ),
+                _ => vortex_bail!("Invalid Arrow map entries: {:?}", entries.data_type()),
+            },
             DataType::Struct(f) => Struct(
                 StructDType::new(
                     f.iter()
                         .map(|f| f.name().as_str().into())
                         .collect_vec()
                         .into(),
-                    f.iter().map(|f| Self::from_arrow(f.as_ref())).collect_vec(),
+                    f.iter()
+                        .map(|f| Self::try_from_arrow(f.as_ref()))
+                        .try_collect()?,
                 ),
                 nullability,
             ),
-            _ => unimplemented!("Arrow data type not yet supported: {:?}", field.data_type()),
-        }
+            DataType::Union(f, _) => Union(
+                UnionDType::new(
+                    f.iter()
+                        .map(|(_, f)| f.name().as_str().into())
+                        .collect_vec()
+                        .into(),
+                    f.iter()
+                        .map(|(_, f)| Self::try_from_arrow(f.as_ref()))
+                        .try_collect()?,
+                ),
+                // Arrow unions have no validity of their own, so any nullable variant makes them
+                // nullable.
+                (field.is_nullable() || f.iter().any(|(_, f)| f.is_nullable())).into(),
+            ),
+            _ => vortex_bail!("Arrow data type not yet supported: {:?}", field.data_type()),
+        })
     }
 }
 
+/// The precision and scale of the Vortex decimal that holds the values of an Arrow decimal.
+///
+/// Vortex decimals can't have a negative scale, so such values are multiplied out to a scale of
+/// zero. Arrow decimals whose precision is beyond what Vortex can store are an error.
+pub(crate) fn decimal_precision_and_scale(precision: u8, scale: i8) -> VortexResult<(u8, u8)> {
+    let widened = precision as u32 + scale.min(0).unsigned_abs() as u32;
+    if widened > DType::MAX_DECIMAL_PRECISION as u32 {
+        vortex_bail!(
+            "Arrow decimal ({}, {}) needs a precision of {}, beyond the maximum of {}",
+            precision,
+            scale,
+            widened,
+            DType::MAX_DECIMAL_PRECISION
+        );
+    }
+    let scale = scale.max(0) as u8;
+    if scale as u32 > widened {
+        vortex_bail!(
+            "Decimal scale {} is greater than the precision {}",
+            scale,
+            widened
+        );
+    }
+    Ok((widened as u8, scale))
+}
+
 impl From<&ArrowTimeUnit> for TimeUnit {
     fn from(value: &ArrowTimeUnit) -> Self {
         match value {
@@ -115,3 +196,23 @@ impl From<TimeUnit> for ArrowTimeUnit {
         }
     }
 }
+
+impl From<&ArrowIntervalUnit> for IntervalUnit {
+    fn from(value: &ArrowIntervalUnit) -> Self {
+        match value {
+            ArrowIntervalUnit::YearMonth => Self::YearMonth,
+            ArrowIntervalUnit::DayTime => Self::DayTime,
+            ArrowIntervalUnit::MonthDayNano => Self::MonthDayNano,
+        }
+    }
+}
+
+impl From<IntervalUnit> for ArrowIntervalUnit {
+    fn from(value: IntervalUnit) -> Self {
+        match value {
+            IntervalUnit::YearMonth => Self::YearMonth,
+            IntervalUnit::DayTime => Self::DayTime,
+            IntervalUnit::MonthDayNano => Self::MonthDayNano,
+        }
+    }
+}
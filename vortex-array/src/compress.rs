@@ -6,7 +6,12 @@ use vortex_error::{vortex_bail, VortexResult};
 
 use crate::array::chunked::{Chunked, ChunkedArray};
 use crate::array::constant::{Constant, ConstantArray};
+use crate::array::decimal::{Decimal, DecimalArray};
+use crate::array::fixed_size_list::{FixedSizeList, FixedSizeListArray};
+use crate::array::list::{List, ListArray};
+use crate::array::map::{Map, MapArray};
 use crate::array::r#struct::{Struct, StructArray};
+use crate::array::union::{Union, UnionArray};
 use crate::compute::scalar_at::scalar_at;
 use crate::compute::slice::slice;
 use crate::encoding::{ArrayEncoding, EncodingRef};
@@ -201,6 +206,55 @@ impl<'a> Compressor<'a> {
                 // Not much better we can do than constant!
                 Ok(arr.clone())
             }
+            Decimal::ID => {
+                // For decimal arrays, we compress the unscaled integers
+                let decimal = DecimalArray::try_from(arr)?;
+                let compressed_unscaled = decimal
+                    .unscaled()
+                    .iter()
+                    .map(|unscaled| self.compress_array(unscaled))
+                    .collect::<VortexResult<Vec<_>>>()?;
+                let validity = self.compress_validity(decimal.validity())?;
+                Ok(DecimalArray::try_new(
+                    decimal.precision(),
+                    decimal.scale(),
+                    compressed_unscaled,
+                    validity,
+                )?
+                .into_array())
+            }
+            List::ID => {
+                // For list arrays, we compress the offsets and the elements independently
+                let list = ListArray::try_from(arr)?;
+                Ok(ListArray::try_new(
+                    self.compress_array(&list.offsets())?,
+                    self.compress_array(&list.elements())?,
+                    self.compress_validity(list.validity())?,
+                )?
+                .into_array())
+            }
+            FixedSizeList::ID => {
+                // For fixed-size list arrays, we compress the flat elements
+                let list = FixedSizeListArray::try_from(arr)?;
+                Ok(FixedSizeListArray::try_new(
+                    self.compress_array(&list.elements())?,
+                    list.list_size(),
+                    list.len(),
+                    self.compress_validity(list.validity())?,
+                )?
+                .into_array())
+            }
+            Map::ID => {
+                // For map arrays, we compress the offsets, keys and values independently
+                let map = MapArray::try_from(arr)?;
+                Ok(MapArray::try_new(
+                    self.compress_array(&map.offsets())?,
+                    self.compress_array(&map.keys())?,
+                    self.compress_array(&map.values())?,
+                    self.compress_validity(map.validity())?,
+                )?
+                .into_array())
+            }
             Struct::ID => {
                 // For struct arrays, we compress each field individually
                 let strct = StructArray::try_from(arr)?;
@@ -217,6 +271,24 @@ impl<'a> Compressor<'a> {
                 )?
                 .into_array())
             }
+            Union::ID => {
+                // For union arrays, we compress the type ids, offsets and each variant independently
+                let union = UnionArray::try_from(arr)?;
+                Ok(UnionArray::try_new(
+                    union.names().clone(),
+                    self.compress_array(&union.type_ids())?,
+                    union
+                        .offsets()
+                        .map(|offsets| self.compress_array(&offsets))
+                        .transpose()?,
+                    union
+                        .variants()
+                        .map(|variant| self.compress_array(&variant))
+                        .collect::<VortexResult<Vec<_>>>()?,
+                    union.dtype().nullability(),
+                )?
+                .into_array())
+            }
             _ => {
                 // Otherwise, we run sampled compression over pluggable encodings
                 let sampled = sampled_compression(arr, self)?;
@@ -0,0 +1,297 @@
+use log::info;
+use num_traits::ToPrimitive;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::array::bool::BoolArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::filter::filter;
+use crate::validity::{ArrayValidity, LogicalValidity};
+use crate::{Array, ArrayDType, IntoArray};
+
+/// A function reducing an array to a single value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Aggregate {
+    Sum,
+    Count,
+    Min,
+    Max,
+    Any,
+    All,
+}
+
+impl Aggregate {
+    /// The type of the aggregate over values of `dtype`, or an error if it isn't defined for them.
+    pub fn return_dtype(self, dtype: &DType) -> VortexResult<DType> {
+        match (self, dtype) {
+            (Self::Count, _) => Ok(DType::Primitive(PType::U64, Nullability::NonNullable)),
+            (Self::Sum, DType::Primitive(ptype, _)) => {
+                let sum_ptype = if ptype.is_unsigned_int() {
+                    PType::U64
+                } else if ptype.is_signed_int() {
+                    PType::I64
+                } else {
+                    PType::F64
+                };
+                Ok(DType::Primitive(sum_ptype, Nullability::Nullable))
+            }
+            (Self::Min | Self::Max, DType::Primitive(..) | DType::Bool(_)) => {
+                Ok(dtype.as_nullable())
+            }
+            (Self::Any | Self::All, DType::Bool(_)) => Ok(DType::Bool(Nullability::NonNullable)),
+            _ => vortex_bail!("{:?} is not supported for {}", self, dtype),
+        }
+    }
+}
+
+pub trait AggregateFn {
+    /// Compute the aggregate over the valid values of the array.
+    ///
+    /// The aggregate is always defined for the array's dtype.
+    fn aggregate(&self, agg: Aggregate) -> VortexResult<Scalar>;
+}
+
+/// Reduce an array to a single value, ignoring nulls.
+///
+/// Sum, min and max are null when there are no valid values, while `any` is false and `all` is
+/// true. Integers are summed in 64 bits, and a sum that doesn't fit is an error.
+pub fn aggregate(array: &Array, agg: Aggregate) -> VortexResult<Scalar> {
+    agg.return_dtype(array.dtype())?;
+
+    if let Some(result) = array.with_dyn(|a| a.aggregate().map(|f| f.aggregate(agg))) {
+        return result;
+    }
+
+    // Counting only needs the validity.
+    if agg == Aggregate::Count {
+        return Ok((valid_count(array.with_dyn(|a| a.logical_validity()))? as u64).into());
+    }
+
+    info!("AggregateFn not implemented for {}, flattening", array);
+    let flat = array.clone().flatten()?.into_array();
+    flat.with_dyn(|a| a.aggregate().map(|f| f.aggregate(agg)))
+        .unwrap_or_else(|| Err(vortex_err!(NotImplemented: "aggregate", flat.encoding().id())))
+}
+
+pub fn sum(array: &Array) -> VortexResult<Scalar> {
+    aggregate(array, Aggregate::Sum)
+}
+
+/// The number of valid values in the array.
+pub fn count(array: &Array) -> VortexResult<usize> {
+    usize::try_from(&aggregate(array, Aggregate::Count)?)
+}
+
+/// The mean of the valid values of a primitive array, or None if there are none.
+pub fn mean(array: &Array) -> VortexResult<Option<f64>> {
+    let total = sum(array)?;
+    let total = PrimitiveScalar::try_from(&total)?;
+    let count = count(array)?;
+    match_each_native_ptype!(total.ptype(), |$T| {
+        Ok(total
+            .typed_value::<$T>()
+            .and_then(|t| ToPrimitive::to_f64(&t))
+            .map(|t| t / count as f64))
+    })
+}
+
+pub fn min(array: &Array) -> VortexResult<Scalar> {
+    aggregate(array, Aggregate::Min)
+}
+
+pub fn max(array: &Array) -> VortexResult<Scalar> {
+    aggregate(array, Aggregate::Max)
+}
+
+/// Whether any valid value of a boolean array is true.
+pub fn any(array: &Array) -> VortexResult<bool> {
+    bool::try_from(&aggregate(array, Aggregate::Any)?)
+}
+
+/// Whether every valid value of a boolean array is true.
+pub fn all(array: &Array) -> VortexResult<bool> {
+    bool::try_from(&aggregate(array, Aggregate::All)?)
+}
+
+/// Aggregate `values` as if each of them was repeated as many times as its weight.
+///
+/// This lets run-length and dictionary encodings aggregate their distinct values instead of
+/// decoding them.
+pub fn weighted_aggregate(values: &Array, weights: &[u64], agg: Aggregate) -> VortexResult<Scalar> {
+    if weights.len() != values.len() {
+        vortex_bail!("Expected {} weights, found {}", values.len(), weights.len());
+    }
+    agg.return_dtype(values.dtype())?;
+
+    match agg {
+        Aggregate::Count => {
+            let valid = values.with_dyn(|a| a.logical_validity()).to_null_buffer()?;
+            let count: u64 = weights
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| valid.as_ref().map(|v| v.is_valid(*i)).unwrap_or(true))
+                .map(|(_, w)| w)
+                .sum();
+            Ok(count.into())
+        }
+        Aggregate::Sum => sum_primitive(&values.clone().flatten_primitive()?, |i| weights[i]),
+        // Otherwise, all that matters is whether a value appears at all.
+        _ => {
+            let present = BoolArray::from(weights.iter().map(|w| *w > 0).collect::<Vec<_>>());
+            aggregate(
+                &filter(
+                    &values.clone().flatten()?.into_array(),
+                    &present.into_array(),
+                )?,
+                agg,
+            )
+        }
+    }
+}
+
+/// Sum the valid values of a primitive array, each repeated by its weight.
+pub(crate) fn sum_primitive(
+    array: &PrimitiveArray,
+    weight: impl Fn(usize) -> u64,
+) -> VortexResult<Scalar> {
+    let valid = array.logical_validity().to_null_buffer()?;
+    let mut sum = SumAccumulator::new(array.ptype())?;
+    match_each_native_ptype!(array.ptype(), |$T| {
+        for (i, &v) in array.typed_data::<$T>().iter().enumerate() {
+            if valid.as_ref().map(|n| n.is_valid(i)).unwrap_or(true) {
+                sum.add(v, weight(i))?;
+            }
+        }
+    });
+    sum.finish()
+}
+
+pub(crate) fn valid_count(validity: LogicalValidity) -> VortexResult<usize> {
+    Ok(match validity.to_null_buffer()? {
+        None => validity.len(),
+        Some(nulls) => nulls.len() - nulls.null_count(),
+    })
+}
+
+/// Accumulates the sum of primitive values, wide enough that only the final result can overflow.
+pub struct SumAccumulator {
+    ptype: PType,
+    int: i128,
+    float: f64,
+    is_empty: bool,
+}
+
+impl SumAccumulator {
+    /// A sum of values of type `ptype`.
+    pub fn new(ptype: PType) -> VortexResult<Self> {
+        let ptype = PType::try_from(&Aggregate::Sum.return_dtype(&ptype.into())?)?;
+        Ok(Self {
+            ptype,
+            int: 0,
+            float: 0.0,
+            is_empty: true,
+        })
+    }
+
+    /// Add `value` to the sum `count` times.
+    pub fn add<T: NativePType>(&mut self, value: T, count: u64) -> VortexResult<()> {
+        if count == 0 {
+            return Ok(());
+        }
+        self.is_empty = false;
+        if self.ptype.is_float() {
+            self.float += ToPrimitive::to_f64(&value).unwrap_or(f64::NAN) * count as f64;
+        } else {
+            self.int = value
+                .to_i128()
+                .and_then(|v| v.checked_mul(count as i128))
+                .and_then(|v| self.int.checked_add(v))
+                .ok_or_else(|| vortex_err!("Sum overflowed adding {} {} times", value, count))?;
+        }
+        Ok(())
+    }
+
+    /// Add a primitive scalar to the sum `count` times, ignoring nulls.
+    pub fn add_scalar(&mut self, scalar: &Scalar, count: u64) -> VortexResult<()> {
+        let scalar = PrimitiveScalar::try_from(scalar)?;
+        match_each_native_ptype!(scalar.ptype(), |$T| {
+            match scalar.typed_value::<$T>() {
+                Some(v) => self.add(v, count),
+                None => Ok(()),
+            }
+        })
+    }
+
+    /// The sum, which is null if no values were added.
+    pub fn finish(self) -> VortexResult<Scalar> {
+        if self.is_empty {
+            return Ok(Scalar::null(DType::Primitive(
+                self.ptype,
+                Nullability::Nullable,
+            )));
+        }
+        match self.ptype {
+            PType::F64 => Ok(Scalar::primitive(self.float, Nullability::Nullable)),
+            PType::I64 => i64::try_from(self.int)
+                .map(|v| Scalar::primitive(v, Nullability::Nullable))
+                .map_err(|_| vortex_err!("Sum {} overflows i64", self.int)),
+            _ => u64::try_from(self.int)
+                .map(|v| Scalar::primitive(v, Nullability::Nullable))
+                .map_err(|_| vortex_err!("Sum {} overflows u64", self.int)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::Nullability;
+    use vortex_scalar::Scalar;
+
+    use crate::array::bool::BoolArray;
+    use crate::array::constant::ConstantArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::aggregate::{all, any, count, max, mean, min, sum};
+    use crate::IntoArray;
+
+    #[test]
+    fn primitive() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(-1), Some(4)])
+            .into_array();
+        assert_eq!(sum(&array).unwrap(), Some(6i64).into());
+        assert_eq!(count(&array).unwrap(), 3);
+        assert_eq!(mean(&array).unwrap(), Some(2.0));
+        assert_eq!(min(&array).unwrap(), Some(-1i32).into());
+        assert_eq!(max(&array).unwrap(), Some(4i32).into());
+        assert!(any(&array).is_err());
+
+        let empty = PrimitiveArray::from_nullable_vec(vec![None::<u8>]).into_array();
+        assert!(sum(&empty).unwrap().is_null());
+        assert!(min(&empty).unwrap().is_null());
+        assert_eq!(mean(&empty).unwrap(), None);
+
+        let overflow = PrimitiveArray::from(vec![u64::MAX, 1]).into_array();
+        assert!(sum(&overflow).is_err());
+    }
+
+    #[test]
+    fn boolean() {
+        let array = BoolArray::from_iter(vec![Some(true), None, Some(false)]).into_array();
+        assert!(any(&array).unwrap());
+        assert!(!all(&array).unwrap());
+        assert_eq!(
+            min(&array).unwrap(),
+            Scalar::bool(false, Nullability::Nullable)
+        );
+        assert_eq!(count(&array).unwrap(), 2);
+        assert!(sum(&array).is_err());
+    }
+
+    #[test]
+    fn constant() {
+        let array = ConstantArray::new(7u16, 5).into_array();
+        assert_eq!(sum(&array).unwrap(), Some(35u64).into());
+        assert_eq!(max(&array).unwrap(), Some(7u16).into());
+        assert_eq!(count(&array).unwrap(), 5);
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt::{Display, Formatter};
+
+use log::info;
+use num_traits::{CheckedNeg, CheckedRem, PrimInt};
+use vortex_dtype::{
+    match_each_float_ptype, match_each_integer_ptype, DType, NativePType, Nullability,
+};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+use crate::array::constant::ConstantArray;
+use crate::{Array, ArrayDType, IntoArray};
+
+/// A binary arithmetic operator over primitive arrays.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum NumericOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl Display for NumericOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::Rem => "%",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+pub trait BinaryNumericFn {
+    fn binary_numeric(&self, rhs: &Array, op: NumericOperator) -> VortexResult<Array>;
+}
+
+pub trait NegateFn {
+    fn negate(&self) -> VortexResult<Array>;
+}
+
+/// Apply an arithmetic operator to two primitive arrays of the same type, element by element.
+///
+/// A null on either side produces a null, and the result is nullable if either side is.
+/// Integer overflow and integer division by zero are errors, while floats follow IEEE 754.
+pub fn binary_numeric(lhs: &Array, rhs: &Array, op: NumericOperator) -> VortexResult<Array> {
+    match (lhs.dtype(), rhs.dtype()) {
+        (DType::Primitive(l, _), DType::Primitive(r, _)) if l == r => {}
+        _ => vortex_bail!(MismatchedTypes: lhs.dtype(), rhs.dtype()),
+    }
+    if lhs.len() != rhs.len() {
+        vortex_bail!(
+            "Arithmetic requires arrays of equal length, found {} and {}",
+            lhs.len(),
+            rhs.len()
+        );
+    }
+
+    if let Some(result) = lhs.with_dyn(|a| a.binary_numeric().map(|f| f.binary_numeric(rhs, op))) {
+        return result;
+    }
+
+    info!("BinaryNumericFn not implemented for {}, flattening", lhs);
+    BinaryNumericFn::binary_numeric(&lhs.clone().flatten_primitive()?, rhs, op)
+}
+
+/// Apply an arithmetic operator between every element of an array and a scalar.
+pub fn scalar_numeric(array: &Array, scalar: &Scalar, op: NumericOperator) -> VortexResult<Array> {
+    binary_numeric(
+        array,
+        &ConstantArray::new(scalar.clone(), array.len()).into_array(),
+        op,
+    )
+}
+
+pub fn add(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_numeric(lhs, rhs, NumericOperator::Add)
+}
+
+pub fn sub(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_numeric(lhs, rhs, NumericOperator::Sub)
+}
+
+pub fn mul(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_numeric(lhs, rhs, NumericOperator::Mul)
+}
+
+pub fn div(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_numeric(lhs, rhs, NumericOperator::Div)
+}
+
+pub fn rem(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_numeric(lhs, rhs, NumericOperator::Rem)
+}
+
+/// Negate every element of a primitive array, keeping its nulls.
+///
+/// Negating the minimum of a signed integer type, or any non-zero unsigned integer, is an error.
+pub fn neg(array: &Array) -> VortexResult<Array> {
+    if !matches!(array.dtype(), DType::Primitive(..)) {
+        vortex_bail!(MismatchedTypes: "primitive", array.dtype());
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.negate().map(|f| f.negate())) {
+        return result;
+    }
+
+    info!("NegateFn not implemented for {}, flattening", array);
+    NegateFn::negate(&array.clone().flatten_primitive()?)
+}
+
+/// The checked integer implementation of an operator, which is None on overflow or division
+/// by zero.
+pub(crate) fn checked_int_op<T: PrimInt + CheckedRem>(
+    op: NumericOperator,
+) -> fn(T, T) -> Option<T> {
+    match op {
+        NumericOperator::Add => |a, b| a.checked_add(&b),
+        NumericOperator::Sub => |a, b| a.checked_sub(&b),
+        NumericOperator::Mul => |a, b| a.checked_mul(&b),
+        NumericOperator::Div => |a, b| a.checked_div(&b),
+        NumericOperator::Rem => |a, b| CheckedRem::checked_rem(&a, &b),
+    }
+}
+
+pub(crate) fn float_op<T: NativePType>(op: NumericOperator) -> fn(T, T) -> T {
+    match op {
+        NumericOperator::Add => |a, b| a + b,
+        NumericOperator::Sub => |a, b| a - b,
+        NumericOperator::Mul => |a, b| a * b,
+        NumericOperator::Div => |a, b| a / b,
+        NumericOperator::Rem => |a, b| a % b,
+    }
+}
+
+pub(crate) fn overflow_error<T: Display>(lhs: T, op: NumericOperator, rhs: T) -> VortexError {
+    vortex_err!(
+        "Integer overflow or division by zero computing {} {} {}",
+        lhs,
+        op,
+        rhs
+    )
+}
+
+/// Apply an operator to two primitive scalars of the same type.
+pub(crate) fn scalar_binary_numeric(
+    lhs: &Scalar,
+    rhs: &Scalar,
+    op: NumericOperator,
+) -> VortexResult<Scalar> {
+    let lhs = PrimitiveScalar::try_from(lhs)?;
+    let rhs = PrimitiveScalar::try_from(rhs)?;
+    let nullability = Nullability::from(lhs.dtype().is_nullable() || rhs.dtype().is_nullable());
+    if lhs.ptype() != rhs.ptype() {
+        vortex_bail!(MismatchedTypes: lhs.dtype(), rhs.dtype());
+    }
+
+    if lhs.ptype().is_int() {
+        match_each_integer_ptype!(lhs.ptype(), |$T| {
+            match (lhs.typed_value::<$T>(), rhs.typed_value::<$T>()) {
+                (Some(l), Some(r)) => checked_int_op::<$T>(op)(l, r)
+                    .map(|v| Scalar::primitive(v, nullability))
+                    .ok_or_else(|| overflow_error(l, op, r)),
+                _ => Ok(Scalar::null(DType::Primitive(lhs.ptype(), Nullability::Nullable))),
+            }
+        })
+    } else {
+        match_each_float_ptype!(lhs.ptype(), |$T| {
+            match (lhs.typed_value::<$T>(), rhs.typed_value::<$T>()) {
+                (Some(l), Some(r)) => Ok(Scalar::primitive(float_op::<$T>(op)(l, r), nullability)),
+                _ => Ok(Scalar::null(DType::Primitive(lhs.ptype(), Nullability::Nullable))),
+            }
+        })
+    }
+}
+
+/// Negate a primitive scalar.
+pub(crate) fn scalar_negate(scalar: &Scalar) -> VortexResult<Scalar> {
+    let primitive = PrimitiveScalar::try_from(scalar)?;
+    let nullability = primitive.dtype().nullability();
+    if primitive.ptype().is_int() {
+        match_each_integer_ptype!(primitive.ptype(), |$T| {
+            match primitive.typed_value::<$T>() {
+                Some(v) => CheckedNeg::checked_neg(&v)
+                    .map(|n| Scalar::primitive(n, nullability))
+                    .ok_or_else(|| vortex_err!("Can't negate {} of type {}", v, primitive.ptype())),
+                None => Ok(scalar.clone()),
+            }
+        })
+    } else {
+        match_each_float_ptype!(primitive.ptype(), |$T| {
+            Ok(primitive
+                .typed_value::<$T>()
+                .map(|v| Scalar::primitive(-v, nullability))
+                .unwrap_or_else(|| scalar.clone()))
+        })
+    }
+}
@@ -0,0 +1,96 @@
+use log::info;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::bool::Bool;
+use crate::{Array, ArrayDType, ArrayDef};
+
+/// A binary operator over boolean arrays.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BooleanOperator {
+    And,
+    /// The left side AND the negation of the right side.
+    AndNot,
+    Or,
+    Xor,
+}
+
+impl BooleanOperator {
+    pub fn is_commutative(self) -> bool {
+        !matches!(self, Self::AndNot)
+    }
+}
+
+pub trait BinaryBooleanFn {
+    fn binary_boolean(&self, rhs: &Array, op: BooleanOperator) -> VortexResult<Array>;
+}
+
+pub trait NotFn {
+    fn not(&self) -> VortexResult<Array>;
+}
+
+/// Combine two boolean arrays with the given operator.
+///
+/// Nulls follow SQL's three-valued logic, so `false AND null` is false and `true OR null` is
+/// true, while any other combination involving a null is null. The result is nullable if
+/// either side is.
+pub fn binary_boolean(lhs: &Array, rhs: &Array, op: BooleanOperator) -> VortexResult<Array> {
+    if !matches!(lhs.dtype(), DType::Bool(_)) || !matches!(rhs.dtype(), DType::Bool(_)) {
+        vortex_bail!(
+            "Boolean operations are only supported on bool arrays, found {} and {}",
+            lhs.dtype(),
+            rhs.dtype()
+        );
+    }
+    if lhs.len() != rhs.len() {
+        vortex_bail!(
+            "Boolean operations require arrays of equal length, found {} and {}",
+            lhs.len(),
+            rhs.len()
+        );
+    }
+
+    // Prefer the kernel of an encoded operand over that of a flat boolean array.
+    let (lhs, rhs) = if op.is_commutative() && lhs.encoding().id() == Bool::ID {
+        (rhs, lhs)
+    } else {
+        (lhs, rhs)
+    };
+
+    if let Some(result) = lhs.with_dyn(|a| a.binary_boolean().map(|f| f.binary_boolean(rhs, op))) {
+        return result;
+    }
+
+    info!("BinaryBooleanFn not implemented for {}, flattening", lhs);
+    BinaryBooleanFn::binary_boolean(&lhs.clone().flatten_bool()?, rhs, op)
+}
+
+pub fn and(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_boolean(lhs, rhs, BooleanOperator::And)
+}
+
+pub fn and_not(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_boolean(lhs, rhs, BooleanOperator::AndNot)
+}
+
+pub fn or(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_boolean(lhs, rhs, BooleanOperator::Or)
+}
+
+pub fn xor(lhs: &Array, rhs: &Array) -> VortexResult<Array> {
+    binary_boolean(lhs, rhs, BooleanOperator::Xor)
+}
+
+/// Negate a boolean array, keeping its nulls.
+pub fn not(array: &Array) -> VortexResult<Array> {
+    if !matches!(array.dtype(), DType::Bool(_)) {
+        vortex_bail!(MismatchedTypes: "bool", array.dtype());
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.not().map(|f| f.not())) {
+        return result;
+    }
+
+    info!("NotFn not implemented for {}, flattening", array);
+    NotFn::not(&array.clone().flatten_bool()?)
+}
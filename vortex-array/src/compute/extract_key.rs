@@ -0,0 +1,29 @@
+use log::info;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::{Array, IntoArray};
+
+pub trait ExtractKeyFn {
+    fn extract_key(&self, key: &Scalar) -> VortexResult<Array>;
+}
+
+/// Extract the value of the given key from each map in the array, as a flat nullable array of the
+/// map's value type. Maps that are null, or that don't contain the key, produce a null value.
+pub fn extract_key(array: &Array, key: &Scalar) -> VortexResult<Array> {
+    array.with_dyn(|a| {
+        if let Some(extract) = a.extract_key() {
+            return extract.extract_key(key);
+        }
+
+        // Otherwise, flatten and try again.
+        info!("ExtractKeyFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.extract_key()
+                .map(|e| e.extract_key(key))
+                .unwrap_or_else(|| {
+                    Err(vortex_err!(NotImplemented: "extract_key", array.encoding().id()))
+                })
+        })
+    })
+}
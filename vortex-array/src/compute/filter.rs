@@ -0,0 +1,93 @@
+use std::ops::BitAnd;
+
+use log::info;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::bool::BoolArray;
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::take::take;
+use crate::validity::ArrayValidity;
+use crate::{Array, ArrayDType, IntoArray};
+
+pub trait FilterFn {
+    /// Keep the values whose position is set in the mask.
+    ///
+    /// The mask is always a non-nullable boolean array of the same length as the array.
+    fn filter(&self, mask: &Array) -> VortexResult<Array>;
+}
+
+/// Return a new array holding only the values of `array` at the positions where `mask` is true.
+///
+/// Null mask values are treated as false.
+pub fn filter(array: &Array, mask: &Array) -> VortexResult<Array> {
+    if !matches!(mask.dtype(), DType::Bool(_)) {
+        vortex_bail!(MismatchedTypes: "bool", mask.dtype());
+    }
+    if mask.len() != array.len() {
+        vortex_bail!(
+            "Mask length {} does not match array length {}",
+            mask.len(),
+            array.len()
+        );
+    }
+
+    let mask_bool = mask.clone().flatten_bool()?;
+    let buffer = match mask_bool.logical_validity().to_null_buffer()? {
+        None => mask_bool.boolean_buffer(),
+        Some(nulls) => mask_bool.boolean_buffer().bitand(nulls.inner()),
+    };
+    let true_count = buffer.count_set_bits();
+    if true_count == array.len() {
+        return Ok(array.clone());
+    }
+    let mask = BoolArray::from(buffer).into_array();
+
+    array.with_dyn(|a| {
+        if let Some(f) = a.filter() {
+            return f.filter(&mask);
+        }
+
+        // Otherwise, flatten and try again, taking the set indices as a last resort.
+        info!("FilterFn not implemented for {}, flattening", array);
+        let flat = array.clone().flatten()?.into_array();
+        flat.with_dyn(|a| {
+            a.filter().map(|f| f.filter(&mask)).unwrap_or_else(|| {
+                let indices = mask
+                    .clone()
+                    .flatten_bool()?
+                    .boolean_buffer()
+                    .set_indices()
+                    .map(|i| i as u64)
+                    .collect::<Vec<_>>();
+                take(&flat, &PrimitiveArray::from(indices).into_array())
+            })
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::bool::BoolArray;
+    use crate::array::primitive::PrimitiveArray;
+    use crate::compute::filter::filter;
+    use crate::IntoArray;
+
+    #[test]
+    fn nullable_mask() {
+        let array = PrimitiveArray::from(vec![1i32, 2, 3, 4]).into_array();
+        let mask = BoolArray::from_iter(vec![Some(true), None, Some(false), Some(true)]);
+        let filtered = filter(&array, &mask.into_array()).unwrap();
+        assert_eq!(
+            filtered.flatten_primitive().unwrap().typed_data::<i32>(),
+            &[1, 4]
+        );
+    }
+
+    #[test]
+    fn invalid_mask() {
+        let array = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        assert!(filter(&array, &BoolArray::from(vec![true, false]).into_array()).is_err());
+        assert!(filter(&array, &PrimitiveArray::from(vec![1u8, 0, 1]).into_array()).is_err());
+    }
+}
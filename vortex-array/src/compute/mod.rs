@@ -1,31 +1,51 @@
+use aggregate::AggregateFn;
+use arithmetic::{BinaryNumericFn, NegateFn};
 use as_arrow::AsArrowArray;
 use as_contiguous::AsContiguousFn;
+use boolean::{BinaryBooleanFn, NotFn};
 use cast::CastFn;
 use compare::CompareFn;
+use extract_key::ExtractKeyFn;
 use fill::FillForwardFn;
 use patch::PatchFn;
 use scalar_at::ScalarAtFn;
 use search_sorted::SearchSortedFn;
 use slice::SliceFn;
+use sort::{ArgsortFn, SortFn};
 use take::TakeFn;
 
+use crate::compute::filter::FilterFn;
 use crate::compute::filter_indices::FilterIndicesFn;
 use crate::compute::scalar_subtract::SubtractScalarFn;
 
+pub mod aggregate;
+pub mod arithmetic;
 pub mod as_arrow;
 pub mod as_contiguous;
+pub mod boolean;
 pub mod cast;
 pub mod compare;
+pub mod extract_key;
 pub mod fill;
+pub mod filter;
 pub mod filter_indices;
 pub mod patch;
 pub mod scalar_at;
 pub mod scalar_subtract;
 pub mod search_sorted;
 pub mod slice;
+pub mod sort;
 pub mod take;
 
 pub trait ArrayCompute {
+    fn aggregate(&self) -> Option<&dyn AggregateFn> {
+        None
+    }
+
+    fn argsort(&self) -> Option<&dyn ArgsortFn> {
+        None
+    }
+
     fn as_arrow(&self) -> Option<&dyn AsArrowArray> {
         None
     }
@@ -34,6 +54,14 @@ pub trait ArrayCompute {
         None
     }
 
+    fn binary_boolean(&self) -> Option<&dyn BinaryBooleanFn> {
+        None
+    }
+
+    fn binary_numeric(&self) -> Option<&dyn BinaryNumericFn> {
+        None
+    }
+
     fn cast(&self) -> Option<&dyn CastFn> {
         None
     }
@@ -42,14 +70,30 @@ pub trait ArrayCompute {
         None
     }
 
+    fn extract_key(&self) -> Option<&dyn ExtractKeyFn> {
+        None
+    }
+
     fn fill_forward(&self) -> Option<&dyn FillForwardFn> {
         None
     }
 
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        None
+    }
+
     fn filter_indices(&self) -> Option<&dyn FilterIndicesFn> {
         None
     }
 
+    fn negate(&self) -> Option<&dyn NegateFn> {
+        None
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        None
+    }
+
     fn patch(&self) -> Option<&dyn PatchFn> {
         None
     }
@@ -70,6 +114,10 @@ pub trait ArrayCompute {
         None
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        None
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         None
     }
@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+
+use arrow_buffer::NullBuffer;
+use log::info;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::array::primitive::PrimitiveArray;
+use crate::compute::take::take;
+use crate::flatten::Flattened;
+use crate::{Array, IntoArray};
+
+pub trait ArgsortFn {
+    fn argsort(&self, descending: bool, nulls_first: bool) -> VortexResult<Array>;
+}
+
+pub trait SortFn {
+    fn sort(&self, descending: bool, nulls_first: bool) -> VortexResult<Array>;
+}
+
+/// Return the u64 indices that would sort the array.
+///
+/// The sort is stable, so equal values keep their relative order. Nulls are placed before or
+/// after every valid value regardless of the direction of the sort.
+pub fn argsort(array: &Array, descending: bool, nulls_first: bool) -> VortexResult<Array> {
+    array.with_dyn(|a| {
+        if let Some(f) = a.argsort() {
+            return f.argsort(descending, nulls_first);
+        }
+
+        // Otherwise, flatten and try again.
+        info!("ArgsortFn not implemented for {}, flattening", array);
+        array.clone().flatten()?.into_array().with_dyn(|a| {
+            a.argsort()
+                .map(|f| f.argsort(descending, nulls_first))
+                .unwrap_or_else(|| {
+                    Err(vortex_err!(NotImplemented: "argsort", array.encoding().id()))
+                })
+        })
+    })
+}
+
+/// Return a sorted copy of the array.
+pub fn sort(array: &Array, descending: bool, nulls_first: bool) -> VortexResult<Array> {
+    if let Some(result) = array.with_dyn(|a| a.sort().map(|f| f.sort(descending, nulls_first))) {
+        return result;
+    }
+    take(array, &argsort(array, descending, nulls_first)?)
+}
+
+/// Compares the values at two positions of an array.
+pub(crate) type Comparator = Box<dyn Fn(usize, usize) -> Ordering>;
+
+/// A comparator over the values of any sortable array, used to sort by several columns.
+pub(crate) fn comparator(
+    array: &Array,
+    descending: bool,
+    nulls_first: bool,
+) -> VortexResult<Comparator> {
+    match array.clone().flatten()? {
+        Flattened::Primitive(a) => a.comparator(descending, nulls_first),
+        Flattened::Bool(a) => a.comparator(descending, nulls_first),
+        Flattened::VarBin(a) => a.comparator(descending, nulls_first),
+        Flattened::VarBinView(a) => a.comparator(descending, nulls_first),
+        Flattened::Struct(a) => a.comparator(descending, nulls_first),
+        _ => vortex_bail!(NotImplemented: "argsort", array.encoding().id()),
+    }
+}
+
+/// Wrap a comparator of valid values so that nulls sort first or last, and so that only the
+/// valid values are reversed when sorting in descending order.
+pub(crate) fn with_nulls(
+    nulls: Option<NullBuffer>,
+    descending: bool,
+    nulls_first: bool,
+    cmp: impl Fn(usize, usize) -> Ordering + 'static,
+) -> Comparator {
+    let null_ordering = if nulls_first {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
+    Box::new(move |a, b| {
+        let (a_valid, b_valid) = match &nulls {
+            None => (true, true),
+            Some(n) => (n.is_valid(a), n.is_valid(b)),
+        };
+        match (a_valid, b_valid) {
+            (true, true) if descending => cmp(a, b).reverse(),
+            (true, true) => cmp(a, b),
+            (false, false) => Ordering::Equal,
+            (false, true) => null_ordering,
+            (true, false) => null_ordering.reverse(),
+        }
+    })
+}
+
+/// Stably sort the positions of an array of `len` values with the comparator.
+pub(crate) fn argsort_by(len: usize, cmp: Comparator) -> Array {
+    let mut indices = (0..len as u64).collect::<Vec<_>>();
+    indices.sort_by(|&a, &b| cmp(a as usize, b as usize));
+    PrimitiveArray::from(indices).into_array()
+}
@@ -3,10 +3,16 @@ use std::collections::HashMap;
 use crate::array::bool::BoolEncoding;
 use crate::array::chunked::ChunkedEncoding;
 use crate::array::constant::ConstantEncoding;
+use crate::array::decimal::DecimalEncoding;
 use crate::array::extension::ExtensionEncoding;
+use crate::array::fixed_size_binary::FixedSizeBinaryEncoding;
+use crate::array::fixed_size_list::FixedSizeListEncoding;
+use crate::array::list::ListEncoding;
+use crate::array::map::MapEncoding;
 use crate::array::primitive::PrimitiveEncoding;
 use crate::array::r#struct::StructEncoding;
 use crate::array::sparse::SparseEncoding;
+use crate::array::union::UnionEncoding;
 use crate::array::varbin::VarBinEncoding;
 use crate::array::varbinview::VarBinViewEncoding;
 use crate::encoding::EncodingRef;
@@ -45,10 +51,16 @@ impl Default for Context {
                     &BoolEncoding as EncodingRef,
                     &ChunkedEncoding,
                     &ConstantEncoding,
+                    &DecimalEncoding,
                     &ExtensionEncoding,
+                    &FixedSizeBinaryEncoding,
+                    &FixedSizeListEncoding,
+                    &ListEncoding,
+                    &MapEncoding,
                     &PrimitiveEncoding,
                     &SparseEncoding,
                     &StructEncoding,
+                    &UnionEncoding,
                     &VarBinEncoding,
                     &VarBinViewEncoding,
                 ]
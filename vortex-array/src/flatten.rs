@@ -1,9 +1,15 @@
 use vortex_error::VortexResult;
 
 use crate::array::bool::BoolArray;
+use crate::array::decimal::DecimalArray;
 use crate::array::extension::ExtensionArray;
+use crate::array::fixed_size_binary::FixedSizeBinaryArray;
+use crate::array::fixed_size_list::FixedSizeListArray;
+use crate::array::list::ListArray;
+use crate::array::map::MapArray;
 use crate::array::primitive::PrimitiveArray;
 use crate::array::r#struct::StructArray;
+use crate::array::union::UnionArray;
 use crate::array::varbin::VarBinArray;
 use crate::array::varbinview::VarBinViewArray;
 use crate::encoding::ArrayEncoding;
@@ -13,9 +19,15 @@ use crate::{Array, IntoArray};
 pub enum Flattened {
     Bool(BoolArray),
     Primitive(PrimitiveArray),
+    Decimal(DecimalArray),
+    List(ListArray),
+    FixedSizeList(FixedSizeListArray),
+    Map(MapArray),
     Struct(StructArray),
+    Union(UnionArray),
     VarBin(VarBinArray),
     VarBinView(VarBinViewArray),
+    FixedSizeBinary(FixedSizeBinaryArray),
     Extension(ExtensionArray),
 }
 
@@ -46,10 +58,16 @@ impl IntoArray for Flattened {
         match self {
             Self::Bool(a) => a.into_array(),
             Self::Primitive(a) => a.into_array(),
+            Self::Decimal(a) => a.into_array(),
+            Self::List(a) => a.into_array(),
+            Self::FixedSizeList(a) => a.into_array(),
+            Self::Map(a) => a.into_array(),
             Self::Struct(a) => a.into_array(),
+            Self::Union(a) => a.into_array(),
             Self::VarBin(a) => a.into_array(),
             Self::Extension(a) => a.into_array(),
             Self::VarBinView(a) => a.into_array(),
+            Self::FixedSizeBinary(a) => a.into_array(),
         }
     }
 }
@@ -16,7 +16,7 @@ pub trait ArrayStreamExt: ArrayStream {
     {
         async {
             let dtype = self.dtype().clone();
-            let chunks: Vec<Array> = self.try_collect().await.unwrap();
+            let chunks: Vec<Array> = self.try_collect().await?;
             ChunkedArray::try_new(chunks, dtype)
         }
     }
@@ -5,6 +5,7 @@ use vortex_error::{vortex_bail, VortexResult};
 
 use crate::array::bool::BoolArray;
 use crate::compute::as_contiguous::as_contiguous;
+use crate::compute::filter::filter;
 use crate::compute::scalar_at::scalar_at;
 use crate::compute::slice::slice;
 use crate::compute::take::take;
@@ -105,6 +106,13 @@ impl Validity {
         }
     }
 
+    pub fn filter(&self, mask: &Array) -> VortexResult<Self> {
+        match self {
+            Self::Array(a) => Ok(Self::Array(filter(a, mask)?)),
+            _ => Ok(self.clone()),
+        }
+    }
+
     pub fn take(&self, indices: &Array) -> VortexResult<Self> {
         match self {
             Self::NonNullable => Ok(Self::NonNullable),
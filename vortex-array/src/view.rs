@@ -145,7 +145,7 @@ impl ArrayView {
     }
 
     /// The number of buffers used by the current Array and all its children.
-    fn cumulative_nbuffers(array: fb::Array) -> usize {
+    pub fn cumulative_nbuffers(array: fb::Array) -> usize {
         let mut nbuffers = if array.has_buffer() { 1 } else { 0 };
         for child in array.children().unwrap_or_default() {
             nbuffers += Self::cumulative_nbuffers(child)
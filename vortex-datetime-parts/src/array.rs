@@ -1,10 +1,17 @@
 use serde::{Deserialize, Serialize};
+use vortex::array::datetime::TimeUnit;
+use vortex::array::extension::ExtensionArray;
+use vortex::array::primitive::PrimitiveArray;
+use vortex::compute::cast::cast;
 use vortex::stats::ArrayStatisticsCompute;
-use vortex::validity::{ArrayValidity, LogicalValidity};
+use vortex::validity::{ArrayValidity, LogicalValidity, Validity};
 use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::{impl_encoding, ArrayDType, ArrayFlatten, ToArrayData};
+use vortex_dtype::PType;
 use vortex_error::vortex_bail;
 
+use crate::compress::divisor;
+
 impl_encoding!("vortex.datetimeparts", DateTimeParts);
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -81,8 +88,31 @@ impl DateTimePartsArray {
 
 impl ArrayFlatten for DateTimePartsArray {
     fn flatten(self) -> VortexResult<Flattened> {
-        // TODO(ngates): flatten into vortex.localdatetime or appropriate per dtype
-        todo!()
+        let DType::Extension(ext, _) = self.dtype() else {
+            vortex_bail!(MismatchedTypes: "datetime extension", self.dtype());
+        };
+        let divisor = divisor(TimeUnit::try_from(ext)?);
+
+        let days = cast(&self.days(), PType::I64.into())?.flatten_primitive()?;
+        let seconds = cast(&self.seconds(), PType::I64.into())?.flatten_primitive()?;
+        let subsecond = cast(&self.subsecond(), PType::I64.into())?.flatten_primitive()?;
+        let timestamps = days
+            .typed_data::<i64>()
+            .iter()
+            .zip(seconds.typed_data::<i64>())
+            .zip(subsecond.typed_data::<i64>())
+            .map(|((d, s), ss)| d * 86_400 * divisor + s * divisor + ss)
+            .collect::<Vec<_>>();
+
+        let validity = if self.dtype().is_nullable() {
+            self.logical_validity().into_validity()
+        } else {
+            Validity::NonNullable
+        };
+        Ok(Flattened::Extension(ExtensionArray::new(
+            ext.clone(),
+            PrimitiveArray::from_vec(timestamps, validity).into_array(),
+        )))
     }
 }
 
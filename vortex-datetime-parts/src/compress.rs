@@ -1,9 +1,9 @@
-use vortex::array::datetime::{LocalDateTimeArray, TimeUnit};
+use vortex::array::datetime::{LocalDateTimeArray, TimeUnit, ZonedDateTimeArray};
 use vortex::array::primitive::PrimitiveArray;
 use vortex::compress::{CompressConfig, Compressor, EncodingCompression};
 use vortex::compute::cast::cast;
-use vortex::{Array, ArrayTrait, IntoArray};
-use vortex_dtype::PType;
+use vortex::{Array, ArrayDType, ArrayTrait, IntoArray};
+use vortex_dtype::{DType, PType};
 use vortex_error::VortexResult;
 
 use crate::{DateTimePartsArray, DateTimePartsEncoding};
@@ -14,7 +14,9 @@ impl EncodingCompression for DateTimePartsEncoding {
         array: &Array,
         _config: &CompressConfig,
     ) -> Option<&dyn EncodingCompression> {
-        if LocalDateTimeArray::try_from(array).is_ok() {
+        if LocalDateTimeArray::try_from(array).is_ok()
+            || ZonedDateTimeArray::try_from(array).is_ok()
+        {
             return Some(self);
         }
         None
@@ -26,27 +28,37 @@ impl EncodingCompression for DateTimePartsEncoding {
         like: Option<&Array>,
         ctx: Compressor,
     ) -> VortexResult<Array> {
-        compress_localdatetime(
-            LocalDateTimeArray::try_from(array)?,
-            like.map(|l| DateTimePartsArray::try_from(l).unwrap()),
+        let like = like.map(|l| DateTimePartsArray::try_from(l).unwrap());
+        if let Ok(zoned) = ZonedDateTimeArray::try_from(array) {
+            // Zoned timestamps are UTC instants, so they split into parts just like local ones.
+            return compress_timestamps(
+                array.dtype(),
+                zoned.time_unit(),
+                &zoned.timestamps(),
+                like,
+                ctx,
+            );
+        }
+        let local = LocalDateTimeArray::try_from(array)?;
+        compress_timestamps(
+            array.dtype(),
+            local.time_unit(),
+            &local.timestamps(),
+            like,
             ctx,
         )
     }
 }
 
-fn compress_localdatetime(
-    array: LocalDateTimeArray,
+fn compress_timestamps(
+    dtype: &DType,
+    time_unit: TimeUnit,
+    timestamps: &Array,
     like: Option<DateTimePartsArray>,
     ctx: Compressor,
 ) -> VortexResult<Array> {
-    let timestamps = cast(&array.timestamps(), PType::I64.into())?.flatten_primitive()?;
-
-    let divisor = match array.time_unit() {
-        TimeUnit::Ns => 1_000_000_000,
-        TimeUnit::Us => 1_000_000,
-        TimeUnit::Ms => 1_000,
-        TimeUnit::S => 1,
-    };
+    let timestamps = cast(timestamps, PType::I64.into())?.flatten_primitive()?;
+    let divisor = divisor(time_unit);
 
     let length = timestamps.len();
     let mut days = Vec::with_capacity(length);
@@ -60,7 +72,7 @@ fn compress_localdatetime(
     }
 
     Ok(DateTimePartsArray::try_new(
-        array.dtype().clone(),
+        dtype.clone(),
         ctx.named("days").compress(
             &PrimitiveArray::from_vec(days, timestamps.validity()).into_array(),
             like.as_ref().map(|l| l.days()).as_ref(),
@@ -76,3 +88,71 @@ fn compress_localdatetime(
     )?
     .into_array())
 }
+
+/// The number of ticks of the time unit in a second.
+pub(crate) fn divisor(time_unit: TimeUnit) -> i64 {
+    match time_unit {
+        TimeUnit::Ns => 1_000_000_000,
+        TimeUnit::Us => 1_000_000,
+        TimeUnit::Ms => 1_000,
+        TimeUnit::S => 1,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::datetime::{LocalDateTimeArray, TimeUnit, ZonedDateTimeArray};
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::compress::{Compressor, EncodingCompression};
+    use vortex::{ArrayDType, ArrayDef, Context, IntoArray, IntoArrayData};
+
+    use crate::{DateTimeParts, DateTimePartsEncoding};
+
+    #[test]
+    fn compress_zoned() {
+        // Every second for an hour either side of the epoch, so some parts are negative.
+        let timestamps = (-3_600_000..3_600_000)
+            .step_by(1_000)
+            .map(|t: i64| t + 7)
+            .collect::<Vec<_>>();
+        let array = ZonedDateTimeArray::try_new(
+            TimeUnit::Ms,
+            "UTC".into(),
+            PrimitiveArray::from(timestamps.clone()).into_array(),
+        )
+        .unwrap()
+        .into_array_data()
+        .into_array();
+        // A zoned array with an empty timezone is still not a local datetime.
+        assert!(LocalDateTimeArray::try_from(
+            &ZonedDateTimeArray::try_new(
+                TimeUnit::S,
+                "".into(),
+                PrimitiveArray::from(vec![0i64]).into_array()
+            )
+            .unwrap()
+            .into_array_data()
+            .into_array()
+        )
+        .is_err());
+
+        let ctx = Context::default();
+        let compressed = DateTimePartsEncoding
+            .compress(&array, None, Compressor::new(&ctx))
+            .unwrap();
+        assert_eq!(compressed.encoding().id(), DateTimeParts::ID);
+        assert_eq!(compressed.dtype(), array.dtype());
+
+        let flattened =
+            ZonedDateTimeArray::try_from(&compressed.flatten().unwrap().into_array()).unwrap();
+        assert_eq!(flattened.time_zone().as_ref(), "UTC");
+        assert_eq!(
+            flattened
+                .timestamps()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<i64>(),
+            timestamps
+        );
+    }
+}
@@ -247,15 +247,20 @@ mod test {
     use std::str;
 
     use vortex::accessor::ArrayAccessor;
+    use vortex::array::constant::Constant;
+    use vortex::array::extension::{register_extension_type, ExtensionArray, ExtensionType};
     use vortex::array::primitive::PrimitiveArray;
     use vortex::array::varbin::VarBinArray;
+    use vortex::compress::Compressor;
     use vortex::compute::scalar_at::scalar_at;
-    use vortex::ToArray;
+    use vortex::encoding::EncodingRef;
+    use vortex::{ArrayDef, Context, IntoArray, ToArray};
     use vortex_dtype::Nullability::Nullable;
-    use vortex_dtype::{DType, PType};
-    use vortex_scalar::Scalar;
+    use vortex_dtype::{DType, ExtDType, ExtID, PType};
+    use vortex_scalar::{ExtScalarType, Scalar};
 
     use crate::compress::{dict_encode_typed_primitive, dict_encode_varbin};
+    use crate::{Dict, DictEncoding};
 
     #[test]
     fn encode_primitive() {
@@ -354,4 +359,39 @@ mod test {
             .unwrap();
         assert_eq!(codes.typed_data::<u64>(), &[0u64, 0, 1, 1, 0, 1, 0, 1]);
     }
+
+    /// IP addresses, which repeat often enough that we always want them dictionary encoded.
+    struct IpAddress(ExtID);
+
+    impl ExtScalarType for IpAddress {
+        fn id(&self) -> &ExtID {
+            &self.0
+        }
+    }
+
+    impl ExtensionType for IpAddress {
+        fn preferred_encoding(&self) -> Option<EncodingRef> {
+            Some(&DictEncoding)
+        }
+    }
+
+    #[test]
+    fn preferred_by_extension_type() {
+        let addresses = VarBinArray::from(vec!["10.0.0.1"; 2048]).into_array();
+        let ctx = Context::default().with_encoding(&DictEncoding);
+        let compress = |id: &str| {
+            let ext = ExtensionArray::new(ExtDType::new(id.into(), None), addresses.clone());
+            let compressed = Compressor::new(&ctx).compress(ext.array(), None).unwrap();
+            ExtensionArray::try_from(compressed)
+                .unwrap()
+                .storage()
+                .encoding()
+                .id()
+        };
+
+        // Sampling finds the constant storage of other extension types.
+        assert_eq!(compress("test.other"), Constant::ID);
+        register_extension_type(IpAddress("test.ip".into()));
+        assert_eq!(compress("test.ip"), Dict::ID);
+    }
 }
@@ -1,14 +1,32 @@
+use vortex::array::primitive::PrimitiveArray;
+use vortex::compute::aggregate::{weighted_aggregate, Aggregate, AggregateFn};
+use vortex::compute::cast::cast;
+use vortex::compute::filter::{filter, FilterFn};
 use vortex::compute::scalar_at::{scalar_at, ScalarAtFn};
 use vortex::compute::slice::{slice, SliceFn};
+use vortex::compute::sort::{argsort, ArgsortFn, SortFn};
 use vortex::compute::take::{take, TakeFn};
 use vortex::compute::ArrayCompute;
-use vortex::{Array, IntoArray};
+use vortex::{Array, ArrayDType, IntoArray};
+use vortex_dtype::match_each_integer_ptype;
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::DictArray;
 
 impl ArrayCompute for DictArray {
+    fn aggregate(&self) -> Option<&dyn AggregateFn> {
+        Some(self)
+    }
+
+    fn argsort(&self) -> Option<&dyn ArgsortFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -17,11 +35,81 @@ impl ArrayCompute for DictArray {
         Some(self)
     }
 
+    fn sort(&self) -> Option<&dyn SortFn> {
+        Some(self)
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
 }
 
+impl AggregateFn for DictArray {
+    fn aggregate(&self, agg: Aggregate) -> VortexResult<Scalar> {
+        // Aggregate each dictionary value weighted by how often its code appears.
+        let codes = self.codes().flatten_primitive()?;
+        let mut histogram = vec![0u64; self.values().len()];
+        match_each_integer_ptype!(codes.ptype(), |$P| {
+            for &code in codes.typed_data::<$P>() {
+                histogram[code as usize] += 1;
+            }
+        });
+        weighted_aggregate(&self.values(), &histogram, agg)
+    }
+}
+
+impl DictArray {
+    /// Sort the dictionary, returning the order of its values and the rank of every code.
+    ///
+    /// Dictionary values are distinct, so sorting the ranks sorts the array.
+    fn ranks(&self, descending: bool, nulls_first: bool) -> VortexResult<(Array, Vec<u64>)> {
+        let order = argsort(&self.values(), descending, nulls_first)?;
+        let mut value_ranks = vec![0u64; order.len()];
+        for (rank, &value) in order
+            .clone()
+            .flatten_primitive()?
+            .typed_data::<u64>()
+            .iter()
+            .enumerate()
+        {
+            value_ranks[value as usize] = rank as u64;
+        }
+
+        let codes = self.codes().flatten_primitive()?;
+        let ranks = match_each_integer_ptype!(codes.ptype(), |$P| {
+            codes
+                .typed_data::<$P>()
+                .iter()
+                .map(|&code| value_ranks[code as usize])
+                .collect::<Vec<_>>()
+        });
+        Ok((order, ranks))
+    }
+}
+
+impl ArgsortFn for DictArray {
+    fn argsort(&self, descending: bool, nulls_first: bool) -> VortexResult<Array> {
+        let (_, ranks) = self.ranks(descending, nulls_first)?;
+        argsort(&PrimitiveArray::from(ranks).into_array(), false, false)
+    }
+}
+
+impl SortFn for DictArray {
+    fn sort(&self, descending: bool, nulls_first: bool) -> VortexResult<Array> {
+        // Reorder the dictionary, after which the codes sort as plain integers.
+        let (order, mut ranks) = self.ranks(descending, nulls_first)?;
+        ranks.sort_unstable();
+        Self::try_new(
+            cast(
+                &PrimitiveArray::from(ranks).into_array(),
+                self.codes().dtype(),
+            )?,
+            take(&self.values(), &order)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
 impl ScalarAtFn for DictArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         let dict_index: usize = scalar_at(&self.codes(), index)?.as_ref().try_into()?;
@@ -29,6 +117,13 @@ impl ScalarAtFn for DictArray {
     }
 }
 
+impl FilterFn for DictArray {
+    fn filter(&self, mask: &Array) -> VortexResult<Array> {
+        // Only the codes are filtered, the dictionary is kept as-is.
+        Self::try_new(filter(&self.codes(), mask)?, self.values()).map(|a| a.into_array())
+    }
+}
+
 impl TakeFn for DictArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         // Dict
@@ -48,8 +143,13 @@ impl SliceFn for DictArray {
 
 #[cfg(test)]
 mod test {
+    use vortex::array::bool::BoolArray;
     use vortex::array::primitive::PrimitiveArray;
     use vortex::array::varbin::VarBinArray;
+    use vortex::compute::aggregate::{count, mean, min, sum};
+    use vortex::compute::filter::filter;
+    use vortex::compute::sort::{argsort, sort};
+    use vortex::validity::ArrayValidity;
     use vortex::{IntoArray, ToArray};
     use vortex_dtype::{DType, Nullability};
 
@@ -93,4 +193,76 @@ mod test {
             reference.bytes().flatten_primitive().unwrap().buffer()
         );
     }
+
+    #[test]
+    fn filter_codes() {
+        let reference = PrimitiveArray::from(vec![3i32, 7, 3, 3, 7]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let mask = BoolArray::from(vec![false, true, true, false, true]).into_array();
+
+        let filtered = DictArray::try_from(filter(&dict.into_array(), &mask).unwrap()).unwrap();
+        assert_eq!(filtered.values().len(), 2);
+        assert_eq!(
+            filtered
+                .into_array()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<i32>(),
+            &[7, 3, 7]
+        );
+    }
+
+    #[test]
+    fn aggregate_histogram() {
+        let reference = PrimitiveArray::from_nullable_vec(vec![
+            Some(42),
+            Some(-9),
+            None,
+            Some(42),
+            None,
+            Some(42),
+        ]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array())
+            .unwrap()
+            .into_array();
+        assert_eq!(sum(&dict).unwrap(), Some(117i64).into());
+        assert_eq!(count(&dict).unwrap(), 4);
+        assert_eq!(mean(&dict).unwrap(), Some(29.25));
+        assert_eq!(min(&dict).unwrap(), Some(-9i32).into());
+    }
+
+    #[test]
+    fn sort_dictionary() {
+        let reference = VarBinArray::from_iter(
+            vec![Some("b"), None, Some("c"), Some("a"), Some("b")],
+            DType::Utf8(Nullability::Nullable),
+        );
+        let (codes, values) = dict_encode_varbin(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array())
+            .unwrap()
+            .into_array();
+
+        assert_eq!(
+            argsort(&dict, false, true)
+                .unwrap()
+                .flatten_primitive()
+                .unwrap()
+                .typed_data::<u64>(),
+            &[1, 3, 0, 4, 2]
+        );
+
+        let sorted = DictArray::try_from(sort(&dict, true, false).unwrap()).unwrap();
+        assert_eq!(sorted.values().len(), 4);
+        let flattened = sorted.into_array().flatten_varbin().unwrap();
+        let values = (0..4)
+            .map(|i| flattened.bytes_at(i).unwrap().as_slice().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![b"c".to_vec(), b"b".to_vec(), b"b".to_vec(), b"a".to_vec()]
+        );
+        assert!(!flattened.is_valid(4));
+    }
 }
@@ -19,10 +19,21 @@ pub enum DType {
     Null,
     Bool(Nullability),
     Primitive(PType, Nullability),
+    /// A fixed-point number with the given precision and scale, stored as its unscaled integer.
+    Decimal(u8, u8, Nullability),
     Utf8(Nullability),
     Binary(Nullability),
     Struct(StructDType, Nullability),
     List(Arc<DType>, Nullability),
+    /// A list whose elements all have the given number of values.
+    FixedSizeList(Arc<DType>, u32, Nullability),
+    /// Binary values that are all the given number of bytes long.
+    FixedSizeBinary(u32, Nullability),
+    /// A map from keys of the first type to values of the second type.
+    Map(Arc<DType>, Arc<DType>, Nullability),
+    /// A value of exactly one of the named variants. Unions have no validity of their own, so a
+    /// value is null when the value of its variant is null, which only nullable unions allow.
+    Union(UnionDType, Nullability),
     Extension(ExtDType, Nullability),
 }
 
@@ -32,6 +43,9 @@ impl DType {
     /// The default DType for indices
     pub const IDX: Self = Primitive(PType::U64, Nullability::NonNullable);
 
+    /// The maximum precision of a decimal, whose unscaled value must fit in 128 bits.
+    pub const MAX_DECIMAL_PRECISION: u8 = 38;
+
     pub fn nullability(&self) -> Nullability {
         self.is_nullable().into()
     }
@@ -43,10 +57,15 @@ impl DType {
             Null => true,
             Bool(n) => matches!(n, Nullable),
             Primitive(_, n) => matches!(n, Nullable),
+            Decimal(_, _, n) => matches!(n, Nullable),
             Utf8(n) => matches!(n, Nullable),
             Binary(n) => matches!(n, Nullable),
-            Struct(st, _) => st.dtypes().iter().all(|f| f.is_nullable()),
+            Struct(_, n) => matches!(n, Nullable),
             List(_, n) => matches!(n, Nullable),
+            FixedSizeList(_, _, n) => matches!(n, Nullable),
+            FixedSizeBinary(_, n) => matches!(n, Nullable),
+            Map(_, _, n) => matches!(n, Nullable),
+            Union(_, n) => matches!(n, Nullable),
             Extension(_, n) => matches!(n, Nullable),
         }
     }
@@ -64,10 +83,15 @@ impl DType {
             Null => Null,
             Bool(_) => Bool(nullability),
             Primitive(p, _) => Primitive(*p, nullability),
+            Decimal(p, s, _) => Decimal(*p, *s, nullability),
             Utf8(_) => Utf8(nullability),
             Binary(_) => Binary(nullability),
             Struct(st, _) => Struct(st.clone(), nullability),
             List(c, _) => List(c.clone(), nullability),
+            FixedSizeList(c, size, _) => FixedSizeList(c.clone(), *size, nullability),
+            FixedSizeBinary(size, _) => FixedSizeBinary(*size, nullability),
+            Map(k, v, _) => Map(k.clone(), v.clone(), nullability),
+            Union(u, _) => Union(u.clone(), nullability),
             Extension(ext, _) => Extension(ext.clone(), nullability),
         }
     }
@@ -83,6 +107,7 @@ impl Display for DType {
             Null => write!(f, "null"),
             Bool(n) => write!(f, "bool{}", n),
             Primitive(p, n) => write!(f, "{}{}", p, n),
+            Decimal(p, s, n) => write!(f, "decimal({}, {}){}", p, s, n),
             Utf8(n) => write!(f, "utf8{}", n),
             Binary(n) => write!(f, "binary{}", n),
             Struct(st, n) => write!(
@@ -96,12 +121,25 @@ impl Display for DType {
                 n
             ),
             List(c, n) => write!(f, "list({}){}", c, n),
+            FixedSizeList(c, size, n) => write!(f, "fixed_size_list({}, {}){}", c, size, n),
+            FixedSizeBinary(size, n) => write!(f, "fixed_size_binary({}){}", size, n),
+            Map(k, v, n) => write!(f, "map({}, {}){}", k, v, n),
+            Union(u, n) => write!(
+                f,
+                "union({}){}",
+                u.names()
+                    .iter()
+                    .zip(u.dtypes().iter())
+                    .map(|(n, dt)| format!("{}={}", n, dt))
+                    .join(", "),
+                n
+            ),
             Extension(ext, n) => write!(
                 f,
                 "ext({}{}){}",
                 ext.id(),
                 ext.metadata()
-                    .map(|m| format!(", {:?}", m))
+                    .map(|m| format!(", {:?}", m.as_ref()))
                     .unwrap_or_else(|| "".to_string()),
                 n
             ),
@@ -137,6 +175,35 @@ impl StructDType {
     }
 }
 
+/// The variants of a union, identified by their index.
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnionDType {
+    names: FieldNames,
+    dtypes: Arc<[DType]>,
+}
+
+impl UnionDType {
+    pub fn new(names: FieldNames, dtypes: Vec<DType>) -> Self {
+        Self {
+            names,
+            dtypes: dtypes.into(),
+        }
+    }
+
+    pub fn names(&self) -> &FieldNames {
+        &self.names
+    }
+
+    pub fn find_name(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n.as_ref() == name)
+    }
+
+    pub fn dtypes(&self) -> &Arc<[DType]> {
+        &self.dtypes
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::mem;
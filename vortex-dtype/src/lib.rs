@@ -10,6 +10,7 @@ mod dtype;
 mod extension;
 pub mod field_paths;
 mod nullability;
+mod parse;
 mod ptype;
 mod serde;
 
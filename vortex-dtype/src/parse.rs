@@ -0,0 +1,361 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use vortex_error::{vortex_err, VortexError, VortexResult};
+
+use crate::{DType, ExtDType, ExtMetadata, FieldName, Nullability, PType, StructDType, UnionDType};
+
+/// Parses the textual form of a dtype, as printed by its [Display](std::fmt::Display) impl.
+///
+/// Field names and extension ids are taken verbatim up to the next delimiter, so names that
+/// contain any of `=,{}()[]?` can't be parsed back.
+impl FromStr for DType {
+    type Err = VortexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input: s, pos: 0 };
+        let dtype = parser.dtype()?;
+        parser.skip_whitespace();
+        if parser.pos < s.len() {
+            return Err(parser.error("unexpected trailing input"));
+        }
+        Ok(dtype)
+    }
+}
+
+/// A recursive-descent parser over the dtype grammar, tracking the byte offset for errors.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn dtype(&mut self) -> VortexResult<DType> {
+        self.skip_whitespace();
+        if self.peek() == Some('{') {
+            return self.struct_dtype();
+        }
+
+        let start = self.pos;
+        let keyword = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+        let dtype = match keyword {
+            "null" => return Ok(DType::Null),
+            "bool" => DType::Bool(self.nullability()),
+            "utf8" => DType::Utf8(self.nullability()),
+            "binary" => DType::Binary(self.nullability()),
+            "decimal" => {
+                self.expect('(')?;
+                let precision = self.integer()?;
+                if precision == 0 || precision > DType::MAX_DECIMAL_PRECISION {
+                    return Err(self.error("invalid decimal precision"));
+                }
+                self.expect(',')?;
+                let scale = self.integer()?;
+                if scale > precision {
+                    return Err(self.error("decimal scale exceeds its precision"));
+                }
+                self.expect(')')?;
+                DType::Decimal(precision, scale, self.nullability())
+            }
+            "list" => {
+                self.expect('(')?;
+                let element = self.dtype()?;
+                self.expect(')')?;
+                DType::List(Arc::new(element), self.nullability())
+            }
+            "fixed_size_list" => {
+                self.expect('(')?;
+                let element = self.dtype()?;
+                self.expect(',')?;
+                let list_size = self.integer()?;
+                self.expect(')')?;
+                DType::FixedSizeList(Arc::new(element), list_size, self.nullability())
+            }
+            "fixed_size_binary" => {
+                self.expect('(')?;
+                let byte_width = self.integer()?;
+                self.expect(')')?;
+                DType::FixedSizeBinary(byte_width, self.nullability())
+            }
+            "map" => {
+                self.expect('(')?;
+                let key = self.dtype()?;
+                self.expect(',')?;
+                let value = self.dtype()?;
+                self.expect(')')?;
+                DType::Map(Arc::new(key), Arc::new(value), self.nullability())
+            }
+            "union" => {
+                self.expect('(')?;
+                let (names, dtypes) = self.fields(')')?;
+                DType::Union(UnionDType::new(names.into(), dtypes), self.nullability())
+            }
+            "ext" => {
+                self.expect('(')?;
+                let id = self.name()?;
+                let metadata = if self.consume(',') {
+                    Some(self.metadata()?)
+                } else {
+                    None
+                };
+                self.expect(')')?;
+                DType::Extension(
+                    ExtDType::new(id.as_ref().into(), metadata),
+                    self.nullability(),
+                )
+            }
+            _ => match ptype(keyword) {
+                Some(ptype) => DType::Primitive(ptype, self.nullability()),
+                None => {
+                    self.pos = start;
+                    return Err(self.error("expected a dtype"));
+                }
+            },
+        };
+        Ok(dtype)
+    }
+
+    fn struct_dtype(&mut self) -> VortexResult<DType> {
+        self.expect('{')?;
+        let (names, dtypes) = self.fields('}')?;
+        Ok(DType::Struct(
+            StructDType::new(names.into(), dtypes),
+            self.nullability(),
+        ))
+    }
+
+    /// Comma-separated `name=dtype` pairs, up to and including the closing delimiter.
+    fn fields(&mut self, close: char) -> VortexResult<(Vec<FieldName>, Vec<DType>)> {
+        let mut names = Vec::new();
+        let mut dtypes = Vec::new();
+        if self.consume(close) {
+            return Ok((names, dtypes));
+        }
+        loop {
+            names.push(self.name()?);
+            self.expect('=')?;
+            dtypes.push(self.dtype()?);
+            if !self.consume(',') {
+                self.expect(close)?;
+                return Ok((names, dtypes));
+            }
+        }
+    }
+
+    /// A field name or extension id, running until the next delimiter.
+    fn name(&mut self) -> VortexResult<FieldName> {
+        self.skip_whitespace();
+        let name = self.take_while(|c| !"=,{}()[]?".contains(c)).trim_end();
+        if name.is_empty() {
+            return Err(self.error("expected a name"));
+        }
+        Ok(name.into())
+    }
+
+    /// Extension metadata, printed as a list of bytes like `[1, 2, 3]`.
+    fn metadata(&mut self) -> VortexResult<ExtMetadata> {
+        self.expect('[')?;
+        let mut bytes = Vec::new();
+        if !self.consume(']') {
+            loop {
+                bytes.push(self.integer::<u8>()?);
+                if !self.consume(',') {
+                    self.expect(']')?;
+                    break;
+                }
+            }
+        }
+        Ok(ExtMetadata::from(bytes.as_slice()))
+    }
+
+    fn integer<T: FromStr>(&mut self) -> VortexResult<T> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        digits.parse().map_err(|_| {
+            self.pos = start;
+            self.error("expected an integer")
+        })
+    }
+
+    fn nullability(&mut self) -> Nullability {
+        if self.input[self.pos..].starts_with('?') {
+            self.pos += 1;
+            Nullability::Nullable
+        } else {
+            Nullability::NonNullable
+        }
+    }
+
+    fn expect(&mut self, c: char) -> VortexResult<()> {
+        if self.consume(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", c)))
+        }
+    }
+
+    /// Skip any whitespace, then consume the given character if it's next.
+    fn consume(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let rest = &self.input[self.pos..];
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    fn error(&self, msg: &str) -> VortexError {
+        vortex_err!(
+            "Invalid dtype {:?}: {} at position {}",
+            self.input,
+            msg,
+            self.pos
+        )
+    }
+}
+
+fn ptype(keyword: &str) -> Option<PType> {
+    Some(match keyword {
+        "u8" => PType::U8,
+        "u16" => PType::U16,
+        "u32" => PType::U32,
+        "u64" => PType::U64,
+        "i8" => PType::I8,
+        "i16" => PType::I16,
+        "i32" => PType::I32,
+        "i64" => PType::I64,
+        "f16" => PType::F16,
+        "f32" => PType::F32,
+        "f64" => PType::F64,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::{DType, ExtDType, ExtMetadata, Nullability, PType, StructDType, UnionDType};
+
+    fn round_trip(dtype: DType) {
+        let text = dtype.to_string();
+        assert_eq!(text.parse::<DType>().unwrap(), dtype, "{}", text);
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        let ext = ExtDType::new(
+            "vortex.zoneddatetime".into(),
+            Some(ExtMetadata::from([3u8, b'U', b'T', b'C'].as_slice())),
+        );
+        let nested = DType::Struct(
+            StructDType::new(
+                vec!["a".into(), "b c".into()].into(),
+                vec![
+                    DType::Primitive(PType::I32, Nullability::Nullable),
+                    DType::List(
+                        Arc::new(DType::Utf8(Nullability::Nullable)),
+                        Nullability::NonNullable,
+                    ),
+                ],
+            ),
+            Nullability::Nullable,
+        );
+
+        for dtype in [
+            DType::Null,
+            DType::Bool(Nullability::Nullable),
+            DType::Primitive(PType::F16, Nullability::NonNullable),
+            DType::Decimal(38, 10, Nullability::Nullable),
+            DType::Binary(Nullability::NonNullable),
+            DType::Struct(
+                StructDType::new(vec![].into(), vec![]),
+                Nullability::NonNullable,
+            ),
+            DType::FixedSizeList(Arc::new(nested.clone()), 4, Nullability::Nullable),
+            DType::FixedSizeBinary(16, Nullability::NonNullable),
+            DType::Map(
+                Arc::new(DType::Utf8(Nullability::NonNullable)),
+                Arc::new(nested.clone()),
+                Nullability::Nullable,
+            ),
+            DType::Union(
+                UnionDType::new(
+                    vec!["int".into(), "nested".into()].into(),
+                    vec![
+                        DType::Primitive(PType::I64, Nullability::NonNullable),
+                        nested,
+                    ],
+                ),
+                Nullability::Nullable,
+            ),
+            DType::Extension(ext, Nullability::Nullable),
+            DType::Extension(
+                ExtDType::new("vortex.date".into(), None),
+                Nullability::NonNullable,
+            ),
+        ] {
+            round_trip(dtype);
+        }
+    }
+
+    #[test]
+    fn parse_whitespace() {
+        assert_eq!(
+            " { x = u8? ,y=list( f64 ) } ".parse::<DType>().unwrap(),
+            DType::Struct(
+                StructDType::new(
+                    vec!["x".into(), "y".into()].into(),
+                    vec![
+                        DType::Primitive(PType::U8, Nullability::Nullable),
+                        DType::List(
+                            Arc::new(DType::Primitive(PType::F64, Nullability::NonNullable)),
+                            Nullability::NonNullable
+                        ),
+                    ],
+                ),
+                Nullability::NonNullable,
+            )
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        for (text, error) in [
+            ("i33", "expected a dtype at position 0"),
+            ("list(f64", "expected ')' at position 8"),
+            ("{a=i32, =utf8}", "expected a name at position 8"),
+            ("decimal(10, x)", "expected an integer at position 12"),
+            ("decimal(39, 2)", "invalid decimal precision at position 10"),
+            (
+                "ext(vortex.date, [1, 256])",
+                "expected an integer at position 21",
+            ),
+            (
+                "decimal(10, 11)",
+                "decimal scale exceeds its precision at position 14",
+            ),
+            ("utf8?)", "unexpected trailing input at position 5"),
+        ] {
+            let err = text.parse::<DType>().unwrap_err().to_string();
+            assert!(err.contains(error), "{}: {}", text, err);
+        }
+    }
+}
@@ -8,7 +8,7 @@ use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_flatbuffers::{FlatBufferRoot, WriteFlatBuffer};
 
 use crate::{flatbuffers as fb, ExtDType, ExtID, ExtMetadata, PType};
-use crate::{DType, StructDType};
+use crate::{DType, StructDType, UnionDType};
 
 impl TryFrom<fb::DType<'_>> for DType {
     type Error = VortexError;
@@ -24,6 +24,14 @@ impl TryFrom<fb::DType<'_>> for DType {
                     fb_primitive.nullable().into(),
                 ))
             }
+            fb::Type::Decimal => {
+                let fb_decimal = fb.type__as_decimal().unwrap();
+                Ok(Self::Decimal(
+                    fb_decimal.precision(),
+                    fb_decimal.scale(),
+                    fb_decimal.nullable().into(),
+                ))
+            }
             fb::Type::Binary => Ok(Self::Binary(
                 fb.type__as_binary().unwrap().nullable().into(),
             )),
@@ -36,6 +44,44 @@ impl TryFrom<fb::DType<'_>> for DType {
                     fb_list.nullable().into(),
                 ))
             }
+            fb::Type::FixedSizeList => {
+                let fb_list = fb.type__as_fixed_size_list().unwrap();
+                let element_dtype = Self::try_from(
+                    fb_list
+                        .element_type()
+                        .ok_or_else(|| vortex_err!(InvalidSerde: "Missing list element type"))?,
+                )?;
+                Ok(Self::FixedSizeList(
+                    Arc::new(element_dtype),
+                    fb_list.list_size(),
+                    fb_list.nullable().into(),
+                ))
+            }
+            fb::Type::FixedSizeBinary => {
+                let fb_binary = fb.type__as_fixed_size_binary().unwrap();
+                Ok(Self::FixedSizeBinary(
+                    fb_binary.byte_width(),
+                    fb_binary.nullable().into(),
+                ))
+            }
+            fb::Type::Map => {
+                let fb_map = fb.type__as_map().unwrap();
+                let key_dtype = Self::try_from(
+                    fb_map
+                        .key_type()
+                        .ok_or_else(|| vortex_err!(InvalidSerde: "Missing map key type"))?,
+                )?;
+                let value_dtype = Self::try_from(
+                    fb_map
+                        .value_type()
+                        .ok_or_else(|| vortex_err!(InvalidSerde: "Missing map value type"))?,
+                )?;
+                Ok(Self::Map(
+                    Arc::new(key_dtype),
+                    Arc::new(value_dtype),
+                    fb_map.nullable().into(),
+                ))
+            }
             fb::Type::Struct_ => {
                 let fb_struct = fb.type__as_struct_().unwrap();
                 let names = fb_struct
@@ -56,6 +102,26 @@ impl TryFrom<fb::DType<'_>> for DType {
                     fb_struct.nullable().into(),
                 ))
             }
+            fb::Type::Union => {
+                let fb_union = fb.type__as_union().unwrap();
+                let names = fb_union
+                    .names()
+                    .ok_or_else(|| vortex_err!(InvalidSerde: "Missing union names"))?
+                    .iter()
+                    .map(|n| (*n).into())
+                    .collect_vec()
+                    .into();
+                let dtypes: Vec<Self> = fb_union
+                    .dtypes()
+                    .ok_or_else(|| vortex_err!(InvalidSerde: "Missing union dtypes"))?
+                    .iter()
+                    .map(Self::try_from)
+                    .collect::<VortexResult<Vec<_>>>()?;
+                Ok(Self::Union(
+                    UnionDType::new(names, dtypes),
+                    fb_union.nullable().into(),
+                ))
+            }
             fb::Type::Extension => {
                 let fb_ext = fb.type__as_extension().unwrap();
                 let id = ExtID::from(fb_ext.id().unwrap());
@@ -95,6 +161,15 @@ impl WriteFlatBuffer for DType {
                 },
             )
             .as_union_value(),
+            Self::Decimal(p, s, n) => fb::Decimal::create(
+                fbb,
+                &fb::DecimalArgs {
+                    precision: *p,
+                    scale: *s,
+                    nullable: (*n).into(),
+                },
+            )
+            .as_union_value(),
             Self::Utf8(n) => fb::Utf8::create(
                 fbb,
                 &fb::Utf8Args {
@@ -145,6 +220,64 @@ impl WriteFlatBuffer for DType {
                 )
                 .as_union_value()
             }
+            Self::FixedSizeList(e, size, n) => {
+                let element_type = Some(e.as_ref().write_flatbuffer(fbb));
+                fb::FixedSizeList::create(
+                    fbb,
+                    &fb::FixedSizeListArgs {
+                        element_type,
+                        list_size: *size,
+                        nullable: (*n).into(),
+                    },
+                )
+                .as_union_value()
+            }
+            Self::FixedSizeBinary(size, n) => fb::FixedSizeBinary::create(
+                fbb,
+                &fb::FixedSizeBinaryArgs {
+                    byte_width: *size,
+                    nullable: (*n).into(),
+                },
+            )
+            .as_union_value(),
+            Self::Map(k, v, n) => {
+                let key_type = Some(k.as_ref().write_flatbuffer(fbb));
+                let value_type = Some(v.as_ref().write_flatbuffer(fbb));
+                fb::Map::create(
+                    fbb,
+                    &fb::MapArgs {
+                        key_type,
+                        value_type,
+                        nullable: (*n).into(),
+                    },
+                )
+                .as_union_value()
+            }
+            Self::Union(u, n) => {
+                let names = u
+                    .names()
+                    .iter()
+                    .map(|n| fbb.create_string(n.as_ref()))
+                    .collect_vec();
+                let names = Some(fbb.create_vector(&names));
+
+                let dtypes = u
+                    .dtypes()
+                    .iter()
+                    .map(|dtype| dtype.write_flatbuffer(fbb))
+                    .collect_vec();
+                let dtypes = Some(fbb.create_vector(&dtypes));
+
+                fb::Union::create(
+                    fbb,
+                    &fb::UnionArgs {
+                        names,
+                        dtypes,
+                        nullable: (*n).into(),
+                    },
+                )
+                .as_union_value()
+            }
             Self::Extension(ext, n) => {
                 let id = Some(fbb.create_string(ext.id().as_ref()));
                 let metadata = ext.metadata().map(|m| fbb.create_vector(m.as_ref()));
@@ -164,10 +297,15 @@ impl WriteFlatBuffer for DType {
             Self::Null => fb::Type::Null,
             Self::Bool(_) => fb::Type::Bool,
             Self::Primitive(..) => fb::Type::Primitive,
+            Self::Decimal(..) => fb::Type::Decimal,
             Self::Utf8(_) => fb::Type::Utf8,
             Self::Binary(_) => fb::Type::Binary,
             Self::Struct(..) => fb::Type::Struct_,
             Self::List(..) => fb::Type::List,
+            Self::FixedSizeList(..) => fb::Type::FixedSizeList,
+            Self::FixedSizeBinary(..) => fb::Type::FixedSizeBinary,
+            Self::Map(..) => fb::Type::Map,
+            Self::Union(..) => fb::Type::Union,
             Self::Extension { .. } => fb::Type::Extension,
         };
 
@@ -229,7 +367,7 @@ mod test {
 
     use crate::nullability::Nullability;
     use crate::DType;
-    use crate::{flatbuffers as fb, PType, StructDType};
+    use crate::{flatbuffers as fb, PType, StructDType, UnionDType};
 
     fn roundtrip_dtype(dtype: DType) {
         let bytes = dtype.with_flatbuffer_bytes(|bytes| bytes.to_vec());
@@ -242,12 +380,34 @@ mod test {
         roundtrip_dtype(DType::Null);
         roundtrip_dtype(DType::Bool(Nullability::NonNullable));
         roundtrip_dtype(DType::Primitive(PType::U64, Nullability::NonNullable));
+        roundtrip_dtype(DType::Decimal(38, 2, Nullability::Nullable));
         roundtrip_dtype(DType::Binary(Nullability::NonNullable));
         roundtrip_dtype(DType::Utf8(Nullability::NonNullable));
         roundtrip_dtype(DType::List(
             Arc::new(DType::Primitive(PType::F32, Nullability::Nullable)),
             Nullability::NonNullable,
         ));
+        roundtrip_dtype(DType::FixedSizeList(
+            Arc::new(DType::Primitive(PType::F32, Nullability::NonNullable)),
+            768,
+            Nullability::Nullable,
+        ));
+        roundtrip_dtype(DType::FixedSizeBinary(32, Nullability::NonNullable));
+        roundtrip_dtype(DType::Map(
+            Arc::new(DType::Utf8(Nullability::NonNullable)),
+            Arc::new(DType::Primitive(PType::I64, Nullability::Nullable)),
+            Nullability::Nullable,
+        ));
+        roundtrip_dtype(DType::Union(
+            UnionDType::new(
+                ["int".into(), "str".into()].into(),
+                vec![
+                    DType::Primitive(PType::I64, Nullability::Nullable),
+                    DType::Utf8(Nullability::NonNullable),
+                ],
+            ),
+            Nullability::Nullable,
+        ));
         roundtrip_dtype(DType::Struct(
             StructDType::new(
                 ["strings".into(), "ints".into()].into(),
@@ -5,7 +5,9 @@ use std::sync::Arc;
 use vortex_error::{vortex_err, VortexError, VortexResult};
 
 use crate::proto::dtype::d_type::Type;
-use crate::{proto::dtype as pb, DType, ExtDType, ExtID, ExtMetadata, PType, StructDType};
+use crate::{
+    proto::dtype as pb, DType, ExtDType, ExtID, ExtMetadata, PType, StructDType, UnionDType,
+};
 
 impl TryFrom<&pb::DType> for DType {
     type Error = VortexError;
@@ -19,7 +21,13 @@ impl TryFrom<&pb::DType> for DType {
             Type::Null(_) => Ok(Self::Null),
             Type::Bool(b) => Ok(Self::Bool(b.nullable.into())),
             Type::Primitive(p) => Ok(Self::Primitive(p.r#type().into(), p.nullable.into())),
-            Type::Decimal(_) => todo!("Not Implemented"),
+            Type::Decimal(d) => Ok(Self::Decimal(
+                u8::try_from(d.precision)
+                    .map_err(|_| vortex_err!(InvalidSerde: "Invalid decimal precision"))?,
+                u8::try_from(d.scale)
+                    .map_err(|_| vortex_err!(InvalidSerde: "Invalid decimal scale"))?,
+                d.nullable.into(),
+            )),
             Type::Utf8(u) => Ok(Self::Utf8(u.nullable.into())),
             Type::Binary(b) => Ok(Self::Binary(b.nullable.into())),
             Type::Struct(s) => Ok(Self::Struct(
@@ -44,6 +52,42 @@ impl TryFrom<&pb::DType> for DType {
                     nullable,
                 ))
             }
+            Type::FixedSizeList(l) => Ok(Self::FixedSizeList(
+                l.element_type
+                    .as_ref()
+                    .ok_or_else(|| vortex_err!(InvalidSerde: "Invalid list element type"))?
+                    .as_ref()
+                    .try_into()
+                    .map(Arc::new)?,
+                l.list_size,
+                l.nullable.into(),
+            )),
+            Type::FixedSizeBinary(b) => Ok(Self::FixedSizeBinary(b.byte_width, b.nullable.into())),
+            Type::Map(m) => Ok(Self::Map(
+                m.key_type
+                    .as_ref()
+                    .ok_or_else(|| vortex_err!(InvalidSerde: "Invalid map key type"))?
+                    .as_ref()
+                    .try_into()
+                    .map(Arc::new)?,
+                m.value_type
+                    .as_ref()
+                    .ok_or_else(|| vortex_err!(InvalidSerde: "Invalid map value type"))?
+                    .as_ref()
+                    .try_into()
+                    .map(Arc::new)?,
+                m.nullable.into(),
+            )),
+            Type::Union(u) => Ok(Self::Union(
+                UnionDType::new(
+                    u.names.iter().map(|s| s.as_str().into()).collect(),
+                    u.dtypes
+                        .iter()
+                        .map(TryInto::<Self>::try_into)
+                        .collect::<VortexResult<Vec<_>>>()?,
+                ),
+                u.nullable.into(),
+            )),
             Type::Extension(e) => Ok(Self::Extension(
                 ExtDType::new(
                     ExtID::from(e.id.as_str()),
@@ -67,6 +111,11 @@ impl From<&DType> for pb::DType {
                     r#type: pb::PType::from(*ptype).into(),
                     nullable: (*n).into(),
                 }),
+                DType::Decimal(p, s, n) => Type::Decimal(pb::Decimal {
+                    precision: (*p).into(),
+                    scale: (*s).into(),
+                    nullable: (*n).into(),
+                }),
                 DType::Utf8(n) => Type::Utf8(pb::Utf8 {
                     nullable: (*n).into(),
                 }),
@@ -82,6 +131,27 @@ impl From<&DType> for pb::DType {
                     element_type: Some(Box::new(l.as_ref().into())),
                     nullable: (*n).into(),
                 })),
+                DType::FixedSizeList(l, size, n) => {
+                    Type::FixedSizeList(Box::new(pb::FixedSizeList {
+                        element_type: Some(Box::new(l.as_ref().into())),
+                        list_size: *size,
+                        nullable: (*n).into(),
+                    }))
+                }
+                DType::FixedSizeBinary(size, n) => Type::FixedSizeBinary(pb::FixedSizeBinary {
+                    byte_width: *size,
+                    nullable: (*n).into(),
+                }),
+                DType::Map(k, v, n) => Type::Map(Box::new(pb::Map {
+                    key_type: Some(Box::new(k.as_ref().into())),
+                    value_type: Some(Box::new(v.as_ref().into())),
+                    nullable: (*n).into(),
+                })),
+                DType::Union(u, n) => Type::Union(pb::Union {
+                    names: u.names().iter().map(|s| s.as_ref().to_string()).collect(),
+                    dtypes: u.dtypes().iter().map(Into::into).collect(),
+                    nullable: (*n).into(),
+                }),
                 DType::Extension(e, n) => Type::Extension(pb::Extension {
                     id: e.id().as_ref().into(),
                     metadata: e.metadata().map(|m| m.as_ref().into()),
@@ -57,6 +57,8 @@ pub enum VortexError {
     NotImplemented(ErrString, ErrString, Backtrace),
     #[error("expected type: {0} but instead got {1}\nBacktrace:\n{2}")]
     MismatchedTypes(ErrString, ErrString, Backtrace),
+    #[error("checksum mismatch in {1} of message at offset {0}\nBacktrace:\n{2}")]
+    ChecksumMismatch(u64, ErrString, Backtrace),
     #[error(transparent)]
     ArrowError(
         #[from]
@@ -164,6 +166,12 @@ macro_rules! vortex_err {
             $crate::VortexError::MismatchedTypes($expected.to_string().into(), $actual.to_string().into(), Backtrace::capture())
         )
     }};
+    (ChecksumMismatch: $offset:expr, $what:expr) => {{
+        use std::backtrace::Backtrace;
+        $crate::__private::must_use(
+            $crate::VortexError::ChecksumMismatch($offset, $what.into(), Backtrace::capture())
+        )
+    }};
     ($variant:ident: $fmt:literal $(, $arg:expr)* $(,)?) => {{
         use std::backtrace::Backtrace;
         $crate::__private::must_use(
@@ -141,8 +141,10 @@ fn trailing_zeros(array: &Array) -> u8 {
 
 #[cfg(test)]
 mod test {
+    use vortex::array::decimal::DecimalArray;
     use vortex::compute::scalar_at::ScalarAtFn;
     use vortex::encoding::{ArrayEncoding, EncodingRef};
+    use vortex::validity::Validity;
     use vortex::Context;
 
     use super::*;
@@ -168,6 +170,23 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_compress_decimal() {
+        // The unscaled integers of a decimal array are compressed like any other integers.
+        let array = DecimalArray::from_unscaled(
+            12,
+            2,
+            (0i128..10_000).map(|v| v + 1_000_000).collect_vec(),
+            Validity::NonNullable,
+        )
+        .unwrap();
+        let compressed = Compressor::new(&ctx())
+            .compress(array.array(), None)
+            .unwrap();
+        let unscaled = DecimalArray::try_from(compressed).unwrap().unscaled();
+        assert_eq!(unscaled[0].encoding().id(), FoREncoding.id());
+    }
+
     #[test]
     fn test_decompress() {
         // Create a range offset by a million
@@ -1,15 +1,28 @@
+use vortex::array::constant::ConstantArray;
+use vortex::compute::aggregate::{aggregate, count, Aggregate, AggregateFn, SumAccumulator};
+use vortex::compute::arithmetic::{binary_numeric, BinaryNumericFn, NumericOperator};
 use vortex::compute::scalar_at::{scalar_at, ScalarAtFn};
 use vortex::compute::slice::{slice, SliceFn};
 use vortex::compute::take::{take, TakeFn};
 use vortex::compute::ArrayCompute;
-use vortex::{Array, IntoArray};
-use vortex_dtype::match_each_integer_ptype;
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::{Array, ArrayDType, IntoArray};
+use vortex_dtype::{match_each_integer_ptype, Nullability, PType};
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::{PrimitiveScalar, Scalar, ScalarValue};
 
+use crate::r#for::compress::decompress;
 use crate::FoRArray;
 
 impl ArrayCompute for FoRArray {
+    fn aggregate(&self) -> Option<&dyn AggregateFn> {
+        Some(self)
+    }
+
+    fn binary_numeric(&self) -> Option<&dyn BinaryNumericFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -23,6 +36,100 @@ impl ArrayCompute for FoRArray {
     }
 }
 
+impl AggregateFn for FoRArray {
+    fn aggregate(&self, agg: Aggregate) -> VortexResult<Scalar> {
+        let encoded = self.encoded();
+        if agg == Aggregate::Count {
+            return aggregate(&encoded, agg);
+        }
+
+        // Values are the encoded offsets plus the reference, as long as no offset wrapped around.
+        let ptype = PType::try_from(self.dtype())?;
+        let unwrapped = self.shift() == 0
+            && (ptype.is_unsigned_int()
+                || match_each_integer_ptype!(ptype, |$T| {
+                    encoded
+                        .statistics()
+                        .compute_as_cast::<$T>(Stat::Min)
+                        .map(|min| min >= <$T>::default())
+                        .unwrap_or(false)
+                }));
+
+        match agg {
+            Aggregate::Sum if unwrapped => {
+                let mut sum = SumAccumulator::new(ptype)?;
+                sum.add_scalar(&aggregate(&encoded, agg)?, 1)?;
+                sum.add_scalar(self.reference(), count(&encoded)? as u64)?;
+                sum.finish()
+            }
+            Aggregate::Min | Aggregate::Max if unwrapped => {
+                let extreme = aggregate(&encoded, agg)?;
+                if extreme.is_null() {
+                    return Ok(extreme);
+                }
+                match_each_integer_ptype!(ptype, |$T| {
+                    let reference: $T = self.reference().try_into()?;
+                    let offset: $T = (&extreme).try_into()?;
+                    Ok(Scalar::primitive(offset + reference, Nullability::Nullable))
+                })
+            }
+            _ => aggregate(&decompress(self.clone())?.into_array(), agg),
+        }
+    }
+}
+
+impl BinaryNumericFn for FoRArray {
+    fn binary_numeric(&self, rhs: &Array, op: NumericOperator) -> VortexResult<Array> {
+        // Adding or subtracting a constant only moves the reference.
+        if let Ok(constant) = ConstantArray::try_from(rhs) {
+            if let Some(reference) = shifted_reference(self, constant.scalar(), op)? {
+                return Self::try_new(self.encoded(), reference, self.shift())
+                    .map(|a| a.into_array());
+            }
+        }
+        binary_numeric(&decompress(self.clone())?.into_array(), rhs, op)
+    }
+}
+
+/// The reference after adding or subtracting `constant`, if no value of the array can overflow.
+fn shifted_reference(
+    array: &FoRArray,
+    constant: &Scalar,
+    op: NumericOperator,
+) -> VortexResult<Option<Scalar>> {
+    if array.shift() != 0
+        || !matches!(op, NumericOperator::Add | NumericOperator::Sub)
+        || constant.is_null()
+        // A nullable constant would make the result nullable, which the reference can't express.
+        || (constant.dtype().is_nullable() && !array.dtype().is_nullable())
+    {
+        return Ok(None);
+    }
+
+    let ptype = PType::try_from(array.dtype())?;
+    let encoded = array.encoded();
+    match_each_integer_ptype!(ptype, |$T| {
+        let reference: $T = array.reference().try_into()?;
+        let delta: $T = constant.try_into()?;
+        // Encoded values are offsets from the reference, so without wrapping the array spans
+        // reference..=reference + max.
+        let (Some(min), Some(max)) = (
+            encoded.statistics().compute_as_cast::<$T>(Stat::Min),
+            encoded.statistics().compute_as_cast::<$T>(Stat::Max),
+        ) else {
+            return Ok(None);
+        };
+        if min < <$T>::default() {
+            return Ok(None);
+        }
+        let shifted = reference.checked_add(max).and_then(|upper| match op {
+            NumericOperator::Add => upper.checked_add(delta).and(reference.checked_add(delta)),
+            _ => upper.checked_sub(delta).and(reference.checked_sub(delta)),
+        });
+        Ok(shifted.map(Scalar::from))
+    })
+}
+
 impl TakeFn for FoRArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         Self::try_new(
@@ -68,10 +175,12 @@ impl SliceFn for FoRArray {
 mod test {
     use vortex::array::primitive::PrimitiveArray;
     use vortex::compress::{Compressor, EncodingCompression};
+    use vortex::compute::aggregate::{count, min, sum};
+    use vortex::compute::arithmetic::{scalar_numeric, NumericOperator};
     use vortex::compute::scalar_at::scalar_at;
     use vortex::Context;
 
-    use crate::FoREncoding;
+    use crate::{FoRArray, FoREncoding};
 
     #[test]
     fn for_scalar_at() {
@@ -86,4 +195,38 @@ mod test {
         assert_eq!(scalar_at(&forarr, 1).unwrap(), 15.into());
         assert_eq!(scalar_at(&forarr, 2).unwrap(), 19.into());
     }
+
+    #[test]
+    fn for_add_scalar() {
+        let forarr = FoREncoding
+            .compress(
+                PrimitiveArray::from(vec![11i32, 15, 19]).array(),
+                None,
+                Compressor::new(&Context::default()),
+            )
+            .unwrap();
+        let shifted = scalar_numeric(&forarr, &5i32.into(), NumericOperator::Add).unwrap();
+        assert_eq!(
+            FoRArray::try_from(&shifted).unwrap().reference(),
+            &16i32.into()
+        );
+        assert_eq!(scalar_at(&shifted, 2).unwrap(), 24i32.into());
+
+        // Overflow is still reported once the reference can't absorb the constant.
+        assert!(scalar_numeric(&forarr, &i32::MAX.into(), NumericOperator::Add).is_err());
+    }
+
+    #[test]
+    fn for_aggregate() {
+        let forarr = FoREncoding
+            .compress(
+                PrimitiveArray::from(vec![11u32, 15, 19]).array(),
+                None,
+                Compressor::new(&Context::default()),
+            )
+            .unwrap();
+        assert_eq!(sum(&forarr).unwrap(), Some(45u64).into());
+        assert_eq!(min(&forarr).unwrap(), Some(11u32).into());
+        assert_eq!(count(&forarr).unwrap(), 3);
+    }
 }
@@ -0,0 +1,53 @@
+use crc32c::{crc32c, crc32c_append};
+use flatbuffers::root;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::flatbuffers::ipc as fb;
+
+/// The CRC32C of a buffer, as recorded in the messages of a stream written with checksums.
+pub(crate) fn checksum(bytes: &[u8]) -> u32 {
+    crc32c(bytes)
+}
+
+/// The position of the checksum field within a serialized message, if it has one.
+pub(crate) fn checksum_position(message: fb::Message) -> Option<usize> {
+    match message._tab.vtable().get(fb::Message::VT_CHECKSUM) {
+        0 => None,
+        field_offset => Some(message._tab.loc() + field_offset as usize),
+    }
+}
+
+/// The checksum of a message, computed as if its checksum field was zeroed.
+pub(crate) fn message_checksum(bytes: &[u8], position: usize) -> u32 {
+    let crc = crc32c_append(crc32c(&bytes[..position]), &[0u8; 4]);
+    crc32c_append(crc, &bytes[position + 4..])
+}
+
+/// Verify the checksum of a message, including its padding, returning whether it had one.
+///
+/// The offset of the message in the stream is only used to report a mismatch.
+pub(crate) fn verify_message(bytes: &[u8], offset: u64) -> VortexResult<bool> {
+    let message = root::<fb::Message>(bytes).map_err(
+        |e| vortex_err!(InvalidSerde: "Failed to parse flatbuffer message at offset {}: {:?}", offset, e),
+    )?;
+    let (Some(expected), Some(position)) = (message.checksum(), checksum_position(message)) else {
+        return Ok(false);
+    };
+    if message_checksum(bytes, position) != expected {
+        vortex_bail!(ChecksumMismatch: offset, "message");
+    }
+    Ok(true)
+}
+
+/// Verify the checksum of the nth buffer of the message at the given offset.
+pub(crate) fn verify_buffer(
+    bytes: &[u8],
+    expected: u32,
+    offset: u64,
+    idx: usize,
+) -> VortexResult<()> {
+    if checksum(bytes) != expected {
+        vortex_bail!(ChecksumMismatch: offset, format!("buffer {}", idx));
+    }
+    Ok(())
+}
@@ -6,7 +6,7 @@ use derive_builder::Builder;
 use vortex::{Array, ViewContext};
 use vortex_dtype::DType;
 
-use crate::io::VortexReadAt;
+use crate::io::{ReadPlanner, VortexReadAt};
 
 /// A reader for a chunked array.
 #[allow(dead_code)]
@@ -20,6 +20,14 @@ pub struct ChunkedArrayReader<R: VortexReadAt> {
     // One row per chunk + 1 row for the end of the last chunk.
     byte_offsets: Array,
     row_offsets: Array,
+
+    /// The end of each chunk, if it may be before the start of the next one.
+    #[builder(default, setter(strip_option))]
+    byte_ends: Option<Array>,
+
+    /// Overrides the planner derived from the performance hint of `read`.
+    #[builder(default, setter(strip_option))]
+    read_planner: Option<ReadPlanner>,
 }
 
 impl<R: VortexReadAt> ChunkedArrayReader<R> {
@@ -34,6 +42,11 @@ impl<R: VortexReadAt> ChunkedArrayReaderBuilder<R> {
             if byte_offsets.len() != row_offsets.len() {
                 return Err("byte_offsets and row_offsets must have the same length".to_string());
             }
+            if let Some(Some(byte_ends)) = &self.byte_ends {
+                if byte_ends.len() != byte_offsets.len().saturating_sub(1) {
+                    return Err("byte_ends must have one offset per chunk".to_string());
+                }
+            }
         }
         Ok(())
     }
@@ -1,31 +1,28 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 use std::collections::HashMap;
-use std::future::ready;
 use std::io::Cursor;
-use std::ops::Deref;
+use std::ops::Range;
 
 use bytes::BytesMut;
-use futures_util::TryStreamExt;
 use itertools::Itertools;
 use vortex::array::chunked::ChunkedArray;
 use vortex::array::primitive::PrimitiveArray;
+use vortex::compute::as_contiguous::as_contiguous;
 use vortex::compute::cast::cast;
 use vortex::compute::scalar_subtract::subtract_scalar;
-use vortex::compute::search_sorted::{search_sorted, SearchSortedSide};
 use vortex::compute::slice::slice;
 use vortex::compute::take::take;
 use vortex::stats::ArrayStatistics;
-use vortex::stream::ArrayStreamExt;
 use vortex::{Array, ArrayDType, IntoArray};
 use vortex_buffer::Buffer;
 use vortex_dtype::PType;
-use vortex_error::{vortex_bail, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::chunked_reader::ChunkedArrayReader;
-use crate::io::VortexReadAt;
-use crate::stream_reader::StreamArrayReader;
+use crate::io::{ReadPlanner, VortexReadAt};
+use crate::MessageReader;
 
 impl<R: VortexReadAt> ChunkedArrayReader<R> {
     pub async fn take_rows(&mut self, indices: &Array) -> VortexResult<Array> {
@@ -39,17 +36,34 @@ impl<R: VortexReadAt> ChunkedArrayReader<R> {
             return self.take_rows_strict_sorted(indices).await;
         }
 
-        //         // Figure out which chunks are relevant to the read operation using the row_offsets array.
-        //         // Depending on whether there are more indices than chunks, we may wish to perform this
-        //         // join differently.
-        //
-        //         // Coalesce the chunks we care about by some metric.
-        //
-        //         // TODO(ngates): we could support read_into for array builders since we know the size
-        //         //  of the result.
-        //         // Read the relevant chunks.
-        // Reshuffle the result as per the original sort order.
-        unimplemented!()
+        if indices.is_empty() {
+            return Ok(ChunkedArray::try_new(vec![], self.dtype.clone())?.into_array());
+        }
+
+        // Sort and dedupe the indices, remembering where each original index ended up.
+        let indices = cast(indices, PType::U64.into())?.flatten_primitive()?;
+        let indices = indices.typed_data::<u64>();
+        let sort_order = (0..indices.len())
+            .sorted_by_key(|&pos| indices[pos])
+            .collect_vec();
+
+        let mut unique_indices: Vec<u64> = Vec::with_capacity(indices.len());
+        let mut positions = vec![0u64; indices.len()];
+        for pos in sort_order {
+            let idx = indices[pos];
+            if unique_indices.last() != Some(&idx) {
+                unique_indices.push(idx);
+            }
+            positions[pos] = (unique_indices.len() - 1) as u64;
+        }
+
+        let taken = self
+            .take_rows_strict_sorted(&PrimitiveArray::from(unique_indices).into_array())
+            .await?;
+
+        // Reshuffle the result as per the original order of the indices.
+        let taken = as_contiguous(&ChunkedArray::try_from(taken)?.chunks().collect_vec())?;
+        take(&taken, &PrimitiveArray::from(positions).into_array())
     }
 
     /// Take rows from a chunked array given strict sorted indices.
@@ -64,95 +78,103 @@ impl<R: VortexReadAt> ChunkedArrayReader<R> {
         let chunk_idxs = find_chunks(&self.row_offsets, indices)?;
 
         // Coalesce the chunks that we're going to read from.
-        let coalesced_chunks = self.coalesce_chunks(chunk_idxs.as_ref());
-
-        // Grab the row and byte offsets for each chunk range.
-        let start_chunks = PrimitiveArray::from(
-            coalesced_chunks
-                .iter()
-                .map(|chunks| chunks[0].chunk_idx)
-                .collect_vec(),
-        )
-        .into_array();
-        let start_rows = take(&self.row_offsets, &start_chunks)?.flatten_primitive()?;
-        let start_bytes = take(&self.byte_offsets, &start_chunks)?.flatten_primitive()?;
-
-        let stop_chunks = PrimitiveArray::from(
-            coalesced_chunks
-                .iter()
-                .map(|chunks| chunks.last().unwrap().chunk_idx + 1)
-                .collect_vec(),
-        )
-        .into_array();
-        let stop_rows = take(&self.row_offsets, &stop_chunks)?.flatten_primitive()?;
-        let stop_bytes = take(&self.byte_offsets, &stop_chunks)?.flatten_primitive()?;
+        let coalesced_chunks = self.coalesce_chunks(chunk_idxs.as_ref())?;
+
+        let row_offsets = cast(&self.row_offsets, PType::U64.into())?.flatten_primitive()?;
+        let row_offsets = row_offsets.typed_data::<u64>();
+        let byte_ranges = self.byte_ranges()?;
 
-        // For each chunk-range, read the data as an ArrayStream and call take on it.
         let mut chunks = vec![];
-        for (range_idx, chunk_range) in coalesced_chunks.into_iter().enumerate() {
-            let start_chunk = chunk_range.first().unwrap().chunk_idx;
-            let stop_chunk = chunk_range.last().unwrap().chunk_idx + 1;
-
-            let (start_byte, stop_byte) = (
-                start_bytes.get_as_cast::<u64>(range_idx),
-                stop_bytes.get_as_cast::<u64>(range_idx),
-            );
+        for chunk_range in coalesced_chunks {
+            let start_byte = byte_ranges[chunk_range.first().unwrap().chunk_idx as usize].start;
+            let stop_byte = byte_ranges[chunk_range.last().unwrap().chunk_idx as usize].end;
             let range_byte_len = (stop_byte - start_byte) as usize;
-            let (start_row, stop_row) = (
-                start_rows.get_as_cast::<u64>(range_idx),
-                stop_rows.get_as_cast::<u64>(range_idx),
-            );
-            let range_row_len = (stop_row - start_row) as usize;
-
-            // Relativize the indices to these chunks
-            let indices_start =
-                search_sorted(indices, start_row, SearchSortedSide::Left)?.to_index();
-            let indices_stop =
-                search_sorted(indices, stop_row, SearchSortedSide::Right)?.to_index();
-            let relative_indices = slice(indices, indices_start, indices_stop)?;
-            let start_row = Scalar::from(start_row).cast(relative_indices.dtype())?;
-            let relative_indices = subtract_scalar(&relative_indices, &start_row)?;
-
-            // Set up an array reader to read this range of chunks.
+
             let mut buffer = BytesMut::with_capacity(range_byte_len);
             unsafe { buffer.set_len(range_byte_len) }
             // TODO(ngates): instead of reading the whole range into a buffer, we should stream
             //  the byte range (e.g. if its coming from an HTTP endpoint) and wrap that with an
             //  MesssageReader.
-            let buffer = self.read.read_at_into(start_byte, buffer).await?;
-
-            let mut reader = StreamArrayReader::try_new(Cursor::new(Buffer::from(buffer.freeze())))
+            let buffer = self.read.read_at_into(start_byte, buffer).await?.freeze();
+
+            // Each chunk is decoded on its own, since the bytes between chunks aren't necessarily
+            // chunk messages, e.g. the footer of a file before it was appended to.
+            for chunk_indices in chunk_range {
+                let chunk_idx = chunk_indices.chunk_idx as usize;
+                let Range { start: begin, end } = byte_ranges[chunk_idx];
+                let chunk_bytes =
+                    buffer.slice((begin - start_byte) as usize..(end - start_byte) as usize);
+                let chunk = MessageReader::try_new_at(
+                    Cursor::new(Buffer::from(chunk_bytes)),
+                    begin,
+                )
                 .await?
-                .with_view_context(self.view_context.deref().clone())
-                .with_dtype(self.dtype.clone());
-
-            // Take the indices from the stream.
-            reader
-                .array_stream()
-                .take_rows(&relative_indices)?
-                .try_for_each(|chunk| {
-                    chunks.push(chunk);
-                    ready(Ok(()))
-                })
-                .await?;
+                .maybe_read_chunk(self.view_context.clone(), self.dtype.clone())
+                .await?
+                .ok_or_else(
+                    || vortex_err!(InvalidSerde: "Expected chunk message at offset {}", begin),
+                )?;
+
+                // Relativize the indices to this chunk.
+                let relative_indices = slice(
+                    indices,
+                    chunk_indices.indices_start as usize,
+                    chunk_indices.indices_stop as usize,
+                )?;
+                let start_row =
+                    Scalar::from(row_offsets[chunk_idx]).cast(relative_indices.dtype())?;
+                let relative_indices = subtract_scalar(&relative_indices, &start_row)?;
+                chunks.push(take(&chunk, &relative_indices)?);
+            }
         }
 
         Ok(ChunkedArray::try_new(chunks, self.dtype.clone())?.into_array())
     }
 
+    /// The bytes of every chunk, which end where the next chunk starts unless explicit end
+    /// offsets were given.
+    fn byte_ranges(&self) -> VortexResult<Vec<Range<u64>>> {
+        let byte_offsets = cast(&self.byte_offsets, PType::U64.into())?.flatten_primitive()?;
+        let byte_offsets = byte_offsets.typed_data::<u64>();
+        let byte_ends = match &self.byte_ends {
+            Some(byte_ends) => cast(byte_ends, PType::U64.into())?
+                .flatten_primitive()?
+                .typed_data::<u64>()
+                .to_vec(),
+            None => byte_offsets.iter().skip(1).copied().collect_vec(),
+        };
+        Ok(byte_offsets
+            .iter()
+            .zip(byte_ends)
+            .map(|(&begin, end)| begin..end)
+            .collect_vec())
+    }
+
     /// Coalesce reads for the given chunks.
     ///
-    /// This depends on a few factors:
-    /// * The number of bytes between adjacent selected chunks.
-    /// * The latency of the underlying storage.
-    /// * The throughput of the underlying storage.
-    fn coalesce_chunks(&self, chunk_idxs: &[ChunkIndices]) -> Vec<Vec<ChunkIndices>> {
-        let _hint = self.read.performance_hint();
-        chunk_idxs
+    /// Chunks are grouped into a single read if the bytes of the unselected chunks between them
+    /// are within the gap threshold of the [ReadPlanner], which by default is derived from the
+    /// performance hint of the underlying storage.
+    fn coalesce_chunks(&self, chunk_idxs: &[ChunkIndices]) -> VortexResult<Vec<Vec<ChunkIndices>>> {
+        let byte_ranges = self.byte_ranges()?;
+        let ranges = chunk_idxs
             .iter()
-            .cloned()
-            .map(|chunk_idx| vec![chunk_idx.clone()])
-            .collect_vec()
+            .map(|c| byte_ranges[c.chunk_idx as usize].clone())
+            .collect_vec();
+
+        let planner = self
+            .read_planner
+            .unwrap_or_else(|| ReadPlanner::for_read(&self.read));
+        Ok(planner
+            .plan(&ranges)
+            .into_iter()
+            .map(|read| {
+                read.requests
+                    .into_iter()
+                    .map(|pos| chunk_idxs[pos].clone())
+                    .collect_vec()
+            })
+            .collect_vec())
     }
 }
 
@@ -212,6 +234,7 @@ struct ChunkIndices {
 mod test {
     use std::io::Cursor;
 
+    use bytes::BytesMut;
     use itertools::Itertools;
     use vortex::array::chunked::ChunkedArray;
     use vortex::array::primitive::PrimitiveArray;
@@ -220,7 +243,8 @@ mod test {
     use vortex_dtype::PType;
     use vortex_error::VortexResult;
 
-    use crate::chunked_reader::ChunkedArrayReaderBuilder;
+    use crate::chunked_reader::{ChunkedArrayReader, ChunkedArrayReaderBuilder};
+    use crate::io::VortexReadAt;
     use crate::writer::ArrayWriter;
     use crate::MessageReader;
 
@@ -238,8 +262,7 @@ mod test {
             .await
     }
 
-    #[tokio::test]
-    async fn test_take_rows() -> VortexResult<()> {
+    async fn chunked_reader() -> VortexResult<ChunkedArrayReader<Buffer>> {
         let writer = chunked_array().await?;
 
         let array_layout = writer.array_layouts()[0].clone();
@@ -252,14 +275,19 @@ mod test {
         let view_ctx = msgs.read_view_context(&Default::default()).await?;
         let dtype = msgs.read_dtype().await?;
 
-        let mut reader = ChunkedArrayReaderBuilder::default()
+        Ok(ChunkedArrayReaderBuilder::default()
             .read(buffer)
             .view_context(view_ctx)
             .dtype(dtype)
             .row_offsets(row_offsets.into_array())
             .byte_offsets(byte_offsets.into_array())
             .build()
-            .unwrap();
+            .unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_take_rows() -> VortexResult<()> {
+        let mut reader = chunked_reader().await?;
 
         let result = reader
             .take_rows(&PrimitiveArray::from(vec![0u64, 10, 10_000 - 1]).into_array())
@@ -271,4 +299,85 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_take_rows_unsorted() -> VortexResult<()> {
+        let mut reader = chunked_reader().await?;
+
+        let result = reader
+            .take_rows(
+                &PrimitiveArray::from(vec![9_999u32, 5, 1_005, 5, 3_002, 9_999, 0]).into_array(),
+            )
+            .await?
+            .flatten_primitive()?;
+        assert_eq!(result.typed_data::<i32>(), &[999, 5, 5, 5, 2, 999, 0]);
+
+        // A permutation without duplicates must also be reshuffled.
+        let result = reader
+            .take_rows(&PrimitiveArray::from(vec![2_001u64, 1_001, 1]).into_array())
+            .await?
+            .flatten_primitive()?;
+        assert_eq!(result.typed_data::<i32>(), &[1, 1, 1]);
+
+        let result = reader
+            .take_rows(&PrimitiveArray::from(vec![3u64, 2, 1]).into_array())
+            .await?
+            .flatten_primitive()?;
+        assert_eq!(result.typed_data::<i32>(), &[3, 2, 1]);
+
+        assert!(reader
+            .take_rows(&PrimitiveArray::from(vec![10_000u64, 1]).into_array())
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    /// Counts the number of reads issued through the wrapped reader.
+    #[derive(Clone)]
+    struct CountingReadAt {
+        read: Buffer,
+        reads: usize,
+        hint: usize,
+    }
+
+    impl VortexReadAt for CountingReadAt {
+        async fn read_at_into(&mut self, pos: u64, buffer: BytesMut) -> std::io::Result<BytesMut> {
+            self.reads += 1;
+            self.read.read_at_into(pos, buffer).await
+        }
+
+        async fn size(&self) -> std::io::Result<u64> {
+            self.read.size().await
+        }
+
+        fn performance_hint(&self) -> usize {
+            self.hint
+        }
+    }
+
+    #[tokio::test]
+    async fn test_take_rows_coalesced() -> VortexResult<()> {
+        let reader = chunked_reader().await?;
+        let indices = PrimitiveArray::from(vec![5u64, 2_005, 4_005, 9_005]).into_array();
+
+        for (hint, expected_reads) in [(0, 4), (10_000, 2), (usize::MAX, 1)] {
+            let mut reader = ChunkedArrayReaderBuilder::default()
+                .read(CountingReadAt {
+                    read: reader.read.clone(),
+                    reads: 0,
+                    hint,
+                })
+                .view_context(reader.view_context.clone())
+                .dtype(reader.dtype.clone())
+                .row_offsets(reader.row_offsets.clone())
+                .byte_offsets(reader.byte_offsets.clone())
+                .build()
+                .unwrap();
+
+            let result = reader.take_rows(&indices).await?.flatten_primitive()?;
+            assert_eq!(result.typed_data::<i32>(), &[5, 5, 5, 5]);
+            assert_eq!(reader.read.reads, expected_reads, "hint {}", hint);
+        }
+        Ok(())
+    }
 }
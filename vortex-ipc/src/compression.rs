@@ -0,0 +1,149 @@
+use std::io;
+
+use bytes::Bytes;
+use vortex_buffer::Buffer;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::flatbuffers::ipc as fb;
+use crate::ALIGNMENT;
+
+/// How a [crate::MessageWriter] compresses the buffers of each chunk.
+///
+/// Buffers are compressed individually, and any buffer that doesn't shrink is written as-is.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BufferCompression {
+    #[default]
+    None,
+    Zstd {
+        level: i32,
+    },
+    LZ4,
+}
+
+impl BufferCompression {
+    /// Returns the bytes to write for the buffer, along with the compression applied to them.
+    pub(crate) fn compress(&self, buffer: Buffer) -> io::Result<(Buffer, fb::Compression)> {
+        let (compressed, compression) = match self {
+            Self::None => return Ok((buffer, fb::Compression::None)),
+            Self::Zstd { level } => (
+                zstd::bulk::compress(buffer.as_ref(), *level)?,
+                fb::Compression::Zstd,
+            ),
+            Self::LZ4 => (
+                lz4_flex::compress_prepend_size(buffer.as_ref()),
+                fb::Compression::LZ4,
+            ),
+        };
+
+        if compressed.len() < buffer.len() {
+            Ok((Buffer::from(compressed), compression))
+        } else {
+            Ok((buffer, fb::Compression::None))
+        }
+    }
+}
+
+/// Decompress a buffer as it was read from the stream.
+///
+/// Decompressed buffers are aligned to [ALIGNMENT], just like uncompressed buffers, so their
+/// values can be accessed in place.
+pub(crate) fn decompress(buffer: Buffer, compression: fb::Compression) -> VortexResult<Buffer> {
+    match compression {
+        fb::Compression::None => Ok(buffer),
+        fb::Compression::Zstd => {
+            let len = zstd::zstd_safe::get_frame_content_size(buffer.as_ref())
+                .ok()
+                .flatten()
+                .ok_or_else(|| vortex_err!(InvalidSerde: "Zstd buffer is missing its size"))?;
+            decompress_aligned(len as usize, |out| {
+                Ok(zstd::bulk::decompress_to_buffer(buffer.as_ref(), out)?)
+            })
+        }
+        fb::Compression::LZ4 => {
+            let lz4_err = |e| vortex_err!(InvalidSerde: "Failed to decompress LZ4 buffer: {}", e);
+            let (len, compressed) =
+                lz4_flex::block::uncompressed_size(buffer.as_ref()).map_err(lz4_err)?;
+            decompress_aligned(len, |out| {
+                lz4_flex::block::decompress_into(compressed, out).map_err(lz4_err)
+            })
+        }
+        _ => vortex_bail!(InvalidSerde: "Unknown buffer compression {:?}", compression),
+    }
+}
+
+/// Decompress `len` bytes into a new allocation aligned to [ALIGNMENT].
+fn decompress_aligned<F>(len: usize, decompress_into: F) -> VortexResult<Buffer>
+where
+    F: FnOnce(&mut [u8]) -> VortexResult<usize>,
+{
+    let mut bytes = Vec::<u8>::with_capacity(len + ALIGNMENT - 1);
+    let offset = bytes.as_ptr().align_offset(ALIGNMENT);
+    bytes.resize(offset + len, 0);
+
+    let written = decompress_into(&mut bytes[offset..])?;
+    if written != len {
+        vortex_bail!(InvalidSerde: "Expected {} decompressed bytes, got {}", len, written);
+    }
+    Ok(Buffer::from(Bytes::from(bytes).slice(offset..)))
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use vortex_buffer::Buffer;
+
+    use crate::compression::{decompress, BufferCompression};
+    use crate::flatbuffers::ipc as fb;
+    use crate::ALIGNMENT;
+
+    #[test]
+    fn test_round_trip() {
+        let buffer = Buffer::from(b"vortex".repeat(100));
+        for (compression, expected) in [
+            (BufferCompression::None, fb::Compression::None),
+            (BufferCompression::Zstd { level: 3 }, fb::Compression::Zstd),
+            (BufferCompression::LZ4, fb::Compression::LZ4),
+        ] {
+            let (compressed, applied) = compression.compress(buffer.clone()).unwrap();
+            assert_eq!(applied, expected);
+            assert_eq!(
+                decompress(compressed, applied).unwrap().as_ref(),
+                buffer.as_ref()
+            );
+        }
+    }
+
+    #[test]
+    fn test_aligned() {
+        for values in [
+            Buffer::from(
+                (0u64..1000)
+                    .flat_map(|v| (v % 7).to_le_bytes())
+                    .collect_vec(),
+            ),
+            Buffer::from(
+                (0..1000)
+                    .flat_map(|v| (v as f64 / 4.0).to_le_bytes())
+                    .collect_vec(),
+            ),
+        ] {
+            for compression in [BufferCompression::Zstd { level: 3 }, BufferCompression::LZ4] {
+                let (compressed, applied) = compression.compress(values.clone()).unwrap();
+                assert_ne!(applied, fb::Compression::None);
+                let decompressed = decompress(compressed, applied).unwrap();
+                assert_eq!(decompressed.as_ptr() as usize % ALIGNMENT, 0);
+                assert_eq!(decompressed.as_ref(), values.as_ref());
+            }
+        }
+    }
+
+    #[test]
+    fn test_incompressible() {
+        let buffer = Buffer::from(vec![1u8, 2, 3]);
+        let (written, applied) = BufferCompression::Zstd { level: 3 }
+            .compress(buffer.clone())
+            .unwrap();
+        assert_eq!(applied, fb::Compression::None);
+        assert_eq!(written.as_ref(), buffer.as_ref());
+    }
+}
@@ -0,0 +1,326 @@
+use std::ops::Range;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use arrow_buffer::Buffer as ArrowBuffer;
+use flatbuffers::root;
+use itertools::Itertools;
+use memmap2::Mmap;
+use vortex::array::chunked::ChunkedArray;
+use vortex::array::primitive::PrimitiveArray;
+use vortex::compute::cast::cast;
+use vortex::compute::take::take;
+use vortex::{Array, ArrayView, Context, IntoArray, ViewContext};
+use vortex_buffer::Buffer;
+use vortex_dtype::{DType, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::checksum::{verify_buffer, verify_message};
+use crate::compression::decompress;
+use crate::file::{trailer_ends, ArrayFooter, Footer, Trailer, TRAILER_SIZE};
+use crate::flatbuffers::ipc as fb;
+use crate::messages::SerdeFooterDeserializer;
+use crate::missing;
+
+/// Zero-copy reader for a memory-mapped file written by [crate::file::FileWriter].
+///
+/// The chunks returned by this reader are views whose buffers point directly into the mapping,
+/// so no bytes are copied out of the file unless a buffer was written with compression. Since
+/// every message and buffer in a file is padded to [crate::ALIGNMENT] and the mapping itself is
+/// page-aligned, the buffers keep the same alignment as those read into memory.
+pub struct MmapFileReader {
+    buffer: Buffer,
+    footer: Footer,
+}
+
+impl MmapFileReader {
+    /// Open a file that has been mapped into memory with [Mmap::map].
+    ///
+    /// The chunks returned by the reader keep the mapping alive, and it is unmapped once the reader
+    /// and all of its chunks have been dropped.
+    pub fn try_new(mmap: Mmap, ctx: &Context) -> VortexResult<Self> {
+        let len = mmap.len();
+        let ptr = NonNull::new(mmap.as_ptr() as *mut u8).unwrap_or(NonNull::dangling());
+        // SAFETY: the pointer and length describe the mapping, which is owned by the buffer.
+        let buffer = unsafe { ArrowBuffer::from_custom_allocation(ptr, len, Arc::new(mmap)) };
+        Self::try_from_buffer(Buffer::Arrow(buffer), ctx)
+    }
+
+    /// Read a file held entirely in memory, without copying any of its chunks.
+    ///
+    /// Like [crate::file::FileReader::try_new], a file left behind by an append that didn't
+    /// finish is read as it was before the append.
+    pub fn try_from_buffer(buffer: Buffer, ctx: &Context) -> VortexResult<Self> {
+        let size = buffer.len();
+        let footer = read_footer(&buffer, size, ctx).or_else(|err| {
+            trailer_ends(&buffer[..size.saturating_sub(1)])
+                .find_map(|end| read_footer(&buffer, end, ctx).ok())
+                .ok_or(err)
+        })?;
+
+        Ok(Self { buffer, footer })
+    }
+
+    pub fn footer(&self) -> &Footer {
+        &self.footer
+    }
+
+    pub fn view_context(&self) -> &Arc<ViewContext> {
+        &self.footer.view_context
+    }
+
+    pub fn narrays(&self) -> usize {
+        self.footer.arrays.len()
+    }
+
+    pub fn array(&self, array_idx: usize) -> VortexResult<&ArrayFooter> {
+        self.footer
+            .arrays
+            .get(array_idx)
+            .ok_or_else(|| vortex_err!(OutOfBounds: array_idx, 0, self.narrays()))
+    }
+
+    pub fn dtype(&self, array_idx: usize) -> VortexResult<&DType> {
+        self.array(array_idx).map(|a| &a.dtype)
+    }
+
+    /// The index of the array written under the given name.
+    pub fn array_idx(&self, name: &str) -> VortexResult<usize> {
+        self.footer
+            .array_idx(name)
+            .ok_or_else(|| vortex_err!("No array named {} in file", name))
+    }
+
+    /// Read a single chunk of the given array, as a view over the mapped file.
+    pub fn read_chunk(&self, array_idx: usize, chunk_idx: usize) -> VortexResult<Array> {
+        let array = self.array(array_idx)?;
+        if chunk_idx >= array.nchunks() {
+            vortex_bail!(OutOfBounds: chunk_idx, 0, array.nchunks());
+        }
+        let begin = array.chunks.byte_offsets[chunk_idx];
+
+        let flatbuffer = read_message(&self.buffer, begin)?;
+        let checksums = verify_message(&flatbuffer, begin)?;
+        let chunk = root::<fb::Message>(&flatbuffer)?
+            .header_as_chunk()
+            .ok_or_else(
+                || vortex_err!(InvalidSerde: "Expected chunk message at offset {}", begin),
+            )?;
+
+        // Buffer offsets are relative to the end of the message.
+        let buffers_begin = begin + 4 + flatbuffer.len() as u64;
+        let buffers = chunk
+            .buffers()
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .map(|(idx, b)| {
+                let start = buffers_begin + b.offset();
+                let bytes = slice(&self.buffer, start..start + b.length())?;
+                if checksums {
+                    verify_buffer(&bytes, b.checksum(), begin, idx)?;
+                }
+                decompress(bytes, b.compression())
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        ArrayView::try_new(
+            self.view_context().clone(),
+            array.dtype.clone(),
+            flatbuffer,
+            |flatbuffer| {
+                root::<fb::Message>(flatbuffer)
+                    .map_err(VortexError::from)
+                    .map(|msg| msg.header_as_chunk().unwrap())
+                    .and_then(|chunk| chunk.array().ok_or_else(missing("chunk.array")))
+            },
+            buffers,
+        )
+        .map(IntoArray::into_array)
+    }
+
+    /// Take the given rows of an array, in any order and with repeats.
+    ///
+    /// Only the chunks containing the requested rows are touched.
+    pub fn take_rows(&self, array_idx: usize, indices: &Array) -> VortexResult<Array> {
+        let array = self.array(array_idx)?;
+        let row_offsets = &array.chunks.row_offsets;
+        let row_count = array.row_count();
+
+        let indices = cast(indices, PType::U64.into())?.flatten_primitive()?;
+        let indices = indices.typed_data::<u64>();
+        if let Some(&idx) = indices.iter().find(|&&idx| idx >= row_count) {
+            vortex_bail!(OutOfBounds: idx as usize, 0, row_count as usize);
+        }
+
+        // Take each run of consecutive indices that fall into the same chunk.
+        let mut chunks = Vec::new();
+        for (chunk_idx, run) in &indices
+            .iter()
+            .group_by(|&&idx| row_offsets.partition_point(|&offset| offset <= idx) - 1)
+        {
+            let chunk_begin = row_offsets[chunk_idx];
+            let run = run.map(|&idx| idx - chunk_begin).collect_vec();
+            chunks.push(take(
+                &self.read_chunk(array_idx, chunk_idx)?,
+                &PrimitiveArray::from(run).into_array(),
+            )?);
+        }
+        ChunkedArray::try_new(chunks, array.dtype.clone()).map(IntoArray::into_array)
+    }
+}
+
+/// Read the footer of a file whose trailer ends at `end`.
+fn read_footer(buffer: &Buffer, end: usize, ctx: &Context) -> VortexResult<Footer> {
+    if end < TRAILER_SIZE {
+        vortex_bail!(InvalidSerde: "File of {} bytes is too small to contain a trailer", end);
+    }
+
+    let trailer_offset = (end - TRAILER_SIZE) as u64;
+    let trailer = Trailer::try_from_bytes(&buffer[trailer_offset as usize..end])?;
+    if trailer.footer_offset > trailer_offset {
+        vortex_bail!(InvalidSerde: "Footer offset {} is past the end of the file", trailer.footer_offset);
+    }
+
+    let footer_message = read_message(buffer, trailer.footer_offset)?;
+    verify_message(&footer_message, trailer.footer_offset)?;
+    SerdeFooterDeserializer {
+        fb: root::<fb::Message>(&footer_message)?
+            .header_as_footer()
+            .ok_or_else(|| vortex_err!(InvalidSerde: "Expected footer message"))?,
+        ctx,
+    }
+    .try_into()
+}
+
+/// Slice a length-prefixed message out of the file, without copying.
+fn read_message(buffer: &Buffer, offset: u64) -> VortexResult<Buffer> {
+    let len_bytes = slice(buffer, offset..offset + 4)?;
+    let msg_len = u32::from_le_bytes(len_bytes.as_ref().try_into()?) as u64;
+    slice(buffer, offset + 4..offset + 4 + msg_len)
+}
+
+fn slice(buffer: &Buffer, range: Range<u64>) -> VortexResult<Buffer> {
+    if range.end > buffer.len() as u64 {
+        vortex_bail!(InvalidSerde:
+            "Byte range {:?} is past the end of the file of {} bytes",
+            range,
+            buffer.len()
+        );
+    }
+    Ok(buffer.slice(range.start as usize..range.end as usize))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+
+    use itertools::Itertools;
+    use memmap2::Mmap;
+    use vortex::array::chunked::ChunkedArray;
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::{Array, ArrayDType, Context, IntoArray, ViewContext};
+    use vortex_dtype::PType;
+    use vortex_error::{VortexError, VortexResult};
+
+    use crate::file::{FileWriter, MmapFileReader};
+    use crate::{BufferCompression, ALIGNMENT};
+
+    async fn write_file(compression: BufferCompression) -> VortexResult<Vec<u8>> {
+        let chunks = (0..4)
+            .map(|i| PrimitiveArray::from((i * 100..(i + 1) * 100).collect_vec()).into_array())
+            .collect_vec();
+        let chunked = ChunkedArray::try_new(chunks, PType::I32.into())?.into_array();
+
+        FileWriter::try_new(vec![], ViewContext::default())
+            .await?
+            .with_compression(compression)
+            .write_array(chunked)
+            .await?
+            .finish()
+            .await
+    }
+
+    fn buffer_ptr(array: &Array) -> usize {
+        match array {
+            Array::View(view) => view.buffer().unwrap().as_ptr() as usize,
+            Array::Data(_) => panic!("Expected a view over the file"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_mmap() -> VortexResult<()> {
+        let path = std::env::temp_dir().join(format!("vortex-mmap-{}.vtx", std::process::id()));
+        std::fs::write(&path, write_file(BufferCompression::None).await?)?;
+        let mmap = unsafe { Mmap::map(&File::open(&path)?)? };
+        std::fs::remove_file(&path)?;
+        let range = mmap.as_ptr() as usize..mmap.as_ptr() as usize + mmap.len();
+
+        let reader = MmapFileReader::try_new(mmap, &Context::default())?;
+        assert_eq!(reader.narrays(), 1);
+
+        let chunk = reader.read_chunk(0, 2)?;
+        assert_eq!(chunk.dtype(), reader.dtype(0)?);
+        // The chunk's buffer points into the mapping, and is aligned as if read into memory.
+        let ptr = buffer_ptr(&chunk);
+        assert!(range.contains(&ptr));
+        assert_eq!(ptr % ALIGNMENT, 0);
+        assert_eq!(
+            chunk.flatten_primitive()?.typed_data::<i32>(),
+            (200..300).collect_vec()
+        );
+
+        let taken = reader.take_rows(
+            0,
+            &PrimitiveArray::from(vec![399u32, 5, 6, 150, 5]).into_array(),
+        )?;
+        assert_eq!(
+            taken.flatten_primitive()?.typed_data::<i32>(),
+            &[399, 5, 6, 150, 5]
+        );
+        assert!(reader
+            .take_rows(0, &PrimitiveArray::from(vec![400u32]).into_array())
+            .is_err());
+        assert!(reader.read_chunk(0, 4).is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_compressed() -> VortexResult<()> {
+        let reader = MmapFileReader::try_from_buffer(
+            write_file(BufferCompression::LZ4).await?.into(),
+            &Context::default(),
+        )?;
+        let chunk = reader.read_chunk(0, 1)?;
+        assert_eq!(
+            chunk.flatten_primitive()?.typed_data::<i32>(),
+            (100..200).collect_vec()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_checksums() -> VortexResult<()> {
+        let mut buffer = FileWriter::try_new(vec![], ViewContext::default())
+            .await?
+            .with_checksums(true)
+            .write_array(PrimitiveArray::from((0..1000).collect_vec()).into_array())
+            .await?
+            .finish()
+            .await?;
+        let reader = MmapFileReader::try_from_buffer(buffer.clone().into(), &Context::default())?;
+        assert_eq!(reader.read_chunk(0, 0)?.len(), 1000);
+
+        let pos = buffer
+            .windows(4)
+            .position(|w| w == 123i32.to_le_bytes())
+            .unwrap();
+        buffer[pos] ^= 1;
+        let reader = MmapFileReader::try_from_buffer(buffer.into(), &Context::default())?;
+        assert!(matches!(
+            reader.read_chunk(0, 0),
+            Err(VortexError::ChecksumMismatch(..))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+//! A self-describing file format built on top of the IPC message stream.
+//!
+//! A file is a regular message stream (context, then a schema followed by chunks for each array),
+//! followed by a [Footer] message and a fixed-size [Trailer]. The footer is the table of contents
+//! of the file: it records the view context, and the name, dtype and the byte and row offsets of
+//! the chunks of every array, so a reader only needs to fetch the trailer and the footer before it
+//! can read any chunk of any array directly.
+use std::sync::Arc;
+
+pub use mmap::*;
+pub use reader::*;
+use vortex::ViewContext;
+use vortex_dtype::{DType, FieldName};
+use vortex_error::{vortex_bail, VortexResult};
+pub use writer::*;
+
+use crate::writer::ChunkLayout;
+
+mod mmap;
+mod reader;
+mod writer;
+
+pub const MAGIC_BYTES: [u8; 4] = *b"VRTX";
+pub const FILE_VERSION: u32 = 1;
+pub const TRAILER_SIZE: usize = 16;
+
+/// The end offsets of the trailers that may be contained in `bytes`, from the last to the first.
+///
+/// Appending never modifies the existing bytes of a file, so an append that didn't finish leaves
+/// the previous trailer intact, followed by a partial write. Readers search for that trailer when
+/// the file doesn't end with a valid one.
+pub(crate) fn trailer_ends(bytes: &[u8]) -> impl Iterator<Item = usize> + '_ {
+    (TRAILER_SIZE..=bytes.len())
+        .rev()
+        .filter(move |&end| bytes[end - MAGIC_BYTES.len()..end] == MAGIC_BYTES)
+}
+
+/// The index of a file, read from the footer message.
+#[derive(Clone, Debug)]
+pub struct Footer {
+    pub view_context: Arc<ViewContext>,
+    pub arrays: Vec<ArrayFooter>,
+}
+
+impl Footer {
+    /// The index of the array with the given name.
+    pub fn array_idx(&self, name: &str) -> Option<usize> {
+        self.arrays
+            .iter()
+            .position(|array| array.name.as_deref() == Some(name))
+    }
+}
+
+/// The name, dtype and chunk offsets of a single array within a file.
+#[derive(Clone, Debug)]
+pub struct ArrayFooter {
+    /// The name the array was written under, if any.
+    pub name: Option<FieldName>,
+    pub dtype: DType,
+    pub chunks: ChunkLayout,
+}
+
+impl ArrayFooter {
+    pub fn nchunks(&self) -> usize {
+        self.chunks.byte_offsets.len().saturating_sub(1)
+    }
+
+    pub fn row_count(&self) -> u64 {
+        self.chunks.row_offsets.last().copied().unwrap_or(0)
+    }
+}
+
+/// The last [TRAILER_SIZE] bytes of a file.
+///
+/// Layout: `footer_offset: u64 | version: u32 | magic: [u8; 4]`, all little-endian.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Trailer {
+    pub footer_offset: u64,
+    pub version: u32,
+}
+
+impl Trailer {
+    pub fn to_bytes(&self) -> [u8; TRAILER_SIZE] {
+        let mut bytes = [0u8; TRAILER_SIZE];
+        bytes[0..8].copy_from_slice(&self.footer_offset.to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.version.to_le_bytes());
+        bytes[12..16].copy_from_slice(&MAGIC_BYTES);
+        bytes
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> VortexResult<Self> {
+        if bytes.len() != TRAILER_SIZE {
+            vortex_bail!(InvalidSerde: "Expected {} trailer bytes, got {}", TRAILER_SIZE, bytes.len());
+        }
+        if bytes[12..16] != MAGIC_BYTES {
+            vortex_bail!(InvalidSerde: "Invalid magic bytes, not a Vortex file");
+        }
+
+        let version = u32::from_le_bytes(bytes[8..12].try_into()?);
+        if version != FILE_VERSION {
+            vortex_bail!(InvalidSerde: "Unsupported file version {}", version);
+        }
+
+        Ok(Self {
+            footer_offset: u64::from_le_bytes(bytes[0..8].try_into()?),
+            version,
+        })
+    }
+}
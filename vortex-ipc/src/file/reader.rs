@@ -0,0 +1,1087 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use bytes::{Bytes, BytesMut};
+use flatbuffers::root;
+use itertools::Itertools;
+use vortex::array::chunked::ChunkedArray;
+use vortex::array::primitive::PrimitiveArray;
+use vortex::compute::filter_indices::filter_indices;
+use vortex::compute::take::take;
+use vortex::flatbuffers as fba;
+use vortex::{Array, ArrayView, Context, IntoArray, ViewContext};
+use vortex_buffer::Buffer;
+use vortex_dtype::field_paths::FieldPath;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_expr::expressions::Disjunction;
+
+use crate::checksum::{verify_buffer, verify_message};
+use crate::chunked_reader::{ChunkedArrayReader, ChunkedArrayReaderBuilder};
+use crate::compression::decompress;
+use crate::file::{trailer_ends, ArrayFooter, Footer, Trailer, TRAILER_SIZE};
+use crate::flatbuffers::ipc as fb;
+use crate::io::{ReadPlanner, VortexReadAt};
+use crate::projection::Selection;
+use crate::pruning::may_match;
+use crate::schema::SchemaResolution;
+use crate::{missing, MessageReader};
+
+/// Random-access reader for a file written by [crate::file::FileWriter].
+///
+/// Opening the file only reads the trailer and the footer, every chunk is then fetched on demand.
+pub struct FileReader<R: VortexReadAt> {
+    read: R,
+    footer: Footer,
+    planner: ReadPlanner,
+}
+
+impl<R: VortexReadAt> FileReader<R> {
+    /// Open a file, reading its trailer and footer.
+    ///
+    /// If the file doesn't end with a valid trailer, because an append to it didn't finish, the
+    /// file is read as it was before the append.
+    pub async fn try_new(mut read: R, ctx: &Context) -> VortexResult<Self> {
+        let size = read.size().await?;
+        let footer = match read_footer(&mut read, size, ctx).await {
+            Ok(footer) => footer,
+            Err(err) => recover_footer(&mut read, size, ctx).await?.ok_or(err)?,
+        };
+
+        let planner = ReadPlanner::for_read(&read);
+        Ok(Self {
+            read,
+            footer,
+            planner,
+        })
+    }
+
+    /// Override how reads of chunk buffers are coalesced, which defaults to the performance hint
+    /// of the reader.
+    pub fn with_read_planner(mut self, planner: ReadPlanner) -> Self {
+        self.planner = planner;
+        self
+    }
+
+    pub fn footer(&self) -> &Footer {
+        &self.footer
+    }
+
+    pub fn view_context(&self) -> &Arc<ViewContext> {
+        &self.footer.view_context
+    }
+
+    pub fn narrays(&self) -> usize {
+        self.footer.arrays.len()
+    }
+
+    pub fn array(&self, array_idx: usize) -> VortexResult<&ArrayFooter> {
+        self.footer
+            .arrays
+            .get(array_idx)
+            .ok_or_else(|| vortex_err!(OutOfBounds: array_idx, 0, self.narrays()))
+    }
+
+    pub fn dtype(&self, array_idx: usize) -> VortexResult<&DType> {
+        self.array(array_idx).map(|a| &a.dtype)
+    }
+
+    /// The index of the array written under the given name.
+    pub fn array_idx(&self, name: &str) -> VortexResult<usize> {
+        self.footer
+            .array_idx(name)
+            .ok_or_else(|| vortex_err!("No array named {} in file", name))
+    }
+
+    /// Read a single chunk of the given array.
+    pub async fn read_chunk(&mut self, array_idx: usize, chunk_idx: usize) -> VortexResult<Array> {
+        let array = self.array(array_idx)?;
+        let dtype = array.dtype.clone();
+        let (begin, end) = self.chunk_range(array_idx, chunk_idx)?;
+
+        let buffer = self
+            .read
+            .read_at_into(begin, BytesMut::zeroed((end - begin) as usize))
+            .await?;
+        MessageReader::try_new_at(Cursor::new(Buffer::from(buffer.freeze())), begin)
+            .await?
+            .maybe_read_chunk(self.view_context().clone(), dtype)
+            .await?
+            .ok_or_else(|| vortex_err!(InvalidSerde: "Expected chunk message at offset {}", begin))
+    }
+
+    /// Read a single chunk of the given array as the requested dtype, which may have evolved
+    /// from the dtype the array was written with, see [SchemaResolution].
+    pub async fn read_chunk_as(
+        &mut self,
+        array_idx: usize,
+        chunk_idx: usize,
+        dtype: &DType,
+    ) -> VortexResult<Array> {
+        let resolution = SchemaResolution::try_new(self.dtype(array_idx)?, dtype)?;
+        resolution.resolve(self.read_chunk(array_idx, chunk_idx).await?)
+    }
+
+    /// The dtype of the chunks returned by [FileReader::read_chunk_projected].
+    pub fn projected_dtype(
+        &self,
+        array_idx: usize,
+        projection: &[FieldPath],
+    ) -> VortexResult<DType> {
+        let dtype = self.dtype(array_idx)?;
+        Ok(Selection::try_new(dtype, projection)?.project_dtype(dtype))
+    }
+
+    /// Read a single chunk of a struct array, only fetching the buffers of the projected fields.
+    ///
+    /// The fields of the resulting struct are ordered as they first appear in the projection.
+    pub async fn read_chunk_projected(
+        &mut self,
+        array_idx: usize,
+        chunk_idx: usize,
+        projection: &[FieldPath],
+    ) -> VortexResult<Array> {
+        let dtype = self.array(array_idx)?.dtype.clone();
+        let selection = Selection::try_new(&dtype, projection)?;
+        if selection == Selection::All {
+            return self.read_chunk(array_idx, chunk_idx).await;
+        }
+
+        let view_ctx = self.view_context().clone();
+        let view = self
+            .read_chunk_view(array_idx, chunk_idx, |fb_array| {
+                selection.required_buffers(fb_array, &dtype, &view_ctx)
+            })
+            .await?;
+        selection.project_array(view.into_array())
+    }
+
+    /// Whether any row of the chunk may match the predicate.
+    ///
+    /// This is decided from the statistics stored in the chunk message, without reading any of
+    /// the chunk's buffers. A false result proves that no row matches.
+    pub async fn chunk_may_match(
+        &mut self,
+        array_idx: usize,
+        chunk_idx: usize,
+        predicate: &Disjunction,
+    ) -> VortexResult<bool> {
+        let message = self.read_chunk_message(array_idx, chunk_idx).await?;
+        self.message_may_match(array_idx, chunk_idx, &message, predicate)
+            .await
+    }
+
+    async fn message_may_match(
+        &mut self,
+        array_idx: usize,
+        chunk_idx: usize,
+        message: &ChunkMessage,
+        predicate: &Disjunction,
+    ) -> VortexResult<bool> {
+        // The length of a view without buffers can't be trusted, so we take it from the footer.
+        let row_offsets = &self.array(array_idx)?.chunks.row_offsets;
+        let len = (row_offsets[chunk_idx + 1] - row_offsets[chunk_idx]) as usize;
+
+        let view = self
+            .read_message_view(array_idx, message, |fb_array| {
+                vec![false; ArrayView::cumulative_nbuffers(fb_array)]
+            })
+            .await?;
+        Ok(may_match(&view.into_array(), len, predicate))
+    }
+
+    /// Read the rows of an array that match the predicate.
+    ///
+    /// Chunks are first pruned using [FileReader::chunk_may_match], and only the buffers of the
+    /// surviving chunks are then read and evaluated with [filter_indices], reusing the chunk
+    /// message that was read for pruning. Returns a chunked array of the matching rows.
+    pub async fn scan(&mut self, array_idx: usize, predicate: &Disjunction) -> VortexResult<Array> {
+        let array = self.array(array_idx)?;
+        let dtype = array.dtype.clone();
+        let nchunks = array.nchunks();
+
+        let mut chunks = Vec::new();
+        for chunk_idx in 0..nchunks {
+            let message = self.read_chunk_message(array_idx, chunk_idx).await?;
+            if !self
+                .message_may_match(array_idx, chunk_idx, &message, predicate)
+                .await?
+            {
+                continue;
+            }
+
+            let chunk = self
+                .read_message_view(array_idx, &message, |fb_array| {
+                    vec![true; ArrayView::cumulative_nbuffers(fb_array)]
+                })
+                .await?
+                .into_array();
+            let indices = filter_indices(&chunk, predicate)?
+                .flatten_bool()?
+                .boolean_buffer()
+                .set_indices()
+                .map(|idx| idx as u64)
+                .collect_vec();
+            if !indices.is_empty() {
+                chunks.push(take(&chunk, &PrimitiveArray::from(indices).into_array())?);
+            }
+        }
+        ChunkedArray::try_new(chunks, dtype).map(IntoArray::into_array)
+    }
+
+    /// Read the chunk message, then only the buffers selected by `required`.
+    ///
+    /// Buffers that are not required are left empty, so must never be accessed.
+    async fn read_chunk_view<F>(
+        &mut self,
+        array_idx: usize,
+        chunk_idx: usize,
+        required: F,
+    ) -> VortexResult<ArrayView>
+    where
+        F: FnOnce(fba::Array<'_>) -> Vec<bool>,
+    {
+        let message = self.read_chunk_message(array_idx, chunk_idx).await?;
+        self.read_message_view(array_idx, &message, required).await
+    }
+
+    /// Read the length-prefixed chunk message on its own, without any of its buffers.
+    async fn read_chunk_message(
+        &mut self,
+        array_idx: usize,
+        chunk_idx: usize,
+    ) -> VortexResult<ChunkMessage> {
+        let (begin, _) = self.chunk_range(array_idx, chunk_idx)?;
+
+        let len_bytes = self.read.read_at_into(begin, BytesMut::zeroed(4)).await?;
+        let msg_len = u32::from_le_bytes(len_bytes.as_ref().try_into()?) as u64;
+        let flatbuffer = Buffer::from(
+            self.read
+                .read_at_into(begin + 4, BytesMut::zeroed(msg_len as usize))
+                .await?
+                .freeze(),
+        );
+        let checksums = verify_message(&flatbuffer, begin)?;
+        Ok(ChunkMessage {
+            begin,
+            flatbuffer,
+            checksums,
+        })
+    }
+
+    /// Read the buffers selected by `required` of a chunk message that was already read.
+    async fn read_message_view<F>(
+        &mut self,
+        array_idx: usize,
+        message: &ChunkMessage,
+        required: F,
+    ) -> VortexResult<ArrayView>
+    where
+        F: FnOnce(fba::Array<'_>) -> Vec<bool>,
+    {
+        let dtype = self.array(array_idx)?.dtype.clone();
+        let begin = message.begin;
+        let flatbuffer = &message.flatbuffer;
+        let msg_len = flatbuffer.len() as u64;
+
+        let buffer_ranges = {
+            let chunk = root::<fb::Message>(flatbuffer.as_ref())?
+                .header_as_chunk()
+                .ok_or_else(
+                    || vortex_err!(InvalidSerde: "Expected chunk message at offset {}", begin),
+                )?;
+            let fb_array = chunk.array().ok_or_else(missing("chunk.array"))?;
+            let fb_buffers = chunk.buffers().unwrap_or_default();
+            let required = required(fb_array);
+            if required.len() != fb_buffers.len() {
+                vortex_bail!(InvalidSerde:
+                    "Chunk has {} buffers, expected {}",
+                    fb_buffers.len(),
+                    required.len()
+                );
+            }
+            fb_buffers
+                .iter()
+                .zip(required)
+                .map(|(buffer, required)| required.then_some(*buffer))
+                .collect_vec()
+        };
+
+        // Buffer offsets are relative to the end of the message.
+        let buffers_begin = begin + 4 + msg_len;
+        let ranges = buffer_ranges
+            .iter()
+            .flatten()
+            .map(|buffer| {
+                buffers_begin + buffer.offset()..buffers_begin + buffer.offset() + buffer.length()
+            })
+            .collect_vec();
+        let mut fetched = self
+            .planner
+            .read_ranges(&mut self.read, &ranges)
+            .await?
+            .into_iter();
+
+        let buffers = buffer_ranges
+            .into_iter()
+            .enumerate()
+            .map(|(idx, buffer)| match buffer {
+                Some(buffer) => {
+                    let bytes = fetched.next().expect("a buffer was read for each range");
+                    if message.checksums {
+                        verify_buffer(&bytes, buffer.checksum(), begin, idx)?;
+                    }
+                    decompress(bytes, buffer.compression())
+                }
+                None => Ok(Buffer::from(Bytes::new())),
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        ArrayView::try_new(
+            self.view_context().clone(),
+            dtype,
+            flatbuffer.clone(),
+            |flatbuffer| {
+                root::<fb::Message>(flatbuffer)
+                    .map_err(VortexError::from)
+                    .map(|msg| msg.header_as_chunk().unwrap())
+                    .and_then(|chunk| chunk.array().ok_or_else(missing("chunk.array")))
+            },
+            buffers,
+        )
+    }
+
+    fn chunk_range(&self, array_idx: usize, chunk_idx: usize) -> VortexResult<(u64, u64)> {
+        let array = self.array(array_idx)?;
+        if chunk_idx >= array.nchunks() {
+            vortex_bail!(OutOfBounds: chunk_idx, 0, array.nchunks());
+        }
+        Ok(array.chunks.byte_range(chunk_idx))
+    }
+}
+
+/// Read the footer of a file whose trailer ends at `end`.
+async fn read_footer<R: VortexReadAt>(
+    read: &mut R,
+    end: u64,
+    ctx: &Context,
+) -> VortexResult<Footer> {
+    if end < TRAILER_SIZE as u64 {
+        vortex_bail!(InvalidSerde: "File of {} bytes is too small to contain a trailer", end);
+    }
+
+    let trailer_offset = end - TRAILER_SIZE as u64;
+    let trailer = read
+        .read_at_into(trailer_offset, BytesMut::zeroed(TRAILER_SIZE))
+        .await?;
+    let trailer = Trailer::try_from_bytes(trailer.as_ref())?;
+    if trailer.footer_offset > trailer_offset {
+        vortex_bail!(InvalidSerde: "Footer offset {} is past the end of the file", trailer.footer_offset);
+    }
+
+    let footer_len = (trailer_offset - trailer.footer_offset) as usize;
+    let footer_bytes = read
+        .read_at_into(trailer.footer_offset, BytesMut::zeroed(footer_len))
+        .await?;
+    MessageReader::try_new_at(
+        Cursor::new(Buffer::from(footer_bytes.freeze())),
+        trailer.footer_offset,
+    )
+    .await?
+    .read_footer(ctx)
+    .await
+}
+
+/// The number of bytes searched at a time for an earlier trailer, see [trailer_ends].
+const RECOVERY_BLOCK_SIZE: u64 = 64 * 1024;
+
+/// Find the footer of the last complete trailer before the end of the file.
+async fn recover_footer<R: VortexReadAt>(
+    read: &mut R,
+    size: u64,
+    ctx: &Context,
+) -> VortexResult<Option<Footer>> {
+    let mut block_end = size;
+    loop {
+        let block_start = block_end.saturating_sub(RECOVERY_BLOCK_SIZE);
+        let block = read
+            .read_at_into(
+                block_start,
+                BytesMut::zeroed((block_end - block_start) as usize),
+            )
+            .await?;
+        for end in trailer_ends(&block) {
+            let end = block_start + end as u64;
+            if end == size {
+                continue;
+            }
+            if let Ok(footer) = read_footer(read, end, ctx).await {
+                return Ok(Some(footer));
+            }
+        }
+        if block_start == 0 {
+            return Ok(None);
+        }
+        // Overlap the blocks so that trailers crossing the boundary are found.
+        block_end = block_start + TRAILER_SIZE as u64 - 1;
+    }
+}
+
+/// A chunk message read on its own, so that its buffers can be read later.
+struct ChunkMessage {
+    /// The offset of the length prefix of the message in the file.
+    begin: u64,
+    flatbuffer: Buffer,
+    checksums: bool,
+}
+
+impl<R: VortexReadAt + Clone> FileReader<R> {
+    /// Convert into a [ChunkedArrayReader] over one of the arrays in the file.
+    pub fn into_chunked_reader(self, array_idx: usize) -> VortexResult<ChunkedArrayReader<R>> {
+        let array = self.array(array_idx)?.clone();
+        ChunkedArrayReaderBuilder::default()
+            .read(self.read)
+            .view_context(self.footer.view_context)
+            .dtype(array.dtype)
+            .byte_offsets(PrimitiveArray::from(array.chunks.byte_offsets).into_array())
+            .row_offsets(PrimitiveArray::from(array.chunks.row_offsets).into_array())
+            .byte_ends(PrimitiveArray::from(array.chunks.byte_ends).into_array())
+            .read_planner(self.planner)
+            .build()
+            .map_err(|e| vortex_err!("Failed to build chunked reader: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::Range;
+
+    use bytes::BytesMut;
+    use itertools::Itertools;
+    use vortex::array::chunked::ChunkedArray;
+    use vortex::array::constant::ConstantArray;
+    use vortex::array::primitive::{PrimitiveArray, PrimitiveEncoding};
+    use vortex::array::r#struct::StructArray;
+    use vortex::array::varbin::VarBinArray;
+    use vortex::compute::scalar_at::scalar_at;
+    use vortex::validity::Validity;
+    use vortex::{Array, ArrayDType, Context, IntoArray, ViewContext};
+    use vortex_buffer::Buffer;
+    use vortex_dtype::field_paths::{field, FieldPath};
+    use vortex_dtype::{DType, FieldName, Nullability, PType, StructDType};
+    use vortex_error::{VortexError, VortexResult};
+    use vortex_expr::expressions::{lit, Conjunction, Disjunction};
+    use vortex_expr::field_paths::FieldPathOperations;
+    use vortex_scalar::Scalar;
+
+    use crate::file::{FileReader, FileWriter, MmapFileReader, TRAILER_SIZE};
+    use crate::io::{VortexReadAt, VortexWrite};
+    use crate::BufferCompression;
+
+    /// Counts the number of bytes read through the wrapped reader.
+    struct CountingReadAt<R> {
+        read: R,
+        bytes_read: u64,
+    }
+
+    impl<R: VortexReadAt> VortexReadAt for CountingReadAt<R> {
+        async fn read_at_into(&mut self, pos: u64, buffer: BytesMut) -> std::io::Result<BytesMut> {
+            self.bytes_read += buffer.len() as u64;
+            self.read.read_at_into(pos, buffer).await
+        }
+
+        async fn size(&self) -> std::io::Result<u64> {
+            self.read.size().await
+        }
+    }
+
+    async fn write_file() -> VortexResult<Vec<u8>> {
+        let chunks = (0..4)
+            .map(|i| PrimitiveArray::from((i * 100..(i + 1) * 100).collect_vec()).into_array())
+            .collect_vec();
+        let chunked = ChunkedArray::try_new(chunks, PType::I32.into())?.into_array();
+        let single = PrimitiveArray::from(vec![1u64, 2, 3]).into_array();
+
+        FileWriter::try_new(vec![], ViewContext::default())
+            .await?
+            .write_array(chunked)
+            .await?
+            .write_array(single)
+            .await?
+            .finish()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_read_footer() -> VortexResult<()> {
+        let buffer = write_file().await?;
+        let reader = FileReader::try_new(buffer, &Context::default()).await?;
+
+        assert_eq!(reader.narrays(), 2);
+        assert_eq!(
+            reader.dtype(0)?,
+            &DType::Primitive(PType::I32, Nullability::NonNullable)
+        );
+        assert_eq!(reader.array(0)?.nchunks(), 4);
+        assert_eq!(
+            reader.array(0)?.chunks.row_offsets,
+            vec![0, 100, 200, 300, 400]
+        );
+        assert_eq!(
+            reader.dtype(1)?,
+            &DType::Primitive(PType::U64, Nullability::NonNullable)
+        );
+        assert_eq!(reader.array(1)?.row_count(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_file() -> VortexResult<()> {
+        let path = std::env::temp_dir().join(format!("vortex-read-{}.vortex", std::process::id()));
+        let file = tokio::fs::File::create(&path).await?;
+        let chunked = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from(vec![1i32, 2]).into_array(),
+                PrimitiveArray::from(vec![3i32]).into_array(),
+            ],
+            PType::I32.into(),
+        )?;
+        let mut file = FileWriter::try_new(file, ViewContext::default())
+            .await?
+            .write_array(chunked.into_array())
+            .await?
+            .finish()
+            .await?;
+        VortexWrite::flush(&mut file).await?;
+
+        let file = tokio::fs::File::open(&path).await?;
+        let mut reader = FileReader::try_new(file, &Context::default()).await?;
+        assert_eq!(reader.array(0)?.nchunks(), 2);
+        let chunk = reader.read_chunk(0, 1).await?;
+        assert_eq!(chunk.flatten_primitive()?.typed_data::<i32>(), &[3]);
+
+        let file = std::fs::File::open(&path)?;
+        let mut reader = FileReader::try_new(file, &Context::default()).await?;
+        let chunk = reader.read_chunk(0, 0).await?;
+        assert_eq!(chunk.flatten_primitive()?.typed_data::<i32>(), &[1, 2]);
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_chunk() -> VortexResult<()> {
+        let buffer = Buffer::from(write_file().await?);
+        let mut reader = FileReader::try_new(buffer, &Context::default()).await?;
+
+        let chunk = reader.read_chunk(0, 2).await?;
+        assert_eq!(chunk.dtype(), reader.dtype(0)?);
+        assert_eq!(
+            chunk.flatten_primitive()?.typed_data::<i32>(),
+            (200..300).collect_vec()
+        );
+
+        let chunk = reader.read_chunk(1, 0).await?;
+        assert_eq!(chunk.flatten_primitive()?.typed_data::<u64>(), &[1, 2, 3]);
+
+        assert!(reader.read_chunk(0, 4).await.is_err());
+        assert!(reader.read_chunk(2, 0).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_chunked_reader() -> VortexResult<()> {
+        let buffer = write_file().await?;
+        let mut reader = FileReader::try_new(buffer, &Context::default())
+            .await?
+            .into_chunked_reader(0)?;
+
+        let result = reader
+            .take_rows(&PrimitiveArray::from(vec![5u64, 150, 399]).into_array())
+            .await?
+            .flatten_primitive()?;
+        assert_eq!(result.typed_data::<i32>(), &[5, 150, 399]);
+        Ok(())
+    }
+
+    async fn write_struct_file() -> VortexResult<Vec<u8>> {
+        let nested = StructArray::try_new(
+            vec![FieldName::from("x"), FieldName::from("y")].into(),
+            vec![
+                PrimitiveArray::from(vec![1u8; 1000]).into_array(),
+                PrimitiveArray::from((0u16..1000).collect_vec()).into_array(),
+            ],
+            1000,
+            Validity::NonNullable,
+        )?;
+        let array = StructArray::try_new(
+            vec![
+                FieldName::from("a"),
+                FieldName::from("b"),
+                FieldName::from("c"),
+            ]
+            .into(),
+            vec![
+                PrimitiveArray::from((0i32..1000).collect_vec()).into_array(),
+                VarBinArray::from((0..1000).map(|i| format!("value-{}", i)).collect_vec())
+                    .into_array(),
+                nested.into_array(),
+            ],
+            1000,
+            Validity::NonNullable,
+        )?;
+
+        FileWriter::try_new(vec![], ViewContext::default())
+            .await?
+            .write_array(array.into_array())
+            .await?
+            .finish()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_read_chunk_projected() -> VortexResult<()> {
+        let buffer = write_struct_file().await?;
+        let mut reader = FileReader::try_new(buffer, &Context::default()).await?;
+
+        let projected = StructArray::try_from(
+            reader
+                .read_chunk_projected(
+                    0,
+                    0,
+                    &[FieldPath::builder().join("c").join("y").build(), field("a")],
+                )
+                .await?,
+        )?;
+        assert_eq!(
+            projected.names().as_ref(),
+            &[FieldName::from("c"), FieldName::from("a")]
+        );
+        assert_eq!(projected.array().len(), 1000);
+        assert_eq!(
+            projected.dtype(),
+            &reader.projected_dtype(
+                0,
+                &[FieldPath::builder().join("c").join("y").build(), field("a")]
+            )?
+        );
+
+        let a = projected.field(1).unwrap().flatten_primitive()?;
+        assert_eq!(a.typed_data::<i32>(), (0i32..1000).collect_vec());
+
+        let c = StructArray::try_from(projected.field(0).unwrap())?;
+        assert_eq!(c.names().as_ref(), &[FieldName::from("y")]);
+        assert_eq!(
+            c.field(0).unwrap().flatten_primitive()?.typed_data::<u16>(),
+            (0u16..1000).collect_vec()
+        );
+
+        assert!(reader
+            .read_chunk_projected(0, 0, &[field("missing")])
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_chunk_as() -> VortexResult<()> {
+        let buffer = write_struct_file().await?;
+        let mut reader = FileReader::try_new(buffer, &Context::default()).await?;
+
+        let requested = DType::Struct(
+            StructDType::new(
+                vec![FieldName::from("added"), FieldName::from("a")].into(),
+                vec![
+                    DType::Utf8(Nullability::Nullable),
+                    DType::Primitive(PType::I64, Nullability::NonNullable),
+                ],
+            ),
+            Nullability::Nullable,
+        );
+        let evolved = StructArray::try_from(reader.read_chunk_as(0, 0, &requested).await?)?;
+        assert_eq!(evolved.dtype(), &requested);
+        assert!(scalar_at(&evolved.field(0).unwrap(), 0)?.is_null());
+        assert_eq!(
+            evolved
+                .field(1)
+                .unwrap()
+                .flatten_primitive()?
+                .typed_data::<i64>(),
+            (0i64..1000).collect_vec()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_projection_skips_buffers() -> VortexResult<()> {
+        let buffer = write_struct_file().await?;
+        let read = CountingReadAt {
+            read: buffer,
+            bytes_read: 0,
+        };
+        let mut reader = FileReader::try_new(read, &Context::default()).await?;
+
+        let before = reader.read.bytes_read;
+        reader.read_chunk(0, 0).await?;
+        let full = reader.read.bytes_read - before;
+
+        let before = reader.read.bytes_read;
+        let projected = reader
+            .read_chunk_projected(0, 0, &[FieldPath::builder().join("c").join("x").build()])
+            .await?;
+        let partial = reader.read.bytes_read - before;
+
+        assert_eq!(projected.len(), 1000);
+        // The u8 column is ~1KB, whereas the full chunk is dominated by the i32 and utf8 columns.
+        assert!(partial * 4 < full, "read {} of {} bytes", partial, full);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_compressed() -> VortexResult<()> {
+        let array = StructArray::try_new(
+            vec![FieldName::from("a"), FieldName::from("b")].into(),
+            vec![
+                PrimitiveArray::from(vec![7i64; 1000]).into_array(),
+                VarBinArray::from(vec!["repeated"; 1000]).into_array(),
+            ],
+            1000,
+            Validity::NonNullable,
+        )?;
+        let buffer = FileWriter::try_new(vec![], ViewContext::default())
+            .await?
+            .with_compression(BufferCompression::LZ4)
+            .write_array(array.into_array())
+            .await?
+            .finish()
+            .await?;
+        let mut reader = FileReader::try_new(buffer, &Context::default()).await?;
+
+        let chunk = StructArray::try_from(reader.read_chunk(0, 0).await?)?;
+        assert_eq!(
+            chunk
+                .field(0)
+                .unwrap()
+                .flatten_primitive()?
+                .typed_data::<i64>(),
+            vec![7i64; 1000]
+        );
+
+        let projected =
+            StructArray::try_from(reader.read_chunk_projected(0, 0, &[field("b")]).await?)?;
+        assert_eq!(
+            scalar_at(&projected.field(0).unwrap(), 999)?,
+            Scalar::from("repeated")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_checksums() -> VortexResult<()> {
+        let array = StructArray::try_new(
+            vec![FieldName::from("a"), FieldName::from("b")].into(),
+            vec![
+                PrimitiveArray::from((0i64..1000).map(|i| i * 7919).collect_vec()).into_array(),
+                VarBinArray::from(vec!["foo"; 1000]).into_array(),
+            ],
+            1000,
+            Validity::NonNullable,
+        )?;
+        let mut buffer = FileWriter::try_new(vec![], ViewContext::default())
+            .await?
+            .with_checksums(true)
+            .write_array(array.into_array())
+            .await?
+            .finish()
+            .await?;
+
+        // Flip a bit in the values of field "a".
+        let pos = buffer
+            .windows(8)
+            .position(|w| w == (500 * 7919i64).to_le_bytes())
+            .unwrap();
+        buffer[pos] ^= 1;
+
+        let mut reader = FileReader::try_new(buffer, &Context::default()).await?;
+        let chunk_offset = reader.array(0)?.chunks.byte_offsets[0];
+        let is_mismatch = |result: VortexResult<_>| matches!(result, Err(VortexError::ChecksumMismatch(offset, ..)) if offset == chunk_offset);
+        assert!(is_mismatch(reader.read_chunk(0, 0).await));
+        assert!(is_mismatch(
+            reader.read_chunk_projected(0, 0, &[field("a")]).await
+        ));
+        // Buffers that aren't read aren't verified.
+        assert!(reader
+            .read_chunk_projected(0, 0, &[field("b")])
+            .await
+            .is_ok());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_append() -> VortexResult<()> {
+        let ctx = Context::default();
+        let chunks = |ranges: [Range<i32>; 2]| {
+            ChunkedArray::try_new(
+                ranges
+                    .into_iter()
+                    .map(|r| PrimitiveArray::from(r.collect_vec()).into_array())
+                    .collect(),
+                PType::I32.into(),
+            )
+            .map(IntoArray::into_array)
+        };
+
+        let file = FileWriter::try_new(vec![], ViewContext::new(vec![&PrimitiveEncoding]))
+            .await?
+            .write_named_array("values", chunks([0..100, 100..200])?)
+            .await?
+            .write_array(PrimitiveArray::from(vec![1u64]).into_array())
+            .await?
+            .finish()
+            .await?;
+        let original = FileReader::try_new(file.clone(), &ctx)
+            .await?
+            .array(0)?
+            .chunks
+            .clone();
+
+        let writer = FileWriter::try_append_to(file.clone(), file.clone(), &ctx).await?;
+        let values = writer.array_idx("values")?;
+        let appended = writer
+            .append_array(values, chunks([200..300, 300..400])?)
+            .await?
+            .finish()
+            .await?;
+        // The existing bytes are left untouched.
+        assert_eq!(&appended[..file.len()], file.as_slice());
+
+        // An append that stops part way leaves the previous trailer as the last complete one.
+        for truncated_len in [appended.len() - 1, file.len() + 100] {
+            let truncated = appended[..truncated_len].to_vec();
+            let reader = FileReader::try_new(truncated.clone(), &ctx).await?;
+            assert_eq!(reader.array(0)?.chunks.row_offsets, vec![0, 100, 200]);
+            let reader = MmapFileReader::try_from_buffer(truncated.into(), &ctx)?;
+            assert_eq!(reader.array(0)?.chunks.row_offsets, vec![0, 100, 200]);
+        }
+
+        let mut reader = FileReader::try_new(appended, &ctx).await?;
+        assert_eq!(reader.array(1)?.row_count(), 1);
+        let layout = &reader.array(0)?.chunks;
+        assert_eq!(layout.row_offsets, vec![0, 100, 200, 300, 400]);
+        // The last existing chunk still ends before the superseded footer.
+        assert_eq!(layout.byte_range(1), original.byte_range(1));
+        assert!(layout.byte_range(1).1 < layout.byte_range(2).0);
+        for chunk_idx in 0..4 {
+            let chunk = reader.read_chunk(0, chunk_idx).await?.flatten_primitive()?;
+            assert_eq!(chunk.typed_data::<i32>()[0], chunk_idx as i32 * 100);
+        }
+        let taken = reader
+            .into_chunked_reader(0)?
+            .take_rows(&PrimitiveArray::from(vec![150u64, 199, 200, 399]).into_array())
+            .await?
+            .flatten_primitive()?;
+        assert_eq!(taken.typed_data::<i32>(), &[150, 199, 200, 399]);
+
+        // Chunks must match the dtype and view context of the file.
+        let append = |array: Array| {
+            let file = file.clone();
+            async move {
+                FileWriter::try_append_to(file.clone(), file, &Context::default())
+                    .await?
+                    .append_array(0, array)
+                    .await
+            }
+        };
+        assert!(append(PrimitiveArray::from(vec![1u64]).into_array())
+            .await
+            .is_err());
+        assert!(append(ConstantArray::new(1i32, 10).into_array())
+            .await
+            .is_err());
+        assert!(
+            FileWriter::try_append_to(file.clone(), file, &Context::default())
+                .await?
+                .append_array(2, chunks([0..1, 1..2])?)
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recover_interrupted_append() -> VortexResult<()> {
+        let ctx = Context::default();
+        let file = FileWriter::try_new(vec![], ViewContext::default())
+            .await?
+            .write_array(PrimitiveArray::from(vec![1i32, 2, 3]).into_array())
+            .await?
+            .finish()
+            .await?;
+        // The partial append spans several of the blocks searched for the previous trailer.
+        let mut appended = FileWriter::try_append_to(file.clone(), file.clone(), &ctx)
+            .await?
+            .append_array(
+                0,
+                PrimitiveArray::from((0..100_000).collect_vec()).into_array(),
+            )
+            .await?
+            .finish()
+            .await?;
+        appended.truncate(appended.len() - TRAILER_SIZE / 2);
+
+        let mut reader = FileReader::try_new(appended, &ctx).await?;
+        assert_eq!(reader.array(0)?.nchunks(), 1);
+        let chunk = reader.read_chunk(0, 0).await?;
+        assert_eq!(chunk.flatten_primitive()?.typed_data::<i32>(), &[1, 2, 3]);
+
+        // Without any earlier trailer, the original error is returned.
+        assert!(FileReader::try_new(file[..file.len() - 1].to_vec(), &ctx)
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_named_arrays() -> VortexResult<()> {
+        let buffer = FileWriter::try_new(vec![], ViewContext::default())
+            .await?
+            .write_named_array("facts", PrimitiveArray::from(vec![1i64, 2, 3]).into_array())
+            .await?
+            .write_array(PrimitiveArray::from(vec![0u8]).into_array())
+            .await?
+            .write_named_array("dim", VarBinArray::from(vec!["a", "b"]).into_array())
+            .await?
+            .finish()
+            .await?;
+        let mut reader = FileReader::try_new(buffer, &Context::default()).await?;
+
+        let toc = reader
+            .footer()
+            .arrays
+            .iter()
+            .map(|a| (a.name.as_deref(), a.dtype.clone()))
+            .collect_vec();
+        assert_eq!(
+            toc,
+            vec![
+                (Some("facts"), PType::I64.into()),
+                (None, PType::U8.into()),
+                (Some("dim"), DType::Utf8(Nullability::NonNullable)),
+            ]
+        );
+
+        let dim = reader.array_idx("dim")?;
+        assert_eq!(dim, 2);
+        let chunk = reader.read_chunk(dim, 0).await?;
+        assert_eq!(scalar_at(&chunk, 1)?, Scalar::from("b"));
+        assert!(reader.array_idx("missing").is_err());
+
+        // Names must be unique.
+        assert!(FileWriter::try_new(vec![], ViewContext::default())
+            .await?
+            .write_named_array("a", PrimitiveArray::from(vec![1i64]).into_array())
+            .await?
+            .write_named_array("a", PrimitiveArray::from(vec![1i64]).into_array())
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scan_prunes_chunks() -> VortexResult<()> {
+        let buffer = write_file().await?;
+        let read = CountingReadAt {
+            read: buffer,
+            bytes_read: 0,
+        };
+        let mut reader = FileReader::try_new(read, &Context::default()).await?;
+        let path = FieldPath::builder().build();
+        let predicate = Disjunction {
+            conjunctions: vec![Conjunction {
+                predicates: vec![path.clone().gte(lit(150i32)), path.lt(lit(250i32))],
+            }],
+        };
+
+        let before = reader.read.bytes_read;
+        reader.read_chunk(0, 0).await?;
+        let full = reader.read.bytes_read - before;
+
+        let mut may_match = vec![];
+        let mut message_bytes = 0;
+        for chunk_idx in 0..4 {
+            let before = reader.read.bytes_read;
+            may_match.push(reader.chunk_may_match(0, chunk_idx, &predicate).await?);
+            // Only the chunk message is read, without the 400 bytes of data.
+            message_bytes += reader.read.bytes_read - before;
+            assert!(reader.read.bytes_read - before + 400 <= full);
+        }
+        assert_eq!(may_match, vec![false, true, true, false]);
+
+        let before = reader.read.bytes_read;
+        let result = reader.scan(0, &predicate).await?;
+        // Every chunk message is read once, and then only the data of the surviving chunks.
+        assert_eq!(reader.read.bytes_read - before, message_bytes + 2 * 400);
+        assert_eq!(
+            ChunkedArray::try_from(&result)?.nchunks(),
+            2,
+            "chunks 0 and 3 are pruned"
+        );
+        assert_eq!(
+            result.flatten_primitive()?.typed_data::<i32>(),
+            (150..250).collect_vec()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scan_struct() -> VortexResult<()> {
+        let buffer = write_struct_file().await?;
+        let mut reader = FileReader::try_new(buffer, &Context::default()).await?;
+
+        let predicate = Disjunction {
+            conjunctions: vec![Conjunction {
+                predicates: vec![FieldPath::builder()
+                    .join("c")
+                    .join("y")
+                    .build()
+                    .gte(lit(995u16))],
+            }],
+        };
+        let result =
+            StructArray::try_from(reader.scan(0, &predicate).await?.flatten()?.into_array())?;
+        assert_eq!(
+            result
+                .field(0)
+                .unwrap()
+                .flatten_primitive()?
+                .typed_data::<i32>(),
+            (995..1000).collect_vec()
+        );
+
+        let pruned = Disjunction {
+            conjunctions: vec![Conjunction {
+                predicates: vec![field("a").gt(lit(1000i32))],
+            }],
+        };
+        assert!(!reader.chunk_may_match(0, 0, &pruned).await?);
+        assert_eq!(reader.scan(0, &pruned).await?.len(), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalid_trailer() -> VortexResult<()> {
+        let mut buffer = write_file().await?;
+        let len = buffer.len();
+        buffer[len - 1] = 0;
+        assert!(FileReader::try_new(buffer, &Context::default())
+            .await
+            .is_err());
+
+        assert!(
+            FileReader::try_new(vec![0u8; TRAILER_SIZE - 1], &Context::default())
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
+}
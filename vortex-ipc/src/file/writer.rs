@@ -0,0 +1,259 @@
+use futures_util::TryStreamExt;
+use vortex::array::chunked::ChunkedArray;
+use vortex::array::r#struct::StructArray;
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::stream::{ArrayStream, ArrayStreamAdapter};
+use vortex::{Array, Context, ViewContext};
+use vortex_dtype::{DType, FieldName};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::file::{ArrayFooter, FileReader, Footer};
+use crate::io::{VortexReadAt, VortexWrite};
+use crate::writer::ArrayWriter;
+use crate::BufferCompression;
+
+/// Writes arrays to a self-describing file.
+///
+/// The file is finalized by [FileWriter::finish], which writes the footer and trailer. An existing
+/// file can be extended by reopening it with [FileWriter::try_append_to].
+///
+/// The min, max and null count of every chunk, and of each of its struct fields, are computed
+/// before it is written so that readers can skip chunks using [crate::file::FileReader::scan].
+pub struct FileWriter<W: VortexWrite> {
+    writer: ArrayWriter<W>,
+    arrays: Vec<ArrayFooter>,
+}
+
+impl<W: VortexWrite> FileWriter<W> {
+    pub async fn try_new(write: W, view_ctx: ViewContext) -> VortexResult<Self> {
+        Ok(Self {
+            writer: ArrayWriter::new(write, view_ctx).write_context().await?,
+            arrays: vec![],
+        })
+    }
+
+    /// Reopen the existing file read by `read` to append to it.
+    ///
+    /// The footer is read from the file, and its view context must only use encodings known to
+    /// `ctx`. `write` must write to the end of the same file, see [FileWriter::try_append].
+    pub async fn try_append_to<R: VortexReadAt>(
+        read: R,
+        write: W,
+        ctx: &Context,
+    ) -> VortexResult<Self> {
+        let size = read.size().await?;
+        let footer = FileReader::try_new(read, ctx).await?.footer().clone();
+        Self::try_append(write, footer, size).await
+    }
+
+    /// Reopen an existing file of `size` bytes, with the given footer, to append to it.
+    ///
+    /// `write` must write to the end of the file. The existing bytes are never modified: the new
+    /// chunks are followed by a new footer and trailer, which supersede the old ones only once
+    /// [FileWriter::finish] has written them. Until then, or if appending fails part way,
+    /// [FileReader] finds the old trailer and reads the file as it was before the append.
+    pub async fn try_append(write: W, footer: Footer, size: u64) -> VortexResult<Self> {
+        if let Some(end) = footer
+            .arrays
+            .iter()
+            .filter_map(|a| a.chunks.byte_offsets.last())
+            .max()
+        {
+            if *end > size {
+                vortex_bail!(
+                    "Footer has chunks up to offset {}, past the end of the file at {}",
+                    end,
+                    size
+                );
+            }
+        }
+        let mut writer = ArrayWriter::new_at(write, footer.view_context.as_ref().clone(), size);
+        // The trailer leaves the end of the file unaligned.
+        writer.messages_mut().write_padding().await?;
+        Ok(Self {
+            writer,
+            arrays: footer.arrays,
+        })
+    }
+
+    /// Compress the buffers of every chunk written from now on, see [BufferCompression].
+    pub fn with_compression(mut self, compression: BufferCompression) -> Self {
+        self.writer = self.writer.with_compression(compression);
+        self
+    }
+
+    /// Checksum every message and buffer written from now on, including the footer.
+    pub fn with_checksums(mut self, checksums: bool) -> Self {
+        self.writer = self.writer.with_checksums(checksums);
+        self
+    }
+
+    pub fn arrays(&self) -> &[ArrayFooter] {
+        &self.arrays
+    }
+
+    /// The index of the array written under the given name, to append to it.
+    pub fn array_idx(&self, name: &str) -> VortexResult<usize> {
+        self.arrays
+            .iter()
+            .position(|array| array.name.as_deref() == Some(name))
+            .ok_or_else(|| vortex_err!("No array named {} in file", name))
+    }
+
+    pub async fn write_array_stream<S: ArrayStream + Unpin>(
+        self,
+        array_stream: S,
+    ) -> VortexResult<Self> {
+        self.write_array_stream_as(None, array_stream).await
+    }
+
+    /// Write an array under a name, which must be unique within the file.
+    ///
+    /// Readers can then look up the array by its name, see [crate::file::FileReader::array_idx].
+    pub async fn write_named_array_stream<S: ArrayStream + Unpin>(
+        self,
+        name: impl Into<FieldName>,
+        array_stream: S,
+    ) -> VortexResult<Self> {
+        self.write_array_stream_as(Some(name.into()), array_stream)
+            .await
+    }
+
+    pub async fn write_named_array(
+        self,
+        name: impl Into<FieldName>,
+        array: Array,
+    ) -> VortexResult<Self> {
+        if let Ok(chunked) = ChunkedArray::try_from(&array) {
+            self.write_named_array_stream(name, chunked.array_stream())
+                .await
+        } else {
+            self.write_named_array_stream(name, array.into_array_stream())
+                .await
+        }
+    }
+
+    async fn write_array_stream_as<S: ArrayStream + Unpin>(
+        mut self,
+        name: Option<FieldName>,
+        array_stream: S,
+    ) -> VortexResult<Self> {
+        if let Some(name) = &name {
+            if self.arrays.iter().any(|a| a.name.as_ref() == Some(name)) {
+                vortex_bail!("An array named {} has already been written", name);
+            }
+        }
+
+        let dtype = array_stream.dtype().clone();
+        self.writer = self
+            .writer
+            .write_array_stream(with_zone_maps(array_stream))
+            .await?;
+        self.push_array_footer(name, dtype);
+        Ok(self)
+    }
+
+    /// Append the chunks of the stream to the array at `array_idx`, see [FileWriter::array_idx].
+    ///
+    /// The chunks must have the dtype of the array, and only use the encodings of the file's view
+    /// context.
+    pub async fn append_array_stream<S: ArrayStream + Unpin>(
+        mut self,
+        array_idx: usize,
+        array_stream: S,
+    ) -> VortexResult<Self> {
+        let narrays = self.arrays.len();
+        let dtype = &self
+            .arrays
+            .get(array_idx)
+            .ok_or_else(|| vortex_err!(OutOfBounds: array_idx, 0, narrays))?
+            .dtype;
+        if array_stream.dtype() != dtype {
+            vortex_bail!(MismatchedTypes: dtype, array_stream.dtype());
+        }
+
+        let chunks = self
+            .writer
+            .write_array_chunks(with_zone_maps(array_stream))
+            .await?;
+
+        // The appended chunks start after the superseded footer, so the last existing chunk keeps
+        // its explicit end offset rather than extending up to them.
+        let array = &mut self.arrays[array_idx];
+        let row_count = array.row_count();
+        array.chunks.byte_offsets.pop();
+        array.chunks.byte_offsets.extend(chunks.byte_offsets);
+        array.chunks.byte_ends.extend(chunks.byte_ends);
+        array.chunks.row_offsets.extend(
+            chunks
+                .row_offsets
+                .into_iter()
+                .skip(1)
+                .map(|row_offset| row_offset + row_count),
+        );
+        Ok(self)
+    }
+
+    pub async fn append_array(self, array_idx: usize, array: Array) -> VortexResult<Self> {
+        if let Ok(chunked) = ChunkedArray::try_from(&array) {
+            self.append_array_stream(array_idx, chunked.array_stream())
+                .await
+        } else {
+            self.append_array_stream(array_idx, array.into_array_stream())
+                .await
+        }
+    }
+
+    pub async fn write_array(self, array: Array) -> VortexResult<Self> {
+        if let Ok(chunked) = ChunkedArray::try_from(&array) {
+            self.write_array_stream(chunked.array_stream()).await
+        } else {
+            self.write_array_stream(array.into_array_stream()).await
+        }
+    }
+
+    fn push_array_footer(&mut self, name: Option<FieldName>, dtype: DType) {
+        let layout = self
+            .writer
+            .array_layouts()
+            .last()
+            .expect("ArrayWriter must record a layout for every array");
+        self.arrays.push(ArrayFooter {
+            name,
+            dtype,
+            chunks: layout.chunks.clone(),
+        });
+    }
+
+    /// Write the footer and trailer, returning the underlying writer.
+    pub async fn finish(mut self) -> VortexResult<W> {
+        let view_ctx = self.writer.view_context().clone();
+        let msgs = self.writer.messages_mut();
+
+        let footer_offset = msgs.tell();
+        msgs.write_footer(&view_ctx, &self.arrays).await?;
+        msgs.write_trailer(footer_offset).await?;
+
+        Ok(self.writer.into_inner())
+    }
+}
+
+fn with_zone_maps<S: ArrayStream + Unpin>(array_stream: S) -> impl ArrayStream + Unpin {
+    ArrayStreamAdapter::new(
+        array_stream.dtype().clone(),
+        array_stream.map_ok(|chunk| {
+            compute_zone_map(&chunk);
+            chunk
+        }),
+    )
+}
+
+/// Compute the statistics used to prune chunks when scanning the file.
+fn compute_zone_map(array: &Array) {
+    for stat in [Stat::Min, Stat::Max, Stat::NullCount] {
+        array.statistics().compute(stat);
+    }
+    if let Ok(st) = StructArray::try_from(array) {
+        st.children().for_each(|field| compute_zone_map(&field));
+    }
+}
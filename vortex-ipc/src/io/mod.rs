@@ -1,6 +1,7 @@
 mod futures;
 mod monoio;
 pub mod offset;
+mod planner;
 mod read;
 mod tokio;
 mod write;
@@ -9,6 +10,7 @@ mod write;
 pub use futures::*;
 #[cfg(feature = "monoio")]
 pub use monoio::*;
+pub use planner::*;
 pub use read::*;
 #[cfg(feature = "tokio")]
 pub use tokio::*;
@@ -25,6 +25,13 @@ impl<R: VortexReadAt> VortexReadAt for OffsetReadAt<R> {
         self.read.read_at_into(pos + self.offset, buffer)
     }
 
+    async fn size(&self) -> std::io::Result<u64> {
+        self.read
+            .size()
+            .await
+            .map(|size| size.saturating_sub(self.offset))
+    }
+
     fn performance_hint(&self) -> usize {
         self.read.performance_hint()
     }
@@ -0,0 +1,149 @@
+use std::io;
+use std::ops::Range;
+
+use bytes::BytesMut;
+use itertools::Itertools;
+use vortex_buffer::Buffer;
+
+use crate::io::VortexReadAt;
+
+/// Plans the reads for a set of byte ranges, merging nearby ranges into fewer, larger reads.
+///
+/// Two ranges are fetched with a single read if the gap between them is no larger than the gap
+/// threshold, trading the bytes read from the gap for one fewer round trip to the storage.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReadPlanner {
+    gap_threshold: u64,
+}
+
+/// A single read issued by a [ReadPlanner], covering one or more of the requested ranges.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoalescedRead {
+    pub range: Range<u64>,
+    /// The positions of the requested ranges that are served by this read.
+    pub requests: Vec<usize>,
+}
+
+impl ReadPlanner {
+    pub fn new(gap_threshold: u64) -> Self {
+        Self { gap_threshold }
+    }
+
+    /// A planner using the [VortexReadAt::performance_hint] of the reader as its gap threshold.
+    pub fn for_read<R: VortexReadAt + ?Sized>(read: &R) -> Self {
+        Self::new(read.performance_hint() as u64)
+    }
+
+    pub fn gap_threshold(&self) -> u64 {
+        self.gap_threshold
+    }
+
+    /// Group the requested ranges into reads, ordered by their position in the file.
+    pub fn plan(&self, ranges: &[Range<u64>]) -> Vec<CoalescedRead> {
+        let mut reads: Vec<CoalescedRead> = Vec::new();
+        for pos in (0..ranges.len()).sorted_by_key(|&pos| ranges[pos].start) {
+            let range = &ranges[pos];
+            match reads.last_mut() {
+                Some(read) if range.start <= read.range.end.saturating_add(self.gap_threshold) => {
+                    read.range.end = read.range.end.max(range.end);
+                    read.requests.push(pos);
+                }
+                _ => reads.push(CoalescedRead {
+                    range: range.clone(),
+                    requests: vec![pos],
+                }),
+            }
+        }
+        reads
+    }
+
+    /// Read all of the requested ranges, returning their bytes in the order they were requested.
+    pub async fn read_ranges<R: VortexReadAt + ?Sized>(
+        &self,
+        read: &mut R,
+        ranges: &[Range<u64>],
+    ) -> io::Result<Vec<Buffer>> {
+        let mut buffers = vec![None; ranges.len()];
+        for coalesced in self.plan(ranges) {
+            let begin = coalesced.range.start;
+            let len = (coalesced.range.end - begin) as usize;
+            let bytes = read
+                .read_at_into(begin, BytesMut::zeroed(len))
+                .await?
+                .freeze();
+
+            // Split the bytes back into the individual ranges, without copying.
+            for pos in coalesced.requests {
+                let range = &ranges[pos];
+                buffers[pos] = Some(Buffer::from(
+                    bytes.slice((range.start - begin) as usize..(range.end - begin) as usize),
+                ));
+            }
+        }
+        Ok(buffers
+            .into_iter()
+            .map(|buffer| buffer.expect("every range is covered by the plan"))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures_executor::block_on;
+    use vortex_buffer::Buffer;
+
+    use crate::io::{CoalescedRead, ReadPlanner};
+
+    #[test]
+    fn test_plan() {
+        let ranges = [100..150, 0..10, 20..30, 10..15, 300..310];
+
+        assert_eq!(
+            ReadPlanner::new(0).plan(&ranges),
+            vec![
+                CoalescedRead {
+                    range: 0..15,
+                    requests: vec![1, 3],
+                },
+                CoalescedRead {
+                    range: 20..30,
+                    requests: vec![2],
+                },
+                CoalescedRead {
+                    range: 100..150,
+                    requests: vec![0],
+                },
+                CoalescedRead {
+                    range: 300..310,
+                    requests: vec![4],
+                },
+            ]
+        );
+
+        assert_eq!(
+            ReadPlanner::new(100).plan(&ranges),
+            vec![
+                CoalescedRead {
+                    range: 0..150,
+                    requests: vec![1, 3, 2, 0],
+                },
+                CoalescedRead {
+                    range: 300..310,
+                    requests: vec![4],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_ranges() {
+        let mut data = Buffer::from((0u8..=255).collect::<Vec<_>>());
+        let ranges = [200..210, 0..4, 2..6, 50..50];
+
+        let buffers = block_on(ReadPlanner::new(64).read_ranges(&mut data, &ranges)).unwrap();
+        assert_eq!(buffers[0].as_ref(), (200u8..210).collect::<Vec<_>>());
+        assert_eq!(buffers[1].as_ref(), &[0, 1, 2, 3]);
+        assert_eq!(buffers[2].as_ref(), &[2, 3, 4, 5]);
+        assert!(buffers[3].is_empty());
+    }
+}
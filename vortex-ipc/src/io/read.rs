@@ -1,14 +1,35 @@
-use std::future::Future;
+use std::fs::File;
+use std::future::{ready, Future};
 use std::io;
-use std::io::{Cursor, Read};
+use std::io::{Cursor, Read, Seek, SeekFrom};
 
 use bytes::BytesMut;
 use vortex_buffer::Buffer;
 
 pub trait VortexRead {
     fn read_into(&mut self, buffer: BytesMut) -> impl Future<Output = io::Result<BytesMut>>;
+
+    /// Advance past the next `len` bytes without returning them.
+    ///
+    /// By default the bytes are read and discarded in small pieces. Readers that can seek should
+    /// jump over them instead.
+    fn skip(&mut self, len: u64) -> impl Future<Output = io::Result<()>> {
+        async move {
+            let mut remaining = len;
+            let mut scratch = BytesMut::new();
+            while remaining > 0 {
+                let n = remaining.min(SKIP_CHUNK_SIZE);
+                scratch.resize(n as usize, 0);
+                scratch = self.read_into(scratch).await?;
+                remaining -= n;
+            }
+            Ok(())
+        }
+    }
 }
 
+const SKIP_CHUNK_SIZE: u64 = 64 * 1024;
+
 pub trait VortexReadAt {
     fn read_at_into(
         &mut self,
@@ -16,8 +37,20 @@ pub trait VortexReadAt {
         buffer: BytesMut,
     ) -> impl Future<Output = io::Result<BytesMut>>;
 
-    // TODO(ngates): the read implementation should be able to hint at its latency/throughput
-    //  allowing the caller to make better decisions about how to coalesce reads.
+    /// The total number of bytes available to read.
+    ///
+    /// Readers that don't know their size can still serve reads at known offsets, but can't be
+    /// opened with [crate::file::FileReader].
+    fn size(&self) -> impl Future<Output = io::Result<u64>> {
+        ready(Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "reader doesn't know its size",
+        )))
+    }
+
+    /// The number of bytes that are cheaper to read than issuing a separate read.
+    ///
+    /// Readers with a high per-read latency should return a larger value, see [crate::io::ReadPlanner].
     fn performance_hint(&self) -> usize {
         0
     }
@@ -28,6 +61,10 @@ impl VortexRead for Cursor<Vec<u8>> {
         Read::read_exact(self, buffer.as_mut())?;
         Ok(buffer)
     }
+
+    async fn skip(&mut self, len: u64) -> io::Result<()> {
+        skip_cursor(self, len)
+    }
 }
 
 impl VortexRead for Cursor<&[u8]> {
@@ -35,6 +72,10 @@ impl VortexRead for Cursor<&[u8]> {
         Read::read_exact(self, buffer.as_mut())?;
         Ok(buffer)
     }
+
+    async fn skip(&mut self, len: u64) -> io::Result<()> {
+        skip_cursor(self, len)
+    }
 }
 
 impl VortexRead for Cursor<Buffer> {
@@ -42,6 +83,19 @@ impl VortexRead for Cursor<Buffer> {
         Read::read_exact(self, buffer.as_mut())?;
         Ok(buffer)
     }
+
+    async fn skip(&mut self, len: u64) -> io::Result<()> {
+        skip_cursor(self, len)
+    }
+}
+
+fn skip_cursor<T: AsRef<[u8]>>(cursor: &mut Cursor<T>, len: u64) -> io::Result<()> {
+    let pos = cursor.position() + len;
+    if pos > cursor.get_ref().as_ref().len() as u64 {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    cursor.set_position(pos);
+    Ok(())
 }
 
 impl VortexReadAt for Vec<u8> {
@@ -52,6 +106,10 @@ impl VortexReadAt for Vec<u8> {
     ) -> impl Future<Output = io::Result<BytesMut>> {
         VortexReadAt::read_at_into(self.as_mut_slice(), pos, buffer)
     }
+
+    fn size(&self) -> impl Future<Output = io::Result<u64>> {
+        ready(Ok(self.len() as u64))
+    }
 }
 
 impl VortexReadAt for [u8] {
@@ -60,6 +118,10 @@ impl VortexReadAt for [u8] {
         buffer.copy_from_slice(&self[pos as usize..][..buffer_len]);
         Ok(buffer)
     }
+
+    fn size(&self) -> impl Future<Output = io::Result<u64>> {
+        ready(Ok(self.len() as u64))
+    }
 }
 
 impl VortexReadAt for Buffer {
@@ -71,4 +133,20 @@ impl VortexReadAt for Buffer {
         );
         Ok(buffer)
     }
+
+    fn size(&self) -> impl Future<Output = io::Result<u64>> {
+        ready(Ok(self.len() as u64))
+    }
+}
+
+impl VortexReadAt for File {
+    async fn read_at_into(&mut self, pos: u64, mut buffer: BytesMut) -> io::Result<BytesMut> {
+        self.seek(SeekFrom::Start(pos))?;
+        self.read_exact(buffer.as_mut())?;
+        Ok(buffer)
+    }
+
+    async fn size(&self) -> io::Result<u64> {
+        self.metadata().map(|m| m.len())
+    }
 }
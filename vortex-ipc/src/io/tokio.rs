@@ -3,10 +3,10 @@ use std::io;
 
 use bytes::BytesMut;
 use tokio::fs::File;
-use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt};
 use vortex_buffer::io_buf::IoBuf;
 
-use crate::io::{VortexRead, VortexWrite};
+use crate::io::{VortexRead, VortexReadAt, VortexWrite};
 
 pub struct TokioAdapter<IO>(pub IO);
 
@@ -46,3 +46,15 @@ impl VortexWrite for File {
         AsyncWriteExt::shutdown(self).await
     }
 }
+
+impl VortexReadAt for File {
+    async fn read_at_into(&mut self, pos: u64, mut buffer: BytesMut) -> io::Result<BytesMut> {
+        self.seek(io::SeekFrom::Start(pos)).await?;
+        self.read_exact(buffer.as_mut()).await?;
+        Ok(buffer)
+    }
+
+    async fn size(&self) -> io::Result<u64> {
+        self.metadata().await.map(|m| m.len())
+    }
+}
@@ -1,13 +1,20 @@
 extern crate core;
 
+pub use compression::*;
 pub use message_reader::*;
 pub use message_writer::*;
 use vortex_error::{vortex_err, VortexError};
+mod checksum;
 pub mod chunked_reader;
+mod compression;
+pub mod file;
 pub mod io;
 mod message_reader;
 mod message_writer;
 mod messages;
+mod projection;
+mod pruning;
+pub mod schema;
 pub mod stream_reader;
 pub mod writer;
 
@@ -46,22 +53,33 @@ pub(crate) const fn missing(field: &'static str) -> impl FnOnce() -> VortexError
 
 #[cfg(test)]
 pub mod test {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use bytes::BytesMut;
     use futures_util::io::Cursor;
     use futures_util::{pin_mut, StreamExt, TryStreamExt};
     use itertools::Itertools;
     use vortex::array::chunked::ChunkedArray;
     use vortex::array::primitive::{PrimitiveArray, PrimitiveEncoding};
+    use vortex::array::r#struct::StructArray;
+    use vortex::array::varbin::VarBinArray;
+    use vortex::compute::scalar_at::scalar_at;
     use vortex::encoding::ArrayEncoding;
     use vortex::encoding::EncodingRef;
     use vortex::stream::ArrayStreamExt;
+    use vortex::validity::Validity;
     use vortex::{ArrayDType, Context, IntoArray, ViewContext};
     use vortex_alp::ALPEncoding;
-    use vortex_error::VortexResult;
+    use vortex_dtype::field_paths::{field, FieldPath};
+    use vortex_error::{VortexError, VortexResult};
     use vortex_fastlanes::BitPackedEncoding;
+    use vortex_scalar::Scalar;
 
-    use crate::io::FuturesAdapter;
+    use crate::io::{FuturesAdapter, VortexRead};
+    use crate::stream_reader::StreamArrayReader;
     use crate::writer::ArrayWriter;
-    use crate::MessageReader;
+    use crate::{BufferCompression, MessageReader, ALIGNMENT};
 
     pub async fn create_stream() -> Vec<u8> {
         let ctx = Context::default().with_encodings([
@@ -162,4 +180,196 @@ pub mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_write_read_compressed() -> VortexResult<()> {
+        let data = VarBinArray::from(
+            (0..10_000)
+                .map(|i| format!("log line {}", i % 100))
+                .collect_vec(),
+        );
+        let uncompressed = write_ipc(data.clone()).await;
+        let data = data.into_array();
+
+        for compression in [BufferCompression::Zstd { level: 3 }, BufferCompression::LZ4] {
+            let buffer = ArrayWriter::new(vec![], ViewContext::from(&Context::default()))
+                .with_compression(compression)
+                .write_context()
+                .await?
+                .write_array(data.clone())
+                .await?
+                .into_inner();
+            assert!(buffer.len() * 2 < uncompressed.len());
+
+            let mut messages = MessageReader::try_new(FuturesAdapter(Cursor::new(buffer))).await?;
+            let result = messages
+                .array_stream_from_messages(&Context::default())
+                .await?
+                .collect_chunked()
+                .await?
+                .into_array();
+            assert_eq!(result.len(), data.len());
+            assert_eq!(scalar_at(&result, 1234)?, scalar_at(&data, 1234)?);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_read_compressed_primitives() -> VortexResult<()> {
+        let read = |data: PrimitiveArray, compression| async move {
+            let buffer = ArrayWriter::new(vec![], ViewContext::from(&Context::default()))
+                .with_compression(compression)
+                .write_context()
+                .await?
+                .write_array(data.into_array())
+                .await?
+                .into_inner();
+            let mut messages = MessageReader::try_new(FuturesAdapter(Cursor::new(buffer))).await?;
+            let chunk = messages
+                .array_stream_from_messages(&Context::default())
+                .await?
+                .collect_chunked()
+                .await?
+                .chunks()
+                .next()
+                .expect("one chunk")
+                .flatten_primitive()?;
+            // Decompressed values are read in place, so must be aligned.
+            assert_eq!(chunk.buffer().as_ptr() as usize % ALIGNMENT, 0);
+            VortexResult::Ok(chunk)
+        };
+
+        let ints = (0u64..10_000).map(|i| i % 100).collect_vec();
+        let floats = (0..10_000).map(|i| (i % 100) as f64 / 4.0).collect_vec();
+        for compression in [BufferCompression::Zstd { level: 3 }, BufferCompression::LZ4] {
+            let chunk = read(PrimitiveArray::from(ints.clone()), compression).await?;
+            assert_eq!(chunk.typed_data::<u64>(), ints.as_slice());
+            let chunk = read(PrimitiveArray::from(floats.clone()), compression).await?;
+            assert_eq!(chunk.typed_data::<f64>(), floats.as_slice());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_read_checksums() -> VortexResult<()> {
+        let data = PrimitiveArray::from((0i32..1000).map(|i| i * 1_000_003).collect_vec());
+        let writer = ArrayWriter::new(vec![], ViewContext::from(&Context::default()))
+            .with_checksums(true)
+            .write_context()
+            .await?
+            .write_array(data.into_array())
+            .await?;
+        let chunk_offset = writer.array_layouts()[0].chunks.byte_offsets[0] as usize;
+        let buffer = writer.into_inner();
+
+        let read = |buffer: Vec<u8>| async move {
+            let mut messages = MessageReader::try_new(FuturesAdapter(Cursor::new(buffer))).await?;
+            let chunked = messages
+                .array_stream_from_messages(&Context::default())
+                .await?
+                .collect_chunked()
+                .await?;
+            VortexResult::Ok(chunked)
+        };
+        assert_eq!(read(buffer.clone()).await?.into_array().len(), 1000);
+
+        // Flip a bit in the values of the chunk's buffer.
+        let value = 500 * 1_000_003i32;
+        let pos = buffer
+            .windows(4)
+            .position(|w| w == value.to_le_bytes())
+            .unwrap();
+        let mut corrupt = buffer.clone();
+        corrupt[pos] ^= 1;
+        assert!(matches!(
+            read(corrupt).await,
+            Err(VortexError::ChecksumMismatch(offset, ..)) if offset == chunk_offset as u64
+        ));
+
+        // Flip a bit in the padding of the chunk message, which is otherwise never read.
+        let msg_len = u32::from_le_bytes(buffer[chunk_offset..chunk_offset + 4].try_into()?);
+        let mut corrupt = buffer.clone();
+        corrupt[chunk_offset + 4 + msg_len as usize - 1] ^= 1;
+        assert!(matches!(
+            read(corrupt).await,
+            Err(VortexError::ChecksumMismatch(offset, ..)) if offset == chunk_offset as u64
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_projected() -> VortexResult<()> {
+        let chunk = |offset: i32| {
+            StructArray::try_new(
+                vec!["a".into(), "b".into()].into(),
+                vec![
+                    PrimitiveArray::from((offset..offset + 1000).collect_vec()).into_array(),
+                    VarBinArray::from((0..1000).map(|i| format!("{}", i)).collect_vec())
+                        .into_array(),
+                ],
+                1000,
+                Validity::NonNullable,
+            )
+            .unwrap()
+            .into_array()
+        };
+        let first = chunk(0);
+        let chunked =
+            ChunkedArray::try_new(vec![first.clone(), chunk(1000)], first.dtype().clone())?;
+        let buffer = write_ipc(chunked).await;
+
+        let read = |projection: Vec<FieldPath>| {
+            let buffer = buffer.clone();
+            async move {
+                let bytes_read = Rc::new(Cell::new(0));
+                let read = CountingRead {
+                    read: std::io::Cursor::new(buffer),
+                    bytes_read: bytes_read.clone(),
+                };
+                let mut reader = StreamArrayReader::try_new(read)
+                    .await?
+                    .load_view_context(&Context::default())
+                    .await?
+                    .load_dtype()
+                    .await?;
+                let chunks: Vec<_> = reader
+                    .array_stream_projected(&projection)?
+                    .try_collect()
+                    .await?;
+                VortexResult::Ok((chunks, bytes_read.get()))
+            }
+        };
+        let (_, all_bytes) = read(vec![]).await?;
+        let (chunks, projected_bytes) = read(vec![field("b")]).await?;
+        // The i32 values of field a are skipped without being read.
+        assert!(projected_bytes + 2 * 4000 <= all_bytes);
+        assert_eq!(chunks.len(), 2);
+
+        let projected = StructArray::try_from(&chunks[1])?;
+        assert_eq!(projected.names().as_ref(), &["b".into()]);
+        assert_eq!(projected.array().len(), 1000);
+        assert_eq!(
+            scalar_at(&projected.field(0).unwrap(), 999)?,
+            Scalar::from("999")
+        );
+        Ok(())
+    }
+
+    /// Counts the number of bytes read through the wrapped reader, excluding skipped bytes.
+    struct CountingRead<R> {
+        read: R,
+        bytes_read: Rc<Cell<u64>>,
+    }
+
+    impl<R: VortexRead> VortexRead for CountingRead<R> {
+        async fn read_into(&mut self, buffer: BytesMut) -> std::io::Result<BytesMut> {
+            self.bytes_read
+                .set(self.bytes_read.get() + buffer.len() as u64);
+            self.read.read_into(buffer).await
+        }
+
+        async fn skip(&mut self, len: u64) -> std::io::Result<()> {
+            self.read.skip(len).await
+        }
+    }
 }
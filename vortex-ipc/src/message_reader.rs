@@ -1,41 +1,58 @@
 use std::io;
 use std::sync::Arc;
 
-use bytes::{Buf, BytesMut};
+use bytes::{Buf, Bytes, BytesMut};
 use flatbuffers::{root, root_unchecked};
 use futures_util::stream::try_unfold;
 use itertools::Itertools;
 use vortex::stream::{ArrayStream, ArrayStreamAdapter};
 use vortex::{Array, ArrayView, Context, IntoArray, ToArray, ViewContext};
 use vortex_buffer::Buffer;
+use vortex_dtype::field_paths::FieldPath;
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 
+use crate::checksum::{verify_buffer, verify_message};
+use crate::compression::decompress;
+use crate::file::Footer;
 use crate::flatbuffers::ipc as fb;
 use crate::io::VortexRead;
-use crate::messages::SerdeContextDeserializer;
-use crate::ALIGNMENT;
+use crate::messages::{SerdeContextDeserializer, SerdeFooterDeserializer};
+use crate::projection::Selection;
 
 pub struct MessageReader<R> {
     read: R,
     message: BytesMut,
     prev_message: BytesMut,
     finished: bool,
+    /// The offset of the next byte in the stream, and of the current message.
+    pos: u64,
+    message_offset: u64,
 }
 
 impl<R: VortexRead> MessageReader<R> {
     pub async fn try_new(read: R) -> VortexResult<Self> {
+        Self::try_new_at(read, 0).await
+    }
+
+    /// Read messages starting at the given offset of a larger stream or file.
+    ///
+    /// The offset is only used to report the location of corrupt messages.
+    pub async fn try_new_at(read: R, offset: u64) -> VortexResult<Self> {
         let mut reader = Self {
             read,
             message: BytesMut::new(),
             prev_message: BytesMut::new(),
             finished: false,
+            pos: offset,
+            message_offset: offset,
         };
         reader.load_next_message().await?;
         Ok(reader)
     }
 
     async fn load_next_message(&mut self) -> VortexResult<bool> {
+        let offset = self.pos;
         let mut buffer = std::mem::take(&mut self.message);
         buffer.resize(4, 0);
         let mut buffer = match self.read.read_into(buffer).await {
@@ -48,6 +65,7 @@ impl<R: VortexRead> MessageReader<R> {
             }
         };
 
+        self.pos += 4;
         let len = buffer.get_u32_le();
         if len == u32::MAX {
             // Marker for no more messages.
@@ -59,11 +77,11 @@ impl<R: VortexRead> MessageReader<R> {
         buffer.reserve(len as usize);
         unsafe { buffer.set_len(len as usize) };
         self.message = self.read.read_into(buffer).await?;
+        self.pos += len as u64;
+        self.message_offset = offset;
 
-        // Validate that the message is valid a flatbuffer.
-        root::<fb::Message>(&self.message).map_err(
-            |e| vortex_err!(InvalidSerde: "Failed to parse flatbuffer message: {:?}", e),
-        )?;
+        // Validate that the message is a valid flatbuffer, with a matching checksum if it has one.
+        verify_message(&self.message, offset)?;
 
         Ok(true)
     }
@@ -99,64 +117,76 @@ impl<R: VortexRead> MessageReader<R> {
     }
 
     /// Fetch the buffers associated with this message.
-    async fn read_buffers(&mut self) -> VortexResult<Vec<Buffer>> {
+    ///
+    /// Only the buffers marked in `required` are read, the stream skips over the others, see
+    /// [VortexRead::skip]. Skipped buffers are left empty, so must never be accessed.
+    async fn read_buffers(&mut self, required: Option<Vec<bool>>) -> VortexResult<Vec<Buffer>> {
         let Some(chunk_msg) = self.peek().and_then(|m| m.header_as_chunk()) else {
             // We could return an error here?
             return Ok(Vec::new());
         };
-
-        // Initialize the column's buffers for a vectored read.
-        // To start with, we include the padding and then truncate the buffers after.
-        // TODO(ngates): improve the flatbuffer format instead of storing offset/len per buffer.
-        let buffers = chunk_msg
+        let checksums = self.peek().expect("checked above").checksum().is_some();
+        let buffer_size = chunk_msg.buffer_size();
+        let fb_buffers = chunk_msg
             .buffers()
             .unwrap_or_default()
             .iter()
-            .map(|buffer| {
-                // FIXME(ngates): this assumes the next buffer offset == the aligned length of
-                //  the previous buffer. I will fix this by improving the flatbuffer format instead
-                //  of fiddling with the logic here.
-                let len_width_padding =
-                    (buffer.length() as usize + (ALIGNMENT - 1)) & !(ALIGNMENT - 1);
-                // TODO(ngates): switch to use uninitialized
-                // TODO(ngates): allocate the entire thing in one go and then split
-                vec![0u8; len_width_padding]
-            })
+            .copied()
             .collect_vec();
+        let required = required.unwrap_or_else(|| vec![true; fb_buffers.len()]);
+        if required.len() != fb_buffers.len() {
+            vortex_bail!(InvalidSerde:
+                "Chunk has {} buffers, expected {}",
+                fb_buffers.len(),
+                required.len()
+            );
+        }
 
-        // Just sanity check the above
-        assert_eq!(
-            buffers.iter().map(|b| b.len()).sum::<usize>(),
-            chunk_msg.buffer_size() as usize
-        );
-
-        // Issue a single read to grab all buffers
-        let mut all_buffers = BytesMut::with_capacity(chunk_msg.buffer_size() as usize);
-        unsafe { all_buffers.set_len(chunk_msg.buffer_size() as usize) };
-        let mut all_buffers = self.read.read_into(all_buffers).await?;
+        // Issue a single read for each run of required buffers, including the padding between
+        // them, and skip everything else.
+        let mut buffers = vec![Buffer::from(Bytes::new()); fb_buffers.len()];
+        let mut pos = 0;
+        let mut idx = 0;
+        while idx < fb_buffers.len() {
+            if !required[idx] {
+                idx += 1;
+                continue;
+            }
+            let run_start = idx;
+            while idx < fb_buffers.len() && required[idx] {
+                idx += 1;
+            }
+            let begin = fb_buffers[run_start].offset();
+            let end = fb_buffers[idx - 1].offset() + fb_buffers[idx - 1].length();
+            if begin < pos || end > buffer_size {
+                vortex_bail!(InvalidSerde:
+                    "Buffers {}..{} of message at offset {} are out of order or out of bounds",
+                    run_start,
+                    idx,
+                    self.message_offset
+                );
+            }
 
-        // Split out into individual buffers
-        let buffers = self
-            .peek()
-            .expect("Checked above in peek")
-            .header_as_chunk()
-            .expect("Checked above in peek")
-            .buffers()
-            .unwrap_or_default()
-            .iter()
-            .scan(0, |offset, buffer| {
-                let len = buffer.length() as usize;
-                let padding_len = buffer.offset() as usize - *offset;
-
-                // Strip off any padding from the previous buffer
-                all_buffers.advance(padding_len);
-                // Grab the buffer
-                let buffer = all_buffers.split_to(len);
-
-                *offset += padding_len + len;
-                Some(Buffer::from(buffer.freeze()))
-            })
-            .collect_vec();
+            self.read.skip(begin - pos).await?;
+            let run = self
+                .read
+                .read_into(BytesMut::zeroed((end - begin) as usize))
+                .await?
+                .freeze();
+            pos = end;
+
+            for buffer_idx in run_start..idx {
+                let buffer = fb_buffers[buffer_idx];
+                let start = (buffer.offset() - begin) as usize;
+                let bytes = Buffer::from(run.slice(start..start + buffer.length() as usize));
+                if checksums {
+                    verify_buffer(&bytes, buffer.checksum(), self.message_offset, buffer_idx)?;
+                }
+                buffers[buffer_idx] = decompress(bytes, buffer.compression())?;
+            }
+        }
+        self.read.skip(buffer_size - pos).await?;
+        self.pos += buffer_size;
 
         Ok(buffers)
     }
@@ -195,6 +225,18 @@ impl<R: VortexRead> MessageReader<R> {
         Ok(dtype)
     }
 
+    pub async fn read_footer(&mut self, ctx: &Context) -> VortexResult<Footer> {
+        if self.peek().and_then(|m| m.header_as_footer()).is_none() {
+            vortex_bail!("Expected footer message")
+        }
+
+        SerdeFooterDeserializer {
+            fb: self.next().await?.header_as_footer().unwrap(),
+            ctx,
+        }
+        .try_into()
+    }
+
     pub async fn maybe_read_chunk(
         &mut self,
         view_ctx: Arc<ViewContext>,
@@ -204,7 +246,7 @@ impl<R: VortexRead> MessageReader<R> {
             return Ok(None);
         }
 
-        let buffers = self.read_buffers().await?;
+        let buffers = self.read_buffers(None).await?;
         let flatbuffer = self.next_raw().await?;
 
         let view = ArrayView::try_new(
@@ -230,6 +272,61 @@ impl<R: VortexRead> MessageReader<R> {
         Ok(Some(view.into_array()))
     }
 
+    /// Read the next chunk of a struct array, only keeping the buffers of the projected fields.
+    ///
+    /// The fields of the resulting struct are ordered as they first appear in the projection,
+    /// see [crate::file::FileReader::read_chunk_projected].
+    pub async fn maybe_read_chunk_projected(
+        &mut self,
+        view_ctx: Arc<ViewContext>,
+        dtype: DType,
+        projection: &[FieldPath],
+    ) -> VortexResult<Option<Array>> {
+        let selection = Selection::try_new(&dtype, projection)?;
+        self.maybe_read_chunk_selected(view_ctx, dtype, &selection)
+            .await
+    }
+
+    async fn maybe_read_chunk_selected(
+        &mut self,
+        view_ctx: Arc<ViewContext>,
+        dtype: DType,
+        selection: &Selection,
+    ) -> VortexResult<Option<Array>> {
+        if *selection == Selection::All {
+            return self.maybe_read_chunk(view_ctx, dtype).await;
+        }
+        let Some(fb_array) = self
+            .peek()
+            .and_then(|m| m.header_as_chunk())
+            .and_then(|chunk| chunk.array())
+        else {
+            return Ok(None);
+        };
+
+        let required = selection.required_buffers(fb_array, &dtype, &view_ctx);
+        let buffers = self.read_buffers(Some(required)).await?;
+        let flatbuffer = self.next_raw().await?;
+
+        let view = ArrayView::try_new(
+            view_ctx,
+            dtype,
+            flatbuffer,
+            |flatbuffer| {
+                root::<fb::Message>(flatbuffer)
+                    .map_err(VortexError::from)
+                    .map(|msg| msg.header_as_chunk().unwrap())
+                    .and_then(|chunk| {
+                        chunk
+                            .array()
+                            .ok_or_else(|| vortex_err!("Chunk missing Array"))
+                    })
+            },
+            buffers,
+        )?;
+        selection.project_array(view.into_array()).map(Some)
+    }
+
     /// Construct an ArrayStream pulling the ViewContext and DType from the stream.
     pub async fn array_stream_from_messages(
         &mut self,
@@ -272,20 +369,70 @@ impl<R: VortexRead> MessageReader<R> {
         )
     }
 
-    pub async fn maybe_read_page(&mut self) -> VortexResult<Option<Buffer>> {
-        if self.peek().and_then(|m| m.header_as_page()).is_none() {
-            return Ok(None);
+    /// Stream the chunks of a struct array, only keeping the buffers of the projected fields.
+    ///
+    /// The buffers of the other fields are passed over with [VortexRead::skip], so they are only
+    /// read, and then discarded, when the underlying reader can't seek.
+    pub fn array_stream_projected(
+        &mut self,
+        view_context: Arc<ViewContext>,
+        dtype: DType,
+        projection: &[FieldPath],
+    ) -> VortexResult<impl ArrayStream + '_> {
+        struct State<'a, R: VortexRead> {
+            msgs: &'a mut MessageReader<R>,
+            view_context: Arc<ViewContext>,
+            dtype: DType,
+            selection: Selection,
         }
-        let page_msg = self.next().await?.header_as_page().unwrap();
 
+        let selection = Selection::try_new(&dtype, projection)?;
+        let projected_dtype = selection.project_dtype(&dtype);
+        let init = State {
+            msgs: self,
+            view_context,
+            dtype,
+            selection,
+        };
+
+        Ok(ArrayStreamAdapter::new(
+            projected_dtype,
+            try_unfold(init, |state| async move {
+                match state
+                    .msgs
+                    .maybe_read_chunk_selected(
+                        state.view_context.clone(),
+                        state.dtype.clone(),
+                        &state.selection,
+                    )
+                    .await?
+                {
+                    None => Ok(None),
+                    Some(array) => Ok(Some((array, state))),
+                }
+            }),
+        ))
+    }
+
+    pub async fn maybe_read_page(&mut self) -> VortexResult<Option<Buffer>> {
+        let Some(message) = self.peek().filter(|m| m.header_as_page().is_some()) else {
+            return Ok(None);
+        };
+        let checksums = message.checksum().is_some();
+        let page_msg = message.header_as_page().unwrap();
         let buffer_len = page_msg.buffer_size() as usize;
         let total_len = buffer_len + (page_msg.padding() as usize);
+        let buffer_checksum = page_msg.buffer_checksum();
 
-        let mut buffer = self
-            .read
-            .read_into(BytesMut::with_capacity(total_len))
-            .await?;
+        // The page's buffer follows the page message, so must be read before the next message.
+        let mut buffer = self.read.read_into(BytesMut::zeroed(total_len)).await?;
+        self.pos += total_len as u64;
         buffer.truncate(buffer_len);
+        if checksums {
+            verify_buffer(&buffer, buffer_checksum, self.message_offset, 0)?;
+        }
+
+        self.next().await?;
         Ok(Some(Buffer::from(buffer.freeze())))
     }
 }
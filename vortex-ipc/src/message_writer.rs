@@ -1,16 +1,19 @@
 use std::io;
 
-use flatbuffers::FlatBufferBuilder;
-use itertools::Itertools;
+use crc32c::crc32c_append;
+use flatbuffers::{root_unchecked, FlatBufferBuilder};
 use vortex::{ArrayData, ViewContext};
 use vortex_buffer::io_buf::IoBuf;
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_flatbuffers::WriteFlatBuffer;
 
+use crate::checksum::{checksum, checksum_position, message_checksum};
+use crate::file::{ArrayFooter, Trailer, FILE_VERSION};
+use crate::flatbuffers::ipc as fb;
 use crate::io::VortexWrite;
-use crate::messages::{IPCChunk, IPCContext, IPCMessage, IPCPage, IPCSchema};
-use crate::ALIGNMENT;
+use crate::messages::{IPCChunk, IPCContext, IPCFooter, IPCMessage, IPCPage, IPCSchema};
+use crate::{BufferCompression, ALIGNMENT};
 
 const ZEROS: [u8; 512] = [0u8; 512];
 
@@ -19,6 +22,8 @@ pub struct MessageWriter<W> {
     write: W,
     pos: u64,
     alignment: usize,
+    compression: BufferCompression,
+    checksums: bool,
 
     scratch: Option<Vec<u8>>,
 }
@@ -30,10 +35,33 @@ impl<W: VortexWrite> MessageWriter<W> {
             write,
             pos: 0,
             alignment: ALIGNMENT,
+            compression: BufferCompression::None,
+            checksums: false,
             scratch: Some(Vec::new()),
         }
     }
 
+    /// Continue a stream of which `pos` bytes have already been written, e.g. to append to a file.
+    pub fn new_at(write: W, pos: u64) -> Self {
+        Self {
+            pos,
+            ..Self::new(write)
+        }
+    }
+
+    /// Compress the buffers of every chunk written from now on.
+    pub fn with_compression(mut self, compression: BufferCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Record a CRC32C checksum of every message and buffer written from now on, which is then
+    /// verified by the reader.
+    pub fn with_checksums(mut self, checksums: bool) -> Self {
+        self.checksums = checksums;
+        self
+    }
+
     pub fn into_inner(self) -> W {
         self.write
     }
@@ -59,32 +87,49 @@ impl<W: VortexWrite> MessageWriter<W> {
         // TODO(ngates): should we support writing from an ArrayView?
         chunk: ArrayData,
     ) -> io::Result<()> {
-        let buffer_offsets = chunk.all_buffer_offsets(self.alignment);
-
-        // Serialize the Chunk message.
-        self.write_message(IPCMessage::Chunk(IPCChunk(view_ctx, &chunk)))
-            .await?;
-
-        // Keep track of the offset to add padding after each buffer.
-        let mut current_offset = 0;
-        for (buffer, &buffer_end) in chunk
+        // Buffers are compressed up-front, since the message records their compressed lengths.
+        let buffers = chunk
             .depth_first_traversal()
             .flat_map(|data| data.buffer().cloned().into_iter())
-            .zip_eq(buffer_offsets.iter().skip(1))
-        {
-            let buffer_len = buffer.len();
+            .map(|buffer| self.compression.compress(buffer))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let mut fb_buffers = Vec::with_capacity(buffers.len());
+        let mut offset = 0;
+        for (buffer, compression) in buffers.iter() {
+            fb_buffers.push(fb::Buffer::new(
+                offset,
+                buffer.len() as u64,
+                *compression,
+                self.buffer_checksum(buffer),
+            ));
+            offset += self.aligned_len(buffer.len()) as u64;
+        }
+
+        // Serialize the Chunk message.
+        self.write_message(IPCMessage::Chunk(IPCChunk(
+            view_ctx,
+            &chunk,
+            &fb_buffers,
+            offset,
+        )))
+        .await?;
+
+        for (buffer, _) in buffers {
+            let padding = self.aligned_len(buffer.len()) - buffer.len();
             self.write_all(buffer).await?;
-            let padding = (buffer_end as usize) - current_offset - buffer_len;
             self.write_all(&ZEROS[0..padding]).await?;
-            current_offset = buffer_end as usize;
         }
 
         Ok(())
     }
 
     pub async fn write_page(&mut self, buffer: Buffer) -> io::Result<()> {
-        self.write_message(IPCMessage::Page(IPCPage(&buffer)))
-            .await?;
+        self.write_message(IPCMessage::Page(IPCPage(
+            &buffer,
+            self.buffer_checksum(&buffer),
+        )))
+        .await?;
         let buffer_len = buffer.len();
         self.write_all(buffer).await?;
 
@@ -95,7 +140,33 @@ impl<W: VortexWrite> MessageWriter<W> {
         Ok(())
     }
 
-    async fn write_message<F: WriteFlatBuffer>(&mut self, flatbuffer: F) -> io::Result<()> {
+    pub async fn write_footer(
+        &mut self,
+        view_ctx: &ViewContext,
+        arrays: &[ArrayFooter],
+    ) -> io::Result<()> {
+        self.write_message(IPCMessage::Footer(IPCFooter(view_ctx, arrays)))
+            .await
+    }
+
+    /// Pad the stream up to the alignment, so the next message can be written.
+    pub async fn write_padding(&mut self) -> io::Result<()> {
+        let padding = self.aligned_len(self.pos as usize) - self.pos as usize;
+        self.write_all(&ZEROS[0..padding]).await?;
+        Ok(())
+    }
+
+    /// Write the fixed-size file trailer pointing back at the footer message.
+    pub async fn write_trailer(&mut self, footer_offset: u64) -> io::Result<()> {
+        let trailer = Trailer {
+            footer_offset,
+            version: FILE_VERSION,
+        };
+        self.write_all(trailer.to_bytes()).await?;
+        Ok(())
+    }
+
+    async fn write_message(&mut self, message: IPCMessage<'_>) -> io::Result<()> {
         // We reuse the scratch buffer each time and then replace it at the end.
         // The scratch buffer may be missing if a previous write failed. We could use scopeguard
         // or similar here if it becomes a problem in practice.
@@ -107,16 +178,32 @@ impl<W: VortexWrite> MessageWriter<W> {
         scratch.extend_from_slice(&[0u8; 4]);
 
         let mut fbb = FlatBufferBuilder::from_vec(scratch);
-        let root = flatbuffer.write_flatbuffer(&mut fbb);
+        let root = if self.checksums {
+            message.write_flatbuffer_with_checksum(&mut fbb)
+        } else {
+            message.write_flatbuffer(&mut fbb)
+        };
         fbb.finish_minimal(root);
 
-        let (buffer, buffer_begin) = fbb.collapse();
+        let (mut buffer, buffer_begin) = fbb.collapse();
         let buffer_end = buffer.len();
         let buffer_len = buffer_end - buffer_begin;
 
         let aligned_size = (4 + buffer_len + (self.alignment - 1)) & !(self.alignment - 1);
         let padding_bytes = aligned_size - buffer_len - 4;
 
+        if self.checksums {
+            let flatbuffer = &mut buffer[buffer_begin..buffer_end];
+            // SAFETY: we have just written the message.
+            let message = unsafe { root_unchecked::<fb::Message>(flatbuffer) };
+            let position = checksum_position(message).expect("message has a checksum field");
+            let crc = crc32c_append(
+                message_checksum(flatbuffer, position),
+                &ZEROS[0..padding_bytes],
+            );
+            flatbuffer[position..position + 4].copy_from_slice(&crc.to_le_bytes());
+        }
+
         // Write the size as u32, followed by the buffer, followed by padding.
         self.write_all(((aligned_size - 4) as u32).to_le_bytes())
             .await?;
@@ -134,6 +221,18 @@ impl<W: VortexWrite> MessageWriter<W> {
         Ok(())
     }
 
+    fn buffer_checksum(&self, buffer: &[u8]) -> u32 {
+        if self.checksums {
+            checksum(buffer)
+        } else {
+            0
+        }
+    }
+
+    fn aligned_len(&self, len: usize) -> usize {
+        (len + (self.alignment - 1)) & !(self.alignment - 1)
+    }
+
     async fn write_all<B: IoBuf>(&mut self, buf: B) -> io::Result<B> {
         let buf = self.write.write_all(buf).await?;
         self.pos += buf.bytes_init() as u64;
@@ -3,12 +3,13 @@ use itertools::Itertools;
 use vortex::flatbuffers as fba;
 use vortex::{ArrayData, Context, ViewContext};
 use vortex_buffer::Buffer;
-use vortex_dtype::DType;
-use vortex_error::{vortex_err, VortexError};
+use vortex_dtype::{DType, FieldName};
+use vortex_error::{vortex_bail, vortex_err, VortexError};
 use vortex_flatbuffers::{FlatBufferRoot, WriteFlatBuffer};
 
+use crate::file::{ArrayFooter, Footer};
 use crate::flatbuffers::ipc as fb;
-use crate::flatbuffers::ipc::Compression;
+use crate::writer::ChunkLayout;
 use crate::{missing, ALIGNMENT};
 
 pub enum IPCMessage<'a> {
@@ -16,28 +17,43 @@ pub enum IPCMessage<'a> {
     Schema(IPCSchema<'a>),
     Chunk(IPCChunk<'a>),
     Page(IPCPage<'a>),
+    Footer(IPCFooter<'a>),
 }
 
 pub struct IPCContext<'a>(pub &'a ViewContext);
 pub struct IPCSchema<'a>(pub &'a DType);
-pub struct IPCChunk<'a>(pub &'a ViewContext, pub &'a ArrayData);
+pub struct IPCChunk<'a>(
+    pub &'a ViewContext,
+    pub &'a ArrayData,
+    pub &'a [fb::Buffer],
+    pub u64,
+);
 pub struct IPCArray<'a>(pub &'a ViewContext, pub &'a ArrayData);
-pub struct IPCPage<'a>(pub &'a Buffer);
+pub struct IPCPage<'a>(pub &'a Buffer, pub u32);
+pub struct IPCFooter<'a>(pub &'a ViewContext, pub &'a [ArrayFooter]);
 
 impl FlatBufferRoot for IPCMessage<'_> {}
 
-impl WriteFlatBuffer for IPCMessage<'_> {
-    type Target<'a> = fb::Message<'a>;
+impl IPCMessage<'_> {
+    /// Write the message with a zeroed checksum, to be filled in once the message is serialized.
+    pub(crate) fn write_flatbuffer_with_checksum<'fb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fb>,
+    ) -> WIPOffset<fb::Message<'fb>> {
+        self.write_message(fbb, true)
+    }
 
-    fn write_flatbuffer<'fb>(
+    fn write_message<'fb>(
         &self,
         fbb: &mut FlatBufferBuilder<'fb>,
-    ) -> WIPOffset<Self::Target<'fb>> {
+        checksum: bool,
+    ) -> WIPOffset<fb::Message<'fb>> {
         let header = match self {
             Self::Context(f) => f.write_flatbuffer(fbb).as_union_value(),
             Self::Schema(f) => f.write_flatbuffer(fbb).as_union_value(),
             Self::Chunk(f) => f.write_flatbuffer(fbb).as_union_value(),
             Self::Page(f) => f.write_flatbuffer(fbb).as_union_value(),
+            Self::Footer(f) => f.write_flatbuffer(fbb).as_union_value(),
         };
 
         let mut msg = fb::MessageBuilder::new(fbb);
@@ -47,12 +63,27 @@ impl WriteFlatBuffer for IPCMessage<'_> {
             Self::Schema(_) => fb::MessageHeader::Schema,
             Self::Chunk(_) => fb::MessageHeader::Chunk,
             Self::Page(_) => fb::MessageHeader::Page,
+            Self::Footer(_) => fb::MessageHeader::Footer,
         });
         msg.add_header(header);
+        if checksum {
+            msg.add_checksum(0);
+        }
         msg.finish()
     }
 }
 
+impl WriteFlatBuffer for IPCMessage<'_> {
+    type Target<'a> = fb::Message<'a>;
+
+    fn write_flatbuffer<'fb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fb>,
+    ) -> WIPOffset<Self::Target<'fb>> {
+        self.write_message(fbb, false)
+    }
+}
+
 impl<'a> WriteFlatBuffer for IPCContext<'a> {
     type Target<'t> = fb::Context<'t>;
 
@@ -129,31 +160,15 @@ impl<'a> WriteFlatBuffer for IPCChunk<'a> {
         &self,
         fbb: &mut FlatBufferBuilder<'fb>,
     ) -> WIPOffset<Self::Target<'fb>> {
-        let array_data = self.1;
-        let array = Some(IPCArray(self.0, array_data).write_flatbuffer(fbb));
-
-        // Walk the ColumnData depth-first to compute the buffer offsets.
-        let mut buffers = vec![];
-        let mut offset = 0;
-        for array_data in array_data.depth_first_traversal() {
-            if let Some(buffer) = array_data.buffer() {
-                buffers.push(fb::Buffer::new(
-                    offset as u64,
-                    buffer.len() as u64,
-                    Compression::None,
-                ));
-                let aligned_size = (buffer.len() + (ALIGNMENT - 1)) & !(ALIGNMENT - 1);
-                offset += aligned_size;
-            }
-        }
-        let buffers = Some(fbb.create_vector(&buffers));
+        let array = Some(IPCArray(self.0, self.1).write_flatbuffer(fbb));
+        let buffers = Some(fbb.create_vector(self.2));
 
         fb::Chunk::create(
             fbb,
             &fb::ChunkArgs {
                 array,
                 buffers,
-                buffer_size: offset as u64,
+                buffer_size: self.3,
             },
         )
     }
@@ -224,7 +239,109 @@ impl<'a> WriteFlatBuffer for IPCPage<'a> {
             &fb::PageArgs {
                 buffer_size: buffer_size as u32,
                 padding: padding_size as u16,
+                buffer_checksum: self.1,
             },
         )
     }
 }
+
+impl<'a> WriteFlatBuffer for IPCFooter<'a> {
+    type Target<'t> = fb::Footer<'t>;
+
+    fn write_flatbuffer<'fb>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'fb>,
+    ) -> WIPOffset<Self::Target<'fb>> {
+        let context = Some(IPCContext(self.0).write_flatbuffer(fbb));
+
+        let arrays = self
+            .1
+            .iter()
+            .map(|array| {
+                let name = array.name.as_ref().map(|name| fbb.create_string(name));
+                let dtype = Some(array.dtype.write_flatbuffer(fbb));
+                let byte_offsets = Some(fbb.create_vector(&array.chunks.byte_offsets));
+                let row_offsets = Some(fbb.create_vector(&array.chunks.row_offsets));
+                let byte_ends = Some(fbb.create_vector(&array.chunks.byte_ends));
+                let chunks = Some(fb::ChunkLayout::create(
+                    fbb,
+                    &fb::ChunkLayoutArgs {
+                        byte_offsets,
+                        row_offsets,
+                        byte_ends,
+                    },
+                ));
+                fb::ArrayFooter::create(
+                    fbb,
+                    &fb::ArrayFooterArgs {
+                        name,
+                        dtype,
+                        chunks,
+                    },
+                )
+            })
+            .collect_vec();
+        let arrays = Some(fbb.create_vector(arrays.as_slice()));
+
+        fb::Footer::create(fbb, &fb::FooterArgs { context, arrays })
+    }
+}
+
+pub struct SerdeFooterDeserializer<'a> {
+    pub(crate) fb: fb::Footer<'a>,
+    pub(crate) ctx: &'a Context,
+}
+
+impl<'a> TryFrom<SerdeFooterDeserializer<'a>> for Footer {
+    type Error = VortexError;
+
+    fn try_from(deser: SerdeFooterDeserializer<'a>) -> Result<Self, Self::Error> {
+        let view_context: ViewContext = SerdeContextDeserializer {
+            fb: deser.fb.context().ok_or_else(missing("footer.context"))?,
+            ctx: deser.ctx,
+        }
+        .try_into()?;
+
+        let fb_arrays = deser.fb.arrays().ok_or_else(missing("footer.arrays"))?;
+        let mut arrays = Vec::with_capacity(fb_arrays.len());
+        for fb_array in fb_arrays {
+            let dtype = DType::try_from(fb_array.dtype().ok_or_else(missing("array.dtype"))?)
+                .map_err(|e| vortex_err!(InvalidSerde: "Failed to parse DType: {}", e))?;
+            let fb_chunks = fb_array.chunks().ok_or_else(missing("array.chunks"))?;
+            let byte_offsets = fb_chunks
+                .byte_offsets()
+                .ok_or_else(missing("chunks.byte_offsets"))?
+                .iter()
+                .collect_vec();
+            let row_offsets = fb_chunks
+                .row_offsets()
+                .ok_or_else(missing("chunks.row_offsets"))?
+                .iter()
+                .collect_vec();
+            if byte_offsets.len() != row_offsets.len() {
+                vortex_bail!(InvalidSerde: "byte_offsets and row_offsets must have the same length");
+            }
+            let byte_ends = match fb_chunks.byte_ends() {
+                Some(byte_ends) => byte_ends.iter().collect_vec(),
+                None => byte_offsets.iter().skip(1).copied().collect_vec(),
+            };
+            if byte_ends.len() != byte_offsets.len().saturating_sub(1) {
+                vortex_bail!(InvalidSerde: "byte_ends must have one offset per chunk");
+            }
+            arrays.push(ArrayFooter {
+                name: fb_array.name().map(FieldName::from),
+                dtype,
+                chunks: ChunkLayout {
+                    byte_offsets,
+                    row_offsets,
+                    byte_ends,
+                },
+            });
+        }
+
+        Ok(Footer {
+            view_context: view_context.into(),
+            arrays,
+        })
+    }
+}
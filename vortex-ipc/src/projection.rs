@@ -0,0 +1,168 @@
+use itertools::Itertools;
+use vortex::array::r#struct::{StructArray, StructEncoding};
+use vortex::encoding::ArrayEncoding;
+use vortex::flatbuffers as fba;
+use vortex::{Array, IntoArray, ViewContext};
+use vortex_dtype::field_paths::{FieldIdentifier, FieldPath};
+use vortex_dtype::{DType, StructDType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+/// The fields of a (possibly nested) struct that are selected by a set of field paths.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Selection {
+    /// The whole array is selected.
+    All,
+    /// The given struct fields, in projection order, each with their own selection.
+    Fields(Vec<(usize, Selection)>),
+}
+
+impl Selection {
+    /// Resolve the field paths against the dtype.
+    ///
+    /// Fields are returned in the order they first appear in `paths`. An empty path selects the
+    /// entire array.
+    pub fn try_new(dtype: &DType, paths: &[FieldPath]) -> VortexResult<Self> {
+        if paths.is_empty() || paths.iter().any(|p| p.head().is_none()) {
+            return Ok(Self::All);
+        }
+
+        let DType::Struct(st, _) = dtype else {
+            vortex_bail!(
+                "Cannot project fields {:?} from non-struct dtype {}",
+                paths,
+                dtype
+            );
+        };
+
+        let mut fields: Vec<(usize, Vec<FieldPath>)> = vec![];
+        for path in paths {
+            let idx = match path.head().expect("checked above") {
+                FieldIdentifier::Name(name) => st
+                    .find_name(name)
+                    .ok_or_else(|| vortex_err!("Unknown field {} in {}", name, dtype))?,
+                FieldIdentifier::ListIndex(_) => {
+                    vortex_bail!("List index projections are not supported: {}", path)
+                }
+            };
+            let tail = path.tail().expect("checked above");
+            match fields.iter_mut().find(|(i, _)| *i == idx) {
+                Some((_, tails)) => tails.push(tail),
+                None => fields.push((idx, vec![tail])),
+            }
+        }
+
+        let fields = fields
+            .into_iter()
+            .map(|(idx, tails)| {
+                Self::try_new(&st.dtypes()[idx], &tails).map(|selection| (idx, selection))
+            })
+            .try_collect()?;
+        Ok(Self::Fields(fields))
+    }
+
+    /// The dtype that results from applying this selection.
+    pub fn project_dtype(&self, dtype: &DType) -> DType {
+        match (self, dtype) {
+            (Self::Fields(fields), DType::Struct(st, n)) => DType::Struct(
+                StructDType::new(
+                    fields
+                        .iter()
+                        .map(|(idx, _)| st.names()[*idx].clone())
+                        .collect_vec()
+                        .into(),
+                    fields
+                        .iter()
+                        .map(|(idx, sel)| sel.project_dtype(&st.dtypes()[*idx]))
+                        .collect_vec(),
+                ),
+                *n,
+            ),
+            _ => dtype.clone(),
+        }
+    }
+
+    /// Mark which of the depth-first buffers of the given flatbuffer array are required.
+    pub fn required_buffers(
+        &self,
+        array: fba::Array,
+        dtype: &DType,
+        view_ctx: &ViewContext,
+    ) -> Vec<bool> {
+        let mut required = vec![];
+        self.mark_buffers(array, dtype, view_ctx, &mut required);
+        required
+    }
+
+    fn mark_buffers(
+        &self,
+        array: fba::Array,
+        dtype: &DType,
+        view_ctx: &ViewContext,
+        required: &mut Vec<bool>,
+    ) {
+        let (Self::Fields(fields), DType::Struct(st, _)) = (self, dtype) else {
+            mark_all(array, true, required);
+            return;
+        };
+        let is_struct = view_ctx
+            .find_encoding(array.encoding())
+            .map(|e| e.id() == StructEncoding.id())
+            .unwrap_or(false);
+        if !is_struct {
+            // Other encodings don't lay out their children per field, so we read everything.
+            mark_all(array, true, required);
+            return;
+        }
+
+        if array.has_buffer() {
+            required.push(true);
+        }
+        for (idx, child) in array.children().unwrap_or_default().iter().enumerate() {
+            if idx >= st.dtypes().len() {
+                // Children past the fields hold the struct's validity.
+                mark_all(child, true, required);
+            } else if let Some((_, selection)) = fields.iter().find(|(i, _)| *i == idx) {
+                selection.mark_buffers(child, &st.dtypes()[idx], view_ctx, required);
+            } else {
+                mark_all(child, false, required);
+            }
+        }
+    }
+
+    /// Apply the selection to an array whose unselected buffers may not have been loaded.
+    pub fn project_array(&self, array: Array) -> VortexResult<Array> {
+        let Self::Fields(fields) = self else {
+            return Ok(array);
+        };
+
+        let st = match StructArray::try_from(&array) {
+            Ok(st) => st,
+            Err(_) => StructArray::try_from(array.flatten()?.into_array())?,
+        };
+        let names = fields
+            .iter()
+            .map(|(idx, _)| st.names()[*idx].clone())
+            .collect_vec();
+        let projected = fields
+            .iter()
+            .map(|(idx, selection)| {
+                let field = st
+                    .field(*idx)
+                    .ok_or_else(|| vortex_err!(OutOfBounds: *idx, 0, st.nfields()))?;
+                selection.project_array(field)
+            })
+            .try_collect()?;
+
+        StructArray::try_new(names.into(), projected, st.array().len(), st.validity())
+            .map(|a| a.into_array())
+    }
+}
+
+fn mark_all(array: fba::Array, value: bool, required: &mut Vec<bool>) {
+    if array.has_buffer() {
+        required.push(value);
+    }
+    for child in array.children().unwrap_or_default() {
+        mark_all(child, value, required);
+    }
+}
@@ -0,0 +1,242 @@
+use std::cmp::Ordering;
+
+use vortex::array::r#struct::StructArray;
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::{Array, ArrayDType};
+use vortex_dtype::field_paths::{FieldIdentifier, FieldPath};
+use vortex_dtype::DType;
+use vortex_expr::expressions::{Disjunction, Predicate, Value};
+use vortex_expr::operators::Operator;
+use vortex_scalar::{PrimitiveScalar, Scalar};
+
+/// Returns false only if the statistics of the array prove that none of its `len` rows match.
+///
+/// Only the flatbuffer of the array is accessed, so this can be evaluated against a chunk whose
+/// buffers have not been read. As the length of such an array can't be trusted, it is passed in.
+/// Missing or incomparable statistics never prune a chunk.
+pub(crate) fn may_match(array: &Array, len: usize, predicate: &Disjunction) -> bool {
+    // An empty disjunction matches every row, see FilterIndicesFn.
+    predicate.conjunctions.is_empty()
+        || predicate.conjunctions.iter().any(|conj| {
+            conj.predicates
+                .iter()
+                .all(|pred| predicate_may_match(array, len, pred))
+        })
+}
+
+fn predicate_may_match(array: &Array, len: usize, predicate: &Predicate) -> bool {
+    let Value::Literal(literal) = &predicate.right else {
+        return true;
+    };
+    let Some(column) = resolve_field(array, &predicate.left) else {
+        return true;
+    };
+    let dtype = column.dtype();
+    let stats = column.statistics();
+
+    // Nulls never match a comparison, so a chunk of only nulls can be skipped outright.
+    if stats.get_as_cast::<u64>(Stat::NullCount) == Some(len as u64) {
+        return false;
+    }
+
+    let Some(literal) = coerce_literal(literal, dtype) else {
+        return true;
+    };
+    let min = stats.get(Stat::Min).and_then(|min| coerce(&min, dtype));
+    let max = stats
+        .get(Stat::Max)
+        .and_then(|max| coerce(&max, dtype))
+        .or_else(|| {
+            (stats.get_as::<bool>(Stat::IsConstant) == Some(true))
+                .then(|| min.clone())
+                .flatten()
+        });
+
+    let cmp = |stat: &Option<Scalar>| stat.as_ref().and_then(|s| s.partial_cmp(&literal));
+    match predicate.op {
+        Operator::EqualTo => {
+            cmp(&min) != Some(Ordering::Greater) && cmp(&max) != Some(Ordering::Less)
+        }
+        // NaN is not equal to itself, so float chunks can't be pruned by their bounds.
+        Operator::NotEqualTo => {
+            dtype.is_float()
+                || cmp(&min) != Some(Ordering::Equal)
+                || cmp(&max) != Some(Ordering::Equal)
+        }
+        Operator::GreaterThan => cmp(&max) == Some(Ordering::Greater) || cmp(&max).is_none(),
+        Operator::GreaterThanOrEqualTo => cmp(&max) != Some(Ordering::Less),
+        Operator::LessThan => cmp(&min) == Some(Ordering::Less) || cmp(&min).is_none(),
+        Operator::LessThanOrEqualTo => cmp(&min) != Some(Ordering::Greater),
+    }
+}
+
+fn resolve_field(array: &Array, path: &FieldPath) -> Option<Array> {
+    match path.head() {
+        None => Some(array.clone()),
+        Some(FieldIdentifier::Name(name)) => {
+            let st = StructArray::try_from(array).ok()?;
+            let idx = st.names().iter().position(|n| n.as_ref() == name)?;
+            resolve_field(&st.field(idx)?, &path.tail()?)
+        }
+        Some(FieldIdentifier::ListIndex(_)) => None,
+    }
+}
+
+/// Convert a serialized statistic into a scalar of the given dtype.
+///
+/// Primitive values may be deserialized with a different width than the column they describe.
+fn coerce(scalar: &Scalar, dtype: &DType) -> Option<Scalar> {
+    if scalar.is_null() {
+        return None;
+    }
+    match dtype {
+        DType::Primitive(..) => PrimitiveScalar::try_from(scalar).ok()?.cast(dtype).ok(),
+        _ if scalar.dtype().eq_ignore_nullability(dtype) => {
+            Some(Scalar::new(dtype.clone(), scalar.value().clone()))
+        }
+        _ => None,
+    }
+}
+
+/// Convert the literal into the dtype of the column, only if this can be done without loss.
+fn coerce_literal(literal: &Scalar, dtype: &DType) -> Option<Scalar> {
+    let coerced = coerce(literal, dtype)?;
+    match dtype {
+        DType::Primitive(..) => (coerce(&coerced, literal.dtype())?
+            == coerce(literal, literal.dtype())?)
+        .then_some(coerced),
+        _ => Some(coerced),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::array::r#struct::StructArray;
+    use vortex::array::varbin::VarBinArray;
+    use vortex::stats::{ArrayStatistics, Stat};
+    use vortex::validity::Validity;
+    use vortex::IntoArray;
+    use vortex_dtype::field_paths::{field, FieldPath};
+    use vortex_dtype::FieldName;
+    use vortex_expr::expressions::{lit, Conjunction, Disjunction, Predicate};
+    use vortex_expr::field_paths::FieldPathOperations;
+
+    use crate::pruning::may_match;
+
+    fn disjunction(predicates: Vec<Predicate>) -> Disjunction {
+        Disjunction {
+            conjunctions: vec![Conjunction { predicates }],
+        }
+    }
+
+    #[test]
+    fn test_prune_primitive() {
+        let array = PrimitiveArray::from(vec![10i64, 20, 30]).into_array();
+        array.statistics().compute(Stat::Min);
+        let path = FieldPath::builder().build();
+
+        let matches = |pred| may_match(&array, 3, &disjunction(vec![pred]));
+        assert!(matches(path.clone().eq(lit(20i64))));
+        assert!(!matches(path.clone().eq(lit(40i64))));
+        assert!(!matches(path.clone().gt(lit(30i64))));
+        assert!(matches(path.clone().gte(lit(30i64))));
+        assert!(!matches(path.clone().lt(lit(10i64))));
+        assert!(matches(path.clone().lte(lit(10i64))));
+        assert!(matches(path.clone().not_eq(lit(10i64))));
+        // Literals of a different width are compared in the column's type.
+        assert!(!matches(path.clone().gt(lit(30u8))));
+        // Lossy casts never prune.
+        assert!(matches(path.clone().lt(lit(9.5f64))));
+
+        // A conjunction is pruned if any of its predicates can't match.
+        assert!(!may_match(
+            &array,
+            3,
+            &disjunction(vec![
+                path.clone().gt(lit(15i64)),
+                path.clone().lt(lit(5i64))
+            ])
+        ));
+        // A disjunction is only pruned if all of its conjunctions can't match.
+        assert!(may_match(
+            &array,
+            3,
+            &Disjunction {
+                conjunctions: vec![
+                    Conjunction {
+                        predicates: vec![path.clone().gt(lit(100i64))]
+                    },
+                    Conjunction {
+                        predicates: vec![path.clone().lt(lit(11i64))]
+                    },
+                ],
+            }
+        ));
+    }
+
+    #[test]
+    fn test_prune_constant_and_nulls() {
+        let constant = PrimitiveArray::from(vec![7u32; 5]).into_array();
+        constant.statistics().compute(Stat::Min);
+        let path = FieldPath::builder().build();
+        assert!(!may_match(
+            &constant,
+            5,
+            &disjunction(vec![path.clone().not_eq(lit(7u32))])
+        ));
+        assert!(may_match(
+            &constant,
+            5,
+            &disjunction(vec![path.clone().not_eq(lit(8u32))])
+        ));
+
+        let nulls = PrimitiveArray::from_nullable_vec(vec![None::<u32>; 5]).into_array();
+        nulls.statistics().compute(Stat::NullCount);
+        assert!(!may_match(
+            &nulls,
+            5,
+            &disjunction(vec![path.eq(lit(7u32))])
+        ));
+    }
+
+    #[test]
+    fn test_prune_struct_fields() {
+        let array = StructArray::try_new(
+            vec![FieldName::from("a"), FieldName::from("b")].into(),
+            vec![
+                PrimitiveArray::from(vec![1u16, 2, 3]).into_array(),
+                VarBinArray::from(vec!["bar", "baz", "foo"]).into_array(),
+            ],
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap();
+        for field in array.children() {
+            field.statistics().compute(Stat::Min);
+        }
+        let array = array.into_array();
+
+        assert!(!may_match(
+            &array,
+            3,
+            &disjunction(vec![field("a").gt(lit(3u16))])
+        ));
+        assert!(!may_match(
+            &array,
+            3,
+            &disjunction(vec![field("b").lt(lit("bar"))])
+        ));
+        assert!(may_match(
+            &array,
+            3,
+            &disjunction(vec![field("b").eq(lit("baz"))])
+        ));
+        // Predicates on unknown fields are left for filter_indices to reject.
+        assert!(may_match(
+            &array,
+            3,
+            &disjunction(vec![field("c").eq(lit(1u16))])
+        ));
+    }
+}
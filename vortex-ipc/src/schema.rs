@@ -0,0 +1,372 @@
+use itertools::Itertools;
+use vortex::array::bool::BoolArray;
+use vortex::array::constant::ConstantArray;
+use vortex::array::decimal::DecimalArray;
+use vortex::array::extension::ExtensionArray;
+use vortex::array::fixed_size_binary::FixedSizeBinaryArray;
+use vortex::array::fixed_size_list::FixedSizeListArray;
+use vortex::array::list::ListArray;
+use vortex::array::map::MapArray;
+use vortex::array::r#struct::StructArray;
+use vortex::array::union::UnionArray;
+use vortex::array::varbin::VarBinArray;
+use vortex::array::varbinview::VarBinViewArray;
+use vortex::compute::cast::cast;
+use vortex::validity::Validity;
+use vortex::{Array, ArrayDType, Flattened, IntoArray};
+use vortex_dtype::{DType, FieldNames, Nullability, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+/// How to read an array that was written with one dtype as another, newer, dtype.
+///
+/// Struct fields are matched by name, so they can be reordered, dropped, or added as long as
+/// the added fields are nullable. Primitive columns can be widened without losing precision,
+/// and any non-nullable column can be read as nullable.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SchemaResolution {
+    /// The stored array already has the requested dtype.
+    Identity,
+    /// The stored values are cast to the requested dtype.
+    Cast(DType),
+    /// The stored values keep their type and are only read as nullable.
+    Nullable,
+    /// The requested struct is assembled field by field from the stored struct.
+    Struct {
+        names: FieldNames,
+        fields: Vec<FieldResolution>,
+        nullability: Nullability,
+    },
+}
+
+/// Where the values of a requested struct field come from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldResolution {
+    /// The field at the given index of the stored struct, resolved into the requested dtype.
+    Stored(usize, SchemaResolution),
+    /// The field didn't exist when the array was written, so it's filled with nulls.
+    Missing(DType),
+}
+
+impl SchemaResolution {
+    /// Resolve the dtype the array was written with against the requested dtype.
+    pub fn try_new(stored: &DType, requested: &DType) -> VortexResult<Self> {
+        if stored == requested {
+            return Ok(Self::Identity);
+        }
+        if stored.is_nullable() && !requested.is_nullable() {
+            vortex_bail!(
+                "Can't read nullable {} as non-nullable {}",
+                stored,
+                requested
+            );
+        }
+
+        match (stored, requested) {
+            (DType::Primitive(from, _), DType::Primitive(to, _)) if is_widening(*from, *to) => {
+                Ok(Self::Cast(requested.clone()))
+            }
+            (DType::Struct(stored_st, _), DType::Struct(requested_st, nullability)) => {
+                let fields = requested_st
+                    .names()
+                    .iter()
+                    .zip(requested_st.dtypes().iter())
+                    .map(|(name, dtype)| match stored_st.find_name(name) {
+                        Some(idx) => Self::try_new(&stored_st.dtypes()[idx], dtype)
+                            .map(|resolution| FieldResolution::Stored(idx, resolution)),
+                        None if dtype.is_nullable() => Ok(FieldResolution::Missing(dtype.clone())),
+                        None => Err(vortex_err!(
+                            "Field {} of type {} is missing and not nullable",
+                            name,
+                            dtype
+                        )),
+                    })
+                    .try_collect()?;
+                Ok(Self::Struct {
+                    names: requested_st.names().clone(),
+                    fields,
+                    nullability: *nullability,
+                })
+            }
+            _ if stored.eq_ignore_nullability(requested) => Ok(Self::Nullable),
+            _ => vortex_bail!("Can't read {} as {}", stored, requested),
+        }
+    }
+
+    /// Convert an array of the stored dtype into an array of the requested dtype.
+    pub fn resolve(&self, array: Array) -> VortexResult<Array> {
+        match self {
+            Self::Identity => Ok(array),
+            Self::Cast(dtype) => {
+                // Primitive casts keep the validity of the array, so the nullability is changed
+                // separately from the type.
+                let widened = cast(&array, &dtype.with_nullability(array.dtype().nullability()))?;
+                cast(&widened, dtype)
+            }
+            Self::Nullable => into_nullable(array),
+            Self::Struct {
+                names,
+                fields,
+                nullability,
+            } => {
+                let st = match StructArray::try_from(&array) {
+                    Ok(st) => st,
+                    Err(_) => StructArray::try_from(array.flatten()?.into_array())?,
+                };
+                let len = st.array().len();
+                let resolved = fields
+                    .iter()
+                    .map(|field| match field {
+                        FieldResolution::Stored(idx, resolution) => resolution.resolve(
+                            st.field(*idx)
+                                .ok_or_else(|| vortex_err!(OutOfBounds: *idx, 0, st.nfields()))?,
+                        ),
+                        FieldResolution::Missing(dtype) => {
+                            Ok(ConstantArray::new(Scalar::null(dtype.clone()), len).into_array())
+                        }
+                    })
+                    .try_collect()?;
+                let validity = match (st.validity(), nullability) {
+                    (Validity::NonNullable, Nullability::Nullable) => Validity::AllValid,
+                    (validity, _) => validity,
+                };
+
+                StructArray::try_new(names.clone(), resolved, len, validity).map(|a| a.into_array())
+            }
+        }
+    }
+}
+
+/// Rebuild a non-nullable array as a nullable one with all of its values valid.
+fn into_nullable(array: Array) -> VortexResult<Array> {
+    if array.dtype().is_nullable() {
+        return Ok(array);
+    }
+
+    let len = array.len();
+    let validity = Validity::AllValid;
+    Ok(match array.flatten()? {
+        Flattened::Bool(a) => BoolArray::try_new(a.boolean_buffer(), validity)?.into_array(),
+        Flattened::Primitive(a) => cast(a.array(), &a.dtype().as_nullable())?,
+        Flattened::Decimal(a) => {
+            DecimalArray::try_new(a.precision(), a.scale(), a.unscaled(), validity)?.into_array()
+        }
+        Flattened::List(a) => ListArray::try_new(a.offsets(), a.elements(), validity)?.into_array(),
+        Flattened::FixedSizeList(a) => {
+            FixedSizeListArray::try_new(a.elements(), a.list_size(), len, validity)?.into_array()
+        }
+        Flattened::Map(a) => {
+            MapArray::try_new(a.offsets(), a.keys(), a.values(), validity)?.into_array()
+        }
+        Flattened::Struct(a) => {
+            StructArray::try_new(a.names().clone(), a.children().collect(), len, validity)?
+                .into_array()
+        }
+        Flattened::Union(a) => UnionArray::try_new(
+            a.names().clone(),
+            a.type_ids(),
+            a.offsets(),
+            a.variants().collect(),
+            Nullability::Nullable,
+        )?
+        .into_array(),
+        Flattened::VarBin(a) => {
+            VarBinArray::try_new(a.offsets(), a.bytes(), a.dtype().as_nullable(), validity)?
+                .into_array()
+        }
+        Flattened::VarBinView(a) => {
+            // A non-nullable array has no validity child, so every child after the views is a
+            // data buffer.
+            let data = (1..)
+                .map_while(|idx| a.array().child(idx, &DType::BYTES))
+                .collect();
+            VarBinViewArray::try_new(a.views(), data, a.dtype().as_nullable(), validity)?
+                .into_array()
+        }
+        Flattened::FixedSizeBinary(a) => {
+            FixedSizeBinaryArray::try_new(a.byte_width(), a.bytes().clone(), len, validity)?
+                .into_array()
+        }
+        Flattened::Extension(a) => {
+            ExtensionArray::new(a.ext_dtype().clone(), into_nullable(a.storage())?).into_array()
+        }
+    })
+}
+
+/// Whether every value of the `from` type can be represented exactly by the `to` type.
+fn is_widening(from: PType, to: PType) -> bool {
+    if from == to {
+        return true;
+    }
+    if to.is_float() {
+        // Floats represent integers exactly up to the width of their mantissa.
+        let mantissa_bits = match to {
+            PType::F16 => 11,
+            PType::F32 => 24,
+            _ => 53,
+        };
+        return if from.is_float() {
+            from.bit_width() < to.bit_width()
+        } else {
+            from.bit_width() < mantissa_bits
+        };
+    }
+    // Unsigned integers need an extra bit to be stored as signed ones, so the width must always
+    // grow, and signed integers can never be stored as unsigned ones.
+    from.is_int()
+        && !(from.is_signed_int() && to.is_unsigned_int())
+        && from.bit_width() < to.bit_width()
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::bool::BoolArray;
+    use vortex::array::list::ListArray;
+    use vortex::array::primitive::PrimitiveArray;
+    use vortex::array::r#struct::StructArray;
+    use vortex::array::varbin::VarBinArray;
+    use vortex::compute::scalar_at::scalar_at;
+    use vortex::validity::Validity;
+    use vortex::{Array, ArrayDType, IntoArray};
+    use vortex_dtype::Nullability::NonNullable;
+    use vortex_dtype::{DType, Nullability, PType, StructDType};
+    use vortex_scalar::Scalar;
+
+    use crate::schema::{is_widening, FieldResolution, SchemaResolution};
+
+    fn stored() -> Array {
+        StructArray::try_new(
+            vec!["id".into(), "name".into()].into(),
+            vec![
+                PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+                VarBinArray::from(vec!["a", "b", "c"]).into_array(),
+            ],
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    fn struct_dtype(fields: Vec<(&str, DType)>) -> DType {
+        let (names, dtypes): (Vec<_>, Vec<_>) = fields
+            .into_iter()
+            .map(|(name, dtype)| (name.into(), dtype))
+            .unzip();
+        DType::Struct(
+            StructDType::new(names.into(), dtypes),
+            Nullability::NonNullable,
+        )
+    }
+
+    #[test]
+    fn evolve_struct() {
+        let array = stored();
+        let requested = struct_dtype(vec![
+            ("name", DType::Utf8(Nullability::NonNullable)),
+            ("added", DType::Bool(Nullability::Nullable)),
+            ("id", DType::Primitive(PType::I64, Nullability::Nullable)),
+        ]);
+
+        let resolution = SchemaResolution::try_new(array.dtype(), &requested).unwrap();
+        assert_eq!(
+            resolution,
+            SchemaResolution::Struct {
+                names: vec!["name".into(), "added".into(), "id".into()].into(),
+                fields: vec![
+                    FieldResolution::Stored(1, SchemaResolution::Identity),
+                    FieldResolution::Missing(DType::Bool(Nullability::Nullable)),
+                    FieldResolution::Stored(
+                        0,
+                        SchemaResolution::Cast(DType::Primitive(PType::I64, Nullability::Nullable))
+                    ),
+                ],
+                nullability: Nullability::NonNullable,
+            }
+        );
+
+        let resolved = StructArray::try_from(resolution.resolve(array).unwrap()).unwrap();
+        assert_eq!(resolved.dtype(), &requested);
+        assert_eq!(
+            scalar_at(&resolved.field(0).unwrap(), 2).unwrap(),
+            Scalar::from("c")
+        );
+        assert!(scalar_at(&resolved.field(1).unwrap(), 0).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&resolved.field(2).unwrap(), 1).unwrap(),
+            Scalar::from(Some(2i64))
+        );
+    }
+
+    #[test]
+    fn incompatible_schemas() {
+        let dtype = stored().dtype().clone();
+        // Added fields must be nullable.
+        assert!(SchemaResolution::try_new(
+            &dtype,
+            &struct_dtype(vec![("added", DType::Bool(Nullability::NonNullable))])
+        )
+        .is_err());
+        // Narrowing would lose values.
+        assert!(SchemaResolution::try_new(
+            &dtype,
+            &struct_dtype(vec![(
+                "id",
+                DType::Primitive(PType::I16, Nullability::NonNullable)
+            )])
+        )
+        .is_err());
+        assert!(SchemaResolution::try_new(
+            &dtype,
+            &struct_dtype(vec![("name", DType::Binary(Nullability::NonNullable))])
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn nullable_widening() {
+        let arrays = vec![
+            VarBinArray::from(vec!["a", "b"]).into_array(),
+            VarBinArray::from_vec(
+                vec![b"a".to_vec(), b"b".to_vec()],
+                DType::Binary(NonNullable),
+            )
+            .into_array(),
+            BoolArray::from(vec![true, false]).into_array(),
+            ListArray::try_new(
+                PrimitiveArray::from(vec![0u32, 1, 3]).into_array(),
+                PrimitiveArray::from(vec![1i32, 2, 3]).into_array(),
+                Validity::NonNullable,
+            )
+            .unwrap()
+            .into_array(),
+        ];
+
+        for array in arrays {
+            let requested = array.dtype().as_nullable();
+            let resolution = SchemaResolution::try_new(array.dtype(), &requested).unwrap();
+            assert_eq!(resolution, SchemaResolution::Nullable);
+
+            let resolved = resolution.resolve(array.clone()).unwrap();
+            assert_eq!(resolved.dtype(), &requested);
+            assert_eq!(
+                scalar_at(&resolved, 1).unwrap().value(),
+                scalar_at(&array, 1).unwrap().value()
+            );
+
+            assert!(SchemaResolution::try_new(&requested, array.dtype()).is_err());
+        }
+    }
+
+    #[test]
+    fn widening() {
+        assert!(is_widening(PType::U8, PType::U16));
+        assert!(is_widening(PType::U32, PType::I64));
+        assert!(is_widening(PType::I32, PType::F64));
+        assert!(is_widening(PType::F32, PType::F64));
+        assert!(!is_widening(PType::I32, PType::U64));
+        assert!(!is_widening(PType::U32, PType::I32));
+        assert!(!is_widening(PType::I64, PType::F64));
+        assert!(!is_widening(PType::F64, PType::F32));
+    }
+}
@@ -6,6 +6,7 @@ use futures_util::Stream;
 use vortex::stream::ArrayStream;
 use vortex::{Context, ViewContext};
 use vortex_buffer::Buffer;
+use vortex_dtype::field_paths::FieldPath;
 use vortex_dtype::DType;
 use vortex_error::VortexResult;
 
@@ -20,8 +21,13 @@ pub struct StreamArrayReader<R: VortexRead> {
 
 impl<R: VortexRead> StreamArrayReader<R> {
     pub async fn try_new(read: R) -> VortexResult<Self> {
+        Self::try_new_at(read, 0).await
+    }
+
+    /// Read a stream starting at the given offset of a file, see [MessageReader::try_new_at].
+    pub async fn try_new_at(read: R, offset: u64) -> VortexResult<Self> {
         Ok(Self {
-            msgs: MessageReader::try_new(read).await?,
+            msgs: MessageReader::try_new_at(read, offset).await?,
             view_context: None,
             dtype: None,
         })
@@ -68,6 +74,21 @@ impl<R: VortexRead> StreamArrayReader<R> {
         self.msgs.array_stream(view_context, dtype)
     }
 
+    /// Reads a single struct array from the stream, only keeping the projected fields.
+    pub fn array_stream_projected(
+        &mut self,
+        projection: &[FieldPath],
+    ) -> VortexResult<impl ArrayStream + '_> {
+        let view_context = self
+            .view_context
+            .as_ref()
+            .expect("View context not set")
+            .clone();
+        let dtype = self.dtype.as_ref().expect("DType not set").deref().clone();
+        self.msgs
+            .array_stream_projected(view_context, dtype, projection)
+    }
+
     /// Reads a single page from the stream.
     pub async fn next_page(&mut self) -> VortexResult<Option<Buffer>> {
         self.msgs.maybe_read_page().await
@@ -6,7 +6,7 @@ use vortex_dtype::DType;
 use vortex_error::{vortex_bail, VortexResult};
 
 use crate::io::VortexWrite;
-use crate::MessageWriter;
+use crate::{BufferCompression, MessageWriter};
 
 pub struct ArrayWriter<W: VortexWrite> {
     msgs: MessageWriter<W>,
@@ -26,6 +26,28 @@ impl<W: VortexWrite> ArrayWriter<W> {
         }
     }
 
+    /// Continue writing a stream of which `pos` bytes have already been written.
+    pub fn new_at(write: W, view_ctx: ViewContext, pos: u64) -> Self {
+        Self {
+            msgs: MessageWriter::new_at(write, pos),
+            view_ctx,
+            view_ctx_range: None,
+            array_layouts: vec![],
+        }
+    }
+
+    /// Compress the buffers of every chunk written from now on, see [BufferCompression].
+    pub fn with_compression(mut self, compression: BufferCompression) -> Self {
+        self.msgs = self.msgs.with_compression(compression);
+        self
+    }
+
+    /// Checksum every message and buffer written from now on, see [MessageWriter::with_checksums].
+    pub fn with_checksums(mut self, checksums: bool) -> Self {
+        self.msgs = self.msgs.with_checksums(checksums);
+        self
+    }
+
     pub fn view_context_range(&self) -> Option<ByteRange> {
         self.view_ctx_range
     }
@@ -34,10 +56,18 @@ impl<W: VortexWrite> ArrayWriter<W> {
         &self.array_layouts
     }
 
+    pub fn view_context(&self) -> &ViewContext {
+        &self.view_ctx
+    }
+
     pub fn into_inner(self) -> W {
         self.msgs.into_inner()
     }
 
+    pub(crate) fn messages_mut(&mut self) -> &mut MessageWriter<W> {
+        &mut self.msgs
+    }
+
     pub async fn write_context(mut self) -> VortexResult<Self> {
         if self.view_ctx_range.is_some() {
             vortex_bail!("View context already written");
@@ -59,7 +89,7 @@ impl<W: VortexWrite> ArrayWriter<W> {
         Ok(ByteRange { begin, end })
     }
 
-    async fn write_array_chunks<S>(&mut self, mut stream: S) -> VortexResult<ChunkLayout>
+    pub(crate) async fn write_array_chunks<S>(&mut self, mut stream: S) -> VortexResult<ChunkLayout>
     where
         S: Stream<Item = VortexResult<Array>> + Unpin,
     {
@@ -68,15 +98,26 @@ impl<W: VortexWrite> ArrayWriter<W> {
         let mut row_offset = 0;
 
         while let Some(chunk) = stream.try_next().await? {
-            row_offset += chunk.len() as u64;
+            let len = chunk.len();
+            let chunk = chunk.into_array_data();
+            if let Some(data) = chunk
+                .depth_first_traversal()
+                .find(|data| self.view_ctx.encoding_idx(data.encoding().id()).is_none())
+            {
+                vortex_bail!(
+                    "Encoding {} is not in the view context of the stream",
+                    data.encoding().id()
+                );
+            }
+
+            row_offset += len as u64;
             row_offsets.push(row_offset);
-            self.msgs
-                .write_chunk(&self.view_ctx, chunk.into_array_data())
-                .await?;
+            self.msgs.write_chunk(&self.view_ctx, chunk).await?;
             byte_offsets.push(self.msgs.tell());
         }
 
         Ok(ChunkLayout {
+            byte_ends: byte_offsets[1..].to_vec(),
             byte_offsets,
             row_offsets,
         })
@@ -118,6 +159,16 @@ pub struct ArrayLayout {
 
 #[derive(Clone, Debug)]
 pub struct ChunkLayout {
+    /// The start of each chunk, followed by the end of the last one.
     pub byte_offsets: Vec<u64>,
     pub row_offsets: Vec<u64>,
+    /// The end of each chunk, which may be before the start of the next one.
+    pub byte_ends: Vec<u64>,
+}
+
+impl ChunkLayout {
+    /// The begin and end byte offsets of a chunk.
+    pub fn byte_range(&self, chunk_idx: usize) -> (u64, u64) {
+        (self.byte_offsets[chunk_idx], self.byte_ends[chunk_idx])
+    }
 }
@@ -1,9 +1,11 @@
 use vortex::array::primitive::PrimitiveArray;
+use vortex::compute::aggregate::{aggregate, weighted_aggregate, Aggregate, AggregateFn};
 use vortex::compute::scalar_at::{scalar_at, ScalarAtFn};
 use vortex::compute::slice::{slice, SliceFn};
 use vortex::compute::take::{take, TakeFn};
 use vortex::compute::ArrayCompute;
-use vortex::{Array, IntoArray};
+use vortex::validity::ArrayValidity;
+use vortex::{Array, ArrayTrait, IntoArray};
 use vortex_dtype::match_each_integer_ptype;
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
@@ -11,6 +13,10 @@ use vortex_scalar::Scalar;
 use crate::REEArray;
 
 impl ArrayCompute for REEArray {
+    fn aggregate(&self) -> Option<&dyn AggregateFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -24,6 +30,39 @@ impl ArrayCompute for REEArray {
     }
 }
 
+impl AggregateFn for REEArray {
+    fn aggregate(&self, agg: Aggregate) -> VortexResult<Scalar> {
+        // Nulls are tracked per element rather than per run, so runs are only aggregated when
+        // every element is valid.
+        if !self.logical_validity().all_valid() {
+            return aggregate(
+                &self.clone().into_array().flatten_primitive()?.into_array(),
+                agg,
+            );
+        }
+        weighted_aggregate(&self.values(), &run_lengths(self)?, agg)
+    }
+}
+
+/// The number of elements of each run that fall within the array's offset and length.
+fn run_lengths(array: &REEArray) -> VortexResult<Vec<u64>> {
+    let ends = array.ends().flatten_primitive()?;
+    let offset = array.offset() as u64;
+    let end = offset + array.len() as u64;
+    let mut start = offset;
+    Ok(match_each_integer_ptype!(ends.ptype(), |$E| {
+        ends.typed_data::<$E>()
+            .iter()
+            .map(|&e| {
+                let run_end = (e as u64).clamp(start, end);
+                let length = run_end - start;
+                start = run_end;
+                length
+            })
+            .collect()
+    }))
+}
+
 impl ScalarAtFn for REEArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         scalar_at(&self.values(), self.find_physical_index(index)?)
@@ -68,6 +107,8 @@ impl SliceFn for REEArray {
 #[cfg(test)]
 mod test {
     use vortex::array::primitive::PrimitiveArray;
+    use vortex::compute::aggregate::{count, max, sum};
+    use vortex::compute::slice::slice;
     use vortex::compute::take::take;
     use vortex::ToArray;
 
@@ -85,4 +126,18 @@ mod test {
             &[5, 5, 1, 4]
         );
     }
+
+    #[test]
+    fn ree_aggregate() {
+        let ree = REEArray::encode(
+            PrimitiveArray::from(vec![1, 1, 1, 4, 4, 4, 2, 2, 5, 5, 5, 5]).to_array(),
+        )
+        .unwrap();
+        assert_eq!(sum(ree.array()).unwrap(), Some(39i64).into());
+
+        let sliced = slice(ree.array(), 2, 9).unwrap();
+        assert_eq!(sum(&sliced).unwrap(), Some(22i64).into());
+        assert_eq!(count(&sliced).unwrap(), 7);
+        assert_eq!(max(&sliced).unwrap(), Some(5i32).into());
+    }
 }
@@ -1,14 +1,23 @@
 use croaring::Bitmap;
+use vortex::compute::boolean::{BinaryBooleanFn, BooleanOperator, NotFn};
 use vortex::compute::scalar_at::ScalarAtFn;
 use vortex::compute::slice::SliceFn;
 use vortex::compute::ArrayCompute;
-use vortex::{Array, IntoArray};
+use vortex::{Array, ArrayTrait, IntoArray};
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::RoaringBoolArray;
 
 impl ArrayCompute for RoaringBoolArray {
+    fn binary_boolean(&self) -> Option<&dyn BinaryBooleanFn> {
+        Some(self)
+    }
+
+    fn not(&self) -> Option<&dyn NotFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -18,6 +27,34 @@ impl ArrayCompute for RoaringBoolArray {
     }
 }
 
+impl BinaryBooleanFn for RoaringBoolArray {
+    fn binary_boolean(&self, rhs: &Array, op: BooleanOperator) -> VortexResult<Array> {
+        // Roaring bitmaps have no nulls, so two of them combine without any null handling.
+        let Ok(rhs) = Self::try_from(rhs) else {
+            return BinaryBooleanFn::binary_boolean(
+                &self.clone().into_array().flatten_bool()?,
+                rhs,
+                op,
+            );
+        };
+
+        let (lhs, rhs) = (self.bitmap(), rhs.bitmap());
+        let bitmap = match op {
+            BooleanOperator::And => lhs.and(&rhs),
+            BooleanOperator::AndNot => lhs.andnot(&rhs),
+            BooleanOperator::Or => lhs.or(&rhs),
+            BooleanOperator::Xor => lhs.xor(&rhs),
+        };
+        Self::try_new(bitmap, self.len()).map(|a| a.into_array())
+    }
+}
+
+impl NotFn for RoaringBoolArray {
+    fn not(&self) -> VortexResult<Array> {
+        Self::try_new(self.bitmap().flip(0..self.len() as u32), self.len()).map(|a| a.into_array())
+    }
+}
+
 impl ScalarAtFn for RoaringBoolArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         if self.bitmap().contains(index as u32) {
@@ -36,3 +73,48 @@ impl SliceFn for RoaringBoolArray {
         Self::try_new(bitmap, stop - start).map(|a| a.into_array())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex::array::bool::BoolArray;
+    use vortex::compute::boolean::{and_not, not, or};
+    use vortex::IntoArray;
+
+    use crate::RoaringBoolArray;
+
+    fn roaring(bools: Vec<bool>) -> RoaringBoolArray {
+        RoaringBoolArray::encode(BoolArray::from(bools).into_array())
+            .and_then(RoaringBoolArray::try_from)
+            .unwrap()
+    }
+
+    #[test]
+    fn boolean_bitmaps() {
+        let lhs = roaring(vec![true, true, false, false, true]);
+        let rhs = roaring(vec![true, false, true, false, false]);
+
+        let and_not = RoaringBoolArray::try_from(
+            and_not(&lhs.clone().into_array(), &rhs.clone().into_array()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(and_not.bitmap().to_vec(), vec![1, 4]);
+
+        let negated = RoaringBoolArray::try_from(not(&rhs.into_array()).unwrap()).unwrap();
+        assert_eq!(negated.bitmap().to_vec(), vec![1, 3, 4]);
+
+        // Other arrays are combined with the flattened bitmap.
+        let ored = or(
+            &lhs.into_array(),
+            &BoolArray::from(vec![false, false, false, true, false]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(
+            ored.flatten_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![true, true, false, true, true]
+        );
+    }
+}
@@ -1,5 +1,4 @@
-use arrow_buffer::BooleanBuffer;
-use arrow_buffer::Buffer as ArrowBuffer;
+use arrow_buffer::BooleanBufferBuilder;
 use compress::roaring_encode;
 use croaring::{Bitmap, Portable};
 use serde::{Deserialize, Serialize};
@@ -89,16 +88,14 @@ impl ArrayValidity for RoaringBoolArray {
 impl ArrayFlatten for RoaringBoolArray {
     fn flatten(self) -> VortexResult<Flattened> {
         // TODO(ngates): benchmark the fastest conversion from BitMap.
-        //  Via bitset requires two copies.
-        let bitset = self
-            .bitmap()
-            .to_bitset()
-            .ok_or_else(|| vortex_err!("Failed to convert RoaringBitmap to Bitset"))?;
-
-        let bytes = &bitset.as_slice()[0..bitset.size_in_bytes()];
-        let buffer = ArrowBuffer::from_slice_ref(bytes);
+        //  The bitset only extends to the last set bit, so the bits are set one at a time.
+        let mut bools = BooleanBufferBuilder::new(self.len());
+        bools.append_n(self.len(), false);
+        for idx in self.bitmap().iter() {
+            bools.set_bit(idx as usize, true);
+        }
         Ok(Flattened::Bool(BoolArray::try_new(
-            BooleanBuffer::new(buffer, 0, bitset.size_in_bits()),
+            bools.finish(),
             match self.dtype().nullability() {
                 NonNullable => Validity::NonNullable,
                 Nullable => Validity::AllValid,
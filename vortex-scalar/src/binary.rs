@@ -20,8 +20,26 @@ impl<'a> BinaryScalar<'a> {
         self.value.as_ref().cloned()
     }
 
-    pub fn cast(&self, _dtype: &DType) -> VortexResult<Scalar> {
-        todo!()
+    /// Cast to binary, or to fixed-size binary of the same length.
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        match (dtype, &self.value) {
+            (DType::FixedSizeBinary(size, _), Some(value)) if value.len() != *size as usize => {
+                vortex_bail!("Can't cast binary of {} bytes to {}", value.len(), dtype)
+            }
+            (DType::Binary(_) | DType::FixedSizeBinary(..), _) => Ok(Scalar {
+                dtype: dtype.clone(),
+                value: self
+                    .value
+                    .clone()
+                    .map(ScalarValue::Buffer)
+                    .unwrap_or(ScalarValue::Null),
+            }),
+            _ => vortex_bail!(
+                "Can't cast binary scalar of type {} to {}",
+                self.dtype(),
+                dtype
+            ),
+        }
     }
 }
 
@@ -38,7 +56,7 @@ impl<'a> TryFrom<&'a Scalar> for BinaryScalar<'a> {
     type Error = VortexError;
 
     fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
-        if !matches!(value.dtype(), DType::Binary(_)) {
+        if !matches!(value.dtype(), DType::Binary(_) | DType::FixedSizeBinary(..)) {
             vortex_bail!("Expected binary scalar, found {}", value.dtype())
         }
         Ok(Self {
@@ -57,3 +75,26 @@ impl<'a> TryFrom<&'a Scalar> for Buffer {
             .ok_or_else(|| vortex_err!("Can't extract present value from null scalar"))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex_buffer::Buffer;
+    use vortex_dtype::DType;
+    use vortex_dtype::Nullability::{NonNullable, Nullable};
+
+    use crate::Scalar;
+
+    #[test]
+    fn cast_fixed_size_binary() {
+        let binary = Scalar::binary(Buffer::from(vec![1u8, 2, 3, 4]), NonNullable);
+        let fixed = DType::FixedSizeBinary(4, Nullable);
+        let cast = binary.cast(&fixed).unwrap();
+        assert_eq!(cast.dtype(), &fixed);
+        assert_eq!(cast.cast(&DType::Binary(NonNullable)).unwrap(), binary);
+
+        assert!(binary
+            .cast(&DType::FixedSizeBinary(3, NonNullable))
+            .is_err());
+        assert!(binary.cast(&DType::Utf8(NonNullable)).is_err());
+    }
+}
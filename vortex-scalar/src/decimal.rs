@@ -0,0 +1,252 @@
+use std::fmt::{Display, Formatter};
+
+use vortex_dtype::{match_each_float_ptype, match_each_integer_ptype, DType, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::primitive::PrimitiveScalar;
+use crate::value::ScalarValue;
+use crate::Scalar;
+
+/// A fixed-point scalar, held as its unscaled integer value.
+///
+/// The value of the decimal is `unscaled * 10^-scale`, e.g. 123.45 is stored as 12345 with a
+/// scale of 2.
+pub struct DecimalScalar<'a> {
+    dtype: &'a DType,
+    precision: u8,
+    scale: u8,
+    value: Option<i128>,
+}
+
+impl<'a> DecimalScalar<'a> {
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    #[inline]
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    #[inline]
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Returns the unscaled value of the decimal.
+    pub fn value(&self) -> Option<i128> {
+        self.value
+    }
+
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        let Some(value) = self.value else {
+            return Ok(Scalar::null(dtype.clone()));
+        };
+
+        match dtype {
+            DType::Decimal(precision, scale, nullability) => Ok(Scalar::decimal(
+                rescale(value, self.scale, *scale, *precision)
+                    .ok_or_else(|| vortex_err!("Can't cast {} to {}", self, dtype))?,
+                *precision,
+                *scale,
+                *nullability,
+            )),
+            DType::Primitive(ptype, nullability) if ptype.is_int() => {
+                let divisor = pow10(self.scale)
+                    .ok_or_else(|| vortex_err!("Can't cast {} to {}", self, dtype))?;
+                if value % divisor != 0 {
+                    vortex_bail!("Can't cast {} to {} without losing precision", self, dtype);
+                }
+                match_each_integer_ptype!(ptype, |$T| {
+                    Ok(Scalar::primitive::<$T>(
+                        <$T>::try_from(value / divisor)
+                            .map_err(|_| vortex_err!("Can't cast {} to {}", self, dtype))?,
+                        *nullability,
+                    ))
+                })
+            }
+            DType::Primitive(ptype, nullability) if ptype.is_float() => {
+                let value = value as f64 / 10f64.powi(self.scale as i32);
+                Scalar::primitive(value, *nullability).cast(dtype)
+            }
+            _ => vortex_bail!("Can't cast {} to {}", self.dtype, dtype),
+        }
+    }
+}
+
+impl Display for DecimalScalar<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some(value) = self.value else {
+            return write!(f, "null");
+        };
+        let digits = value.unsigned_abs().to_string();
+        let sign = if value < 0 { "-" } else { "" };
+        let scale = self.scale as usize;
+        if scale == 0 {
+            write!(f, "{}{}", sign, digits)
+        } else if digits.len() > scale {
+            let (int, frac) = digits.split_at(digits.len() - scale);
+            write!(f, "{}{}.{}", sign, int, frac)
+        } else {
+            write!(f, "{}0.{:0>scale$}", sign, digits, scale = scale)
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for DecimalScalar<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
+        let DType::Decimal(precision, scale, _) = value.dtype() else {
+            vortex_bail!("Expected decimal scalar, found {}", value.dtype())
+        };
+        Ok(Self {
+            dtype: value.dtype(),
+            precision: *precision,
+            scale: *scale,
+            value: value.value.as_decimal()?,
+        })
+    }
+}
+
+impl Scalar {
+    /// Create a decimal scalar from its unscaled value.
+    pub fn decimal(value: i128, precision: u8, scale: u8, nullability: Nullability) -> Self {
+        Self {
+            dtype: DType::Decimal(precision, scale, nullability),
+            value: ScalarValue::Decimal(value),
+        }
+    }
+}
+
+/// Cast a numeric scalar to a decimal, checking that it fits in the precision of the decimal.
+pub(crate) fn cast_to_decimal(scalar: &Scalar, dtype: &DType) -> VortexResult<Scalar> {
+    let DType::Decimal(precision, scale, nullability) = dtype else {
+        vortex_bail!("Expected decimal dtype, found {}", dtype)
+    };
+    match scalar.dtype() {
+        DType::Decimal(..) => DecimalScalar::try_from(scalar)?.cast(dtype),
+        DType::Primitive(ptype, _) if ptype.is_int() => {
+            let primitive = PrimitiveScalar::try_from(scalar)?;
+            let value = match_each_integer_ptype!(ptype, |$T| {
+                primitive.typed_value::<$T>().map(i128::from)
+            });
+            match value {
+                None => Ok(Scalar::null(dtype.clone())),
+                Some(value) => Ok(Scalar::decimal(
+                    rescale(value, 0, *scale, *precision)
+                        .ok_or_else(|| vortex_err!("Can't cast {} to {}", scalar, dtype))?,
+                    *precision,
+                    *scale,
+                    *nullability,
+                )),
+            }
+        }
+        DType::Primitive(ptype, _) if ptype.is_float() => {
+            let primitive = PrimitiveScalar::try_from(scalar)?;
+            let value = match_each_float_ptype!(ptype, |$T| {
+                primitive.typed_value::<$T>().map(f64::from)
+            });
+            match value {
+                None => Ok(Scalar::null(dtype.clone())),
+                Some(value) => {
+                    let unscaled = (value * 10f64.powi(*scale as i32)).round();
+                    if !unscaled.is_finite() || unscaled.abs() >= 10f64.powi(*precision as i32) {
+                        vortex_bail!("Can't cast {} to {}", scalar, dtype);
+                    }
+                    Ok(Scalar::decimal(
+                        unscaled as i128,
+                        *precision,
+                        *scale,
+                        *nullability,
+                    ))
+                }
+            }
+        }
+        _ => vortex_bail!("Can't cast {} to {}", scalar.dtype(), dtype),
+    }
+}
+
+/// Convert an unscaled value between scales, returning None if it would lose digits or no longer
+/// fit in the precision.
+fn rescale(value: i128, from: u8, to: u8, precision: u8) -> Option<i128> {
+    let rescaled = if to >= from {
+        value.checked_mul(pow10(to - from)?)?
+    } else {
+        let divisor = pow10(from - to)?;
+        (value % divisor == 0).then_some(value / divisor)?
+    };
+    match pow10(precision) {
+        Some(max) if rescaled.unsigned_abs() >= max.unsigned_abs() => None,
+        _ => Some(rescaled),
+    }
+}
+
+fn pow10(exp: u8) -> Option<i128> {
+    10i128.checked_pow(exp as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::{DecimalScalar, Scalar};
+
+    #[test]
+    fn display() {
+        let display = |value, scale| {
+            DecimalScalar::try_from(&Scalar::decimal(value, 10, scale, Nullability::NonNullable))
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(display(12345, 2), "123.45");
+        assert_eq!(display(-5, 3), "-0.005");
+        assert_eq!(display(42, 0), "42");
+    }
+
+    #[test]
+    fn cast() {
+        let scalar = Scalar::decimal(12300, 7, 2, Nullability::NonNullable);
+        assert_eq!(
+            scalar
+                .cast(&DType::Decimal(8, 3, Nullability::NonNullable))
+                .unwrap(),
+            Scalar::decimal(123000, 8, 3, Nullability::NonNullable)
+        );
+        assert_eq!(
+            scalar
+                .cast(&DType::Decimal(5, 0, Nullability::NonNullable))
+                .unwrap(),
+            Scalar::decimal(123, 5, 0, Nullability::NonNullable)
+        );
+        // Exceeds the precision of the target.
+        assert!(scalar
+            .cast(&DType::Decimal(4, 2, Nullability::NonNullable))
+            .is_err());
+        assert_eq!(
+            i64::try_from(&scalar.cast(&PType::I64.into()).unwrap()).unwrap(),
+            123
+        );
+        assert_eq!(
+            f64::try_from(&scalar.cast(&PType::F64.into()).unwrap()).unwrap(),
+            123.0
+        );
+        assert!(Scalar::decimal(12345, 7, 2, Nullability::NonNullable)
+            .cast(&PType::I64.into())
+            .is_err());
+
+        assert_eq!(
+            Scalar::from(42u8)
+                .cast(&DType::Decimal(5, 2, Nullability::NonNullable))
+                .unwrap(),
+            Scalar::decimal(4200, 5, 2, Nullability::NonNullable)
+        );
+        assert_eq!(
+            Scalar::from(1.25f64)
+                .cast(&DType::Decimal(5, 2, Nullability::NonNullable))
+                .unwrap(),
+            Scalar::decimal(125, 5, 2, Nullability::NonNullable)
+        );
+    }
+}
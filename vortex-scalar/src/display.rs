@@ -1,9 +1,17 @@
 use std::fmt::{Display, Formatter};
 
+use itertools::Itertools;
 use vortex_dtype::{match_each_native_ptype, DType};
 
+use crate::binary::BinaryScalar;
 use crate::bool::BoolScalar;
+use crate::decimal::DecimalScalar;
+use crate::extension::{find_ext_scalar_type, fmt_ext_value, ExtScalar};
+use crate::list::ListScalar;
+use crate::map::MapScalar;
 use crate::primitive::PrimitiveScalar;
+use crate::union::UnionScalar;
+use crate::utf8::Utf8Scalar;
 use crate::Scalar;
 
 impl Display for Scalar {
@@ -20,17 +28,73 @@ impl Display for Scalar {
                     Some(v) => write!(f, "{}", v),
                 }
             }),
-            DType::Utf8(_) => todo!(),
-            DType::Binary(_) => todo!(),
+            DType::Decimal(..) => write!(f, "{}", DecimalScalar::try_from(self).expect("decimal")),
+            DType::Utf8(_) => match Utf8Scalar::try_from(self).expect("utf8").value() {
+                None => write!(f, "null"),
+                Some(s) => write!(f, "{}", s.as_str()),
+            },
+            DType::Binary(_) | DType::FixedSizeBinary(..) => {
+                match BinaryScalar::try_from(self).expect("binary").value() {
+                    None => write!(f, "null"),
+                    Some(b) => {
+                        write!(f, "0x")?;
+                        b.as_ref()
+                            .iter()
+                            .try_for_each(|byte| write!(f, "{:02x}", byte))
+                    }
+                }
+            }
             DType::Struct(..) => todo!(),
-            DType::List(..) => todo!(),
-            DType::Extension(..) => todo!(),
+            DType::List(..) | DType::FixedSizeList(..) => {
+                let list = ListScalar::try_from(self).expect("list");
+                if self.is_null() {
+                    return write!(f, "null");
+                }
+                write!(f, "[{}]", list.elements().format(", "))?;
+                Ok(())
+            }
+            DType::Map(..) => {
+                let map = MapScalar::try_from(self).expect("map");
+                if self.is_null() {
+                    return write!(f, "null");
+                }
+                let entries = map.entries().map(|(k, v)| format!("{}: {}", k, v));
+                write!(f, "{{{}}}", entries.format(", "))?;
+                Ok(())
+            }
+            DType::Union(..) => {
+                let union = UnionScalar::try_from(self).expect("union");
+                match (union.variant_index(), union.value()) {
+                    (Some(idx), Some(value)) => write!(
+                        f,
+                        "{{{}={}}}",
+                        union.union_dtype().names()[idx as usize],
+                        value
+                    ),
+                    _ => write!(f, "null"),
+                }
+            }
+            DType::Extension(ext_dtype, _) => {
+                let ext = ExtScalar::try_from(self).expect("extension");
+                if ext.value().is_null() {
+                    return write!(f, "null");
+                }
+                match find_ext_scalar_type(ext_dtype.id()) {
+                    Some(ext_type) => ext_type.fmt_scalar(&ext, f),
+                    None => fmt_ext_value(&ext, f),
+                }
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use vortex_buffer::Buffer;
+    use vortex_dtype::{DType, FieldName, Nullability, PType, UnionDType};
+
     use crate::Scalar;
 
     #[test]
@@ -38,4 +102,60 @@ mod tests {
         let scalar = Scalar::from(false);
         assert_eq!(format!("{}", scalar), "false");
     }
+
+    #[test]
+    fn display_fixed_size() {
+        let list = Scalar::from(vec![1i32, 2, 3])
+            .cast(&DType::FixedSizeList(
+                Arc::new(PType::I32.into()),
+                3,
+                Nullability::Nullable,
+            ))
+            .unwrap();
+        assert_eq!(format!("{}", list), "[1, 2, 3]");
+        assert_eq!(format!("{}", Scalar::null(list.dtype().clone())), "null");
+
+        let binary = Scalar::binary(Buffer::from(vec![0u8, 171]), Nullability::NonNullable)
+            .cast(&DType::FixedSizeBinary(2, Nullability::NonNullable))
+            .unwrap();
+        assert_eq!(format!("{}", binary), "0x00ab");
+    }
+
+    #[test]
+    fn display_map() {
+        let dtype = DType::Map(
+            Arc::new(DType::Utf8(Nullability::NonNullable)),
+            Arc::new(DType::Primitive(PType::I32, Nullability::Nullable)),
+            Nullability::Nullable,
+        );
+        let map = Scalar::map(
+            dtype.clone(),
+            vec![
+                (
+                    Scalar::from("a").into_value(),
+                    Scalar::from(1i32).into_value(),
+                ),
+                (
+                    Scalar::from("b").into_value(),
+                    Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable)).into_value(),
+                ),
+            ],
+        );
+        assert_eq!(format!("{}", map), "{a: 1, b: null}");
+        assert_eq!(format!("{}", Scalar::null(dtype)), "null");
+    }
+
+    #[test]
+    fn display_union() {
+        let dtype = DType::Union(
+            UnionDType::new(
+                vec![FieldName::from("a"), FieldName::from("b")].into(),
+                vec![PType::I32.into(), DType::Utf8(Nullability::NonNullable)],
+            ),
+            Nullability::Nullable,
+        );
+        let union = Scalar::union(dtype.clone(), 1, Scalar::from("x").into_value());
+        assert_eq!(format!("{}", union), "{b=x}");
+        assert_eq!(format!("{}", Scalar::null(dtype)), "null");
+    }
 }
@@ -1,4 +1,9 @@
-use vortex_dtype::{DType, ExtDType};
+use std::collections::HashMap;
+use std::fmt::{Formatter, Result as FmtResult};
+use std::sync::{Arc, RwLock};
+
+use lazy_static::lazy_static;
+use vortex_dtype::{DType, ExtDType, ExtID};
 use vortex_error::{vortex_bail, VortexError, VortexResult};
 
 use crate::value::ScalarValue;
@@ -17,13 +22,27 @@ impl<'a> ExtScalar<'a> {
         self.dtype
     }
 
+    pub fn ext_dtype(&self) -> &'a ExtDType {
+        let DType::Extension(ext_dtype, _) = self.dtype() else {
+            unreachable!();
+        };
+        ext_dtype
+    }
+
     /// Returns the stored value of the extension scalar.
     pub fn value(&self) -> &'a ScalarValue {
         self.value
     }
 
-    pub fn cast(&self, _dtype: &DType) -> VortexResult<Scalar> {
-        todo!()
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        match find_ext_scalar_type(self.ext_dtype().id()) {
+            Some(ext_type) => ext_type.cast_scalar(self, dtype),
+            None => vortex_bail!(
+                "Can't cast scalar of unregistered extension type {} to {}",
+                self.dtype(),
+                dtype
+            ),
+        }
     }
 }
 
@@ -50,3 +69,109 @@ impl Scalar {
         }
     }
 }
+
+/// The behavior of the scalars of an extension type.
+///
+/// Extension types registered with [register_ext_scalar_type] are rendered and cast through
+/// these hooks, while scalars of unregistered extension types can't be cast.
+pub trait ExtScalarType: Send + Sync {
+    fn id(&self) -> &ExtID;
+
+    /// Render a non-null scalar of this extension type.
+    fn fmt_scalar(&self, scalar: &ExtScalar, f: &mut Formatter<'_>) -> FmtResult {
+        fmt_ext_value(scalar, f)
+    }
+
+    /// Cast a scalar of this extension type to another dtype.
+    fn cast_scalar(&self, scalar: &ExtScalar, dtype: &DType) -> VortexResult<Scalar> {
+        vortex_bail!("Can't cast scalar of type {} to {}", scalar.dtype(), dtype)
+    }
+}
+
+pub type ExtScalarTypeRef = Arc<dyn ExtScalarType>;
+
+lazy_static! {
+    static ref EXT_SCALAR_TYPES: RwLock<HashMap<ExtID, ExtScalarTypeRef>> =
+        RwLock::new(HashMap::new());
+}
+
+/// Register the scalar behavior of an extension type, replacing any previous registration for
+/// the same id.
+pub fn register_ext_scalar_type(ext_type: ExtScalarTypeRef) {
+    EXT_SCALAR_TYPES
+        .write()
+        .expect("extension scalar registry poisoned")
+        .insert(ext_type.id().clone(), ext_type);
+}
+
+pub fn find_ext_scalar_type(id: &ExtID) -> Option<ExtScalarTypeRef> {
+    EXT_SCALAR_TYPES
+        .read()
+        .expect("extension scalar registry poisoned")
+        .get(id)
+        .cloned()
+}
+
+/// Extension scalars without a dedicated rendering show their id and stored value.
+pub(crate) fn fmt_ext_value(scalar: &ExtScalar, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}({:?})", scalar.ext_dtype().id(), scalar.value())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::{Formatter, Result as FmtResult};
+    use std::sync::Arc;
+
+    use vortex_dtype::{DType, ExtDType, ExtID, Nullability, PType};
+    use vortex_error::VortexResult;
+
+    use crate::{register_ext_scalar_type, ExtScalar, ExtScalarType, PValue, Scalar, ScalarValue};
+
+    /// Percentages stored as basis points.
+    struct Percent(ExtID);
+
+    impl ExtScalarType for Percent {
+        fn id(&self) -> &ExtID {
+            &self.0
+        }
+
+        fn fmt_scalar(&self, scalar: &ExtScalar, f: &mut Formatter<'_>) -> FmtResult {
+            let bps = scalar.value().as_pvalue().unwrap().unwrap();
+            write!(f, "{:.2}%", i32::try_from(bps).unwrap() as f64 / 100.0)
+        }
+
+        fn cast_scalar(&self, scalar: &ExtScalar, dtype: &DType) -> VortexResult<Scalar> {
+            Scalar::new(PType::I32.into(), scalar.value().clone()).cast(dtype)
+        }
+    }
+
+    #[test]
+    fn registered_behavior() {
+        let ext_dtype = ExtDType::new("test.percent".into(), None);
+        register_ext_scalar_type(Arc::new(Percent(ext_dtype.id().clone())));
+
+        let scalar = Scalar::extension(ext_dtype.clone(), Scalar::from(1250i32));
+        assert_eq!(scalar.to_string(), "12.50%");
+        assert_eq!(
+            scalar.cast(&PType::I64.into()).unwrap(),
+            Scalar::from(1250i64)
+        );
+        assert_eq!(
+            Scalar::null(DType::Extension(ext_dtype, Nullability::Nullable)).to_string(),
+            "null"
+        );
+
+        let unregistered = Scalar::extension(
+            ExtDType::new("test.unregistered".into(), None),
+            Scalar::from(7u8),
+        );
+        assert_eq!(
+            unregistered.to_string(),
+            format!(
+                "test.unregistered({:?})",
+                ScalarValue::Primitive(PValue::U8(7))
+            )
+        );
+        assert!(unregistered.cast(&PType::U8.into()).is_err());
+    }
+}
@@ -4,23 +4,30 @@ use vortex_dtype::DType;
 
 mod binary;
 mod bool;
+mod decimal;
 mod display;
 mod extension;
 mod list;
+mod map;
 mod primitive;
 mod pvalue;
 mod serde;
 mod struct_;
+mod union;
 mod utf8;
 mod value;
 
 pub use binary::*;
 pub use bool::*;
+use decimal::cast_to_decimal;
+pub use decimal::*;
 pub use extension::*;
 pub use list::*;
+pub use map::*;
 pub use primitive::*;
 pub use pvalue::*;
 pub use struct_::*;
+pub use union::*;
 pub use utf8::*;
 pub use value::*;
 use vortex_error::{vortex_bail, VortexResult};
@@ -103,14 +110,28 @@ impl Scalar {
             vortex_bail!("Can't cast null scalar to non-nullable type")
         }
 
+        // Extension types define how their own scalars are cast to other types.
+        if matches!(self.dtype(), DType::Extension(..)) {
+            return ExtScalar::try_from(self).and_then(|s| s.cast(dtype));
+        }
+
         match dtype {
             DType::Null => vortex_bail!("Can't cast non-null to null"),
             DType::Bool(_) => BoolScalar::try_from(self).and_then(|s| s.cast(dtype)),
+            DType::Primitive(..) if matches!(self.dtype(), DType::Decimal(..)) => {
+                DecimalScalar::try_from(self).and_then(|s| s.cast(dtype))
+            }
             DType::Primitive(..) => PrimitiveScalar::try_from(self).and_then(|s| s.cast(dtype)),
+            DType::Decimal(..) => cast_to_decimal(self, dtype),
             DType::Utf8(_) => Utf8Scalar::try_from(self).and_then(|s| s.cast(dtype)),
             DType::Binary(_) => BinaryScalar::try_from(self).and_then(|s| s.cast(dtype)),
             DType::Struct(..) => StructScalar::try_from(self).and_then(|s| s.cast(dtype)),
-            DType::List(..) => ListScalar::try_from(self).and_then(|s| s.cast(dtype)),
+            DType::List(..) | DType::FixedSizeList(..) => {
+                ListScalar::try_from(self).and_then(|s| s.cast(dtype))
+            }
+            DType::FixedSizeBinary(..) => BinaryScalar::try_from(self).and_then(|s| s.cast(dtype)),
+            DType::Map(..) => MapScalar::try_from(self).and_then(|s| s.cast(dtype)),
+            DType::Union(..) => UnionScalar::try_from(self).and_then(|s| s.cast(dtype)),
             DType::Extension(..) => ExtScalar::try_from(self).and_then(|s| s.cast(dtype)),
         }
     }
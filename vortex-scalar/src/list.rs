@@ -34,7 +34,8 @@ impl<'a> ListScalar<'a> {
     }
 
     pub fn element_dtype(&self) -> DType {
-        let DType::List(element_type, _) = self.dtype() else {
+        let (DType::List(element_type, _) | DType::FixedSizeList(element_type, ..)) = self.dtype()
+        else {
             unreachable!();
         };
         (*element_type).deref().clone()
@@ -62,8 +63,34 @@ impl<'a> ListScalar<'a> {
             })
     }
 
-    pub fn cast(&self, _dtype: &DType) -> VortexResult<Scalar> {
-        todo!()
+    /// Cast to a list or a fixed-size list of the same length, casting each of the elements.
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        let element_dtype = match dtype {
+            DType::List(element_dtype, _) => element_dtype,
+            DType::FixedSizeList(element_dtype, size, _) => {
+                if self.elements.is_some() && self.len() != *size as usize {
+                    vortex_bail!("Can't cast list of {} elements to {}", self.len(), dtype);
+                }
+                element_dtype
+            }
+            _ => vortex_bail!(
+                "Can't cast list scalar of type {} to {}",
+                self.dtype(),
+                dtype
+            ),
+        };
+        if self.elements.is_none() {
+            return Ok(Scalar::null(dtype.clone()));
+        }
+
+        let elements: Vec<ScalarValue> = self
+            .elements()
+            .map(|e| e.cast(element_dtype).map(|e| e.value))
+            .try_collect()?;
+        Ok(Scalar {
+            dtype: dtype.clone(),
+            value: ScalarValue::List(elements.into()),
+        })
     }
 }
 
@@ -80,7 +107,7 @@ impl<'a> TryFrom<&'a Scalar> for ListScalar<'a> {
     type Error = VortexError;
 
     fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
-        if !matches!(value.dtype(), DType::List(..)) {
+        if !matches!(value.dtype(), DType::List(..) | DType::FixedSizeList(..)) {
             vortex_bail!("Expected list scalar, found {}", value.dtype())
         }
 
@@ -118,3 +145,32 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use vortex_dtype::Nullability::{NonNullable, Nullable};
+    use vortex_dtype::{DType, PType};
+
+    use crate::Scalar;
+
+    #[test]
+    fn cast_fixed_size_list() {
+        let list = Scalar::from(vec![1i32, 2, 3]);
+        let fixed = DType::FixedSizeList(Arc::new(PType::I64.into()), 3, Nullable);
+        let cast = list.cast(&fixed).unwrap();
+        assert_eq!(cast.dtype(), &fixed);
+        assert_eq!(Vec::<i64>::try_from(&cast).unwrap(), vec![1, 2, 3]);
+
+        let back = DType::List(Arc::new(PType::I32.into()), NonNullable);
+        assert_eq!(cast.cast(&back).unwrap(), list);
+
+        let wrong_size = DType::FixedSizeList(Arc::new(PType::I32.into()), 2, NonNullable);
+        assert!(list.cast(&wrong_size).is_err());
+        assert!(Scalar::null(fixed.clone())
+            .cast(&wrong_size.as_nullable())
+            .unwrap()
+            .is_null());
+    }
+}
@@ -0,0 +1,169 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::value::ScalarValue;
+use crate::Scalar;
+
+/// A map scalar holds its entries as a list, where each entry is a two-element list of the key
+/// followed by the value.
+pub struct MapScalar<'a> {
+    dtype: &'a DType,
+    entries: Option<Arc<[ScalarValue]>>,
+}
+
+impl<'a> MapScalar<'a> {
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.as_ref().map(|e| e.len()).unwrap_or(0)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn key_dtype(&self) -> DType {
+        let DType::Map(key_type, ..) = self.dtype() else {
+            unreachable!();
+        };
+        key_type.deref().clone()
+    }
+
+    pub fn value_dtype(&self) -> DType {
+        let DType::Map(_, value_type, _) = self.dtype() else {
+            unreachable!();
+        };
+        value_type.deref().clone()
+    }
+
+    /// The key and value of each entry, in the order they are stored.
+    pub fn entries(&self) -> impl Iterator<Item = (Scalar, Scalar)> + '_ {
+        self.entries
+            .as_ref()
+            .map(|e| e.as_ref())
+            .unwrap_or_else(|| &[] as &[ScalarValue])
+            .iter()
+            .map(|entry| {
+                let (key, value) = split_entry(entry).expect("Invalid map entry");
+                (
+                    Scalar {
+                        dtype: self.key_dtype(),
+                        value: key.clone(),
+                    },
+                    Scalar {
+                        dtype: self.value_dtype(),
+                        value: value.clone(),
+                    },
+                )
+            })
+    }
+
+    /// Look up the value of the first entry with the given key.
+    pub fn get(&self, key: &Scalar) -> Option<Scalar> {
+        self.entries()
+            .find(|(k, _)| k.value() == key.value())
+            .map(|(_, v)| v)
+    }
+
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        vortex_bail!(
+            "Can't cast map scalar of type {} to {}",
+            self.dtype(),
+            dtype
+        )
+    }
+}
+
+impl Scalar {
+    pub fn map(dtype: DType, entries: Vec<(ScalarValue, ScalarValue)>) -> Self {
+        Self {
+            dtype,
+            value: ScalarValue::List(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| ScalarValue::List([k, v].into()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for MapScalar<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
+        if !matches!(value.dtype(), DType::Map(..)) {
+            vortex_bail!("Expected map scalar, found {}", value.dtype())
+        }
+
+        let entries = match &value.value {
+            ScalarValue::Null => None,
+            v => {
+                let entries = v.as_list()?.cloned();
+                if let Some(entries) = entries.as_ref() {
+                    entries
+                        .iter()
+                        .try_for_each(|e| split_entry(e).map(|_| ()))?;
+                }
+                entries
+            }
+        };
+        Ok(Self {
+            dtype: value.dtype(),
+            entries,
+        })
+    }
+}
+
+fn split_entry(entry: &ScalarValue) -> VortexResult<(&ScalarValue, &ScalarValue)> {
+    match entry.as_list()?.map(|e| e.as_ref()) {
+        Some([key, value]) => Ok((key, value)),
+        _ => Err(vortex_err!("Map entries must hold a key and a value")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::{MapScalar, Scalar};
+
+    #[test]
+    fn lookup() {
+        let dtype = DType::Map(
+            Arc::new(DType::Utf8(Nullability::NonNullable)),
+            Arc::new(DType::Primitive(PType::I32, Nullability::Nullable)),
+            Nullability::NonNullable,
+        );
+        let scalar = Scalar::map(
+            dtype,
+            vec![
+                (
+                    Scalar::from("a").into_value(),
+                    Scalar::from(1i32).into_value(),
+                ),
+                (
+                    Scalar::from("b").into_value(),
+                    Scalar::from(2i32).into_value(),
+                ),
+            ],
+        );
+        let map = MapScalar::try_from(&scalar).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(
+            map.get(&Scalar::from("b")).unwrap().value(),
+            Scalar::from(2i32).value()
+        );
+        assert!(map.get(&Scalar::from("c")).is_none());
+    }
+}
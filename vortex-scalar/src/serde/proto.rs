@@ -3,7 +3,7 @@
 use prost_types::value::Kind;
 use prost_types::{ListValue, Struct, Value};
 use vortex_buffer::BufferString;
-use vortex_dtype::{DType, StructDType};
+use vortex_dtype::{DType, StructDType, UnionDType};
 use vortex_error::{vortex_bail, vortex_err, VortexError};
 
 use crate::pvalue::PValue;
@@ -72,9 +72,15 @@ fn try_from_value(dtype: &DType, value: &Value) -> Result<ScalarValue, VortexErr
             ScalarValue::BufferString(BufferString::from(v.clone()))
         }
         Kind::ListValue(v) => {
-            if let DType::List(elem_dtype, _) = dtype {
+            if let DType::List(elem_dtype, _) | DType::FixedSizeList(elem_dtype, ..) = dtype {
                 return try_from_list_value(elem_dtype, v);
             }
+            if let DType::Map(key_dtype, value_dtype, _) = dtype {
+                return try_from_map_value(key_dtype, value_dtype, v);
+            }
+            if let DType::Union(union_dtype, _) = dtype {
+                return try_from_union_value(union_dtype, v);
+            }
 
             vortex_bail!(InvalidSerde: "Expected a list dtype, found {:?}", dtype);
         }
@@ -105,6 +111,55 @@ fn try_from_list_value(elem_dtype: &DType, value: &ListValue) -> Result<ScalarVa
     Ok(ScalarValue::List(values.into()))
 }
 
+/// Map entries are encoded as a list of two-element lists, holding the key and then the value.
+fn try_from_map_value(
+    key_dtype: &DType,
+    value_dtype: &DType,
+    value: &ListValue,
+) -> Result<ScalarValue, VortexError> {
+    let mut entries = vec![];
+
+    for entry in value.values.iter() {
+        let Some(Kind::ListValue(entry)) = entry.kind.as_ref() else {
+            vortex_bail!(InvalidSerde: "Map entry must be a list");
+        };
+        let [key, value] = entry.values.as_slice() else {
+            vortex_bail!(InvalidSerde: "Map entry must hold a key and a value");
+        };
+
+        let key = try_from_value(key_dtype, key)?;
+        if matches!(key, ScalarValue::Null) {
+            vortex_bail!(InvalidSerde: "Map key is null");
+        }
+        entries.push(ScalarValue::List(
+            [key, try_from_value(value_dtype, value)?].into(),
+        ));
+    }
+
+    Ok(ScalarValue::List(entries.into()))
+}
+
+/// Union values are encoded as a two-element list, holding the variant index and then the value.
+fn try_from_union_value(dtype: &UnionDType, value: &ListValue) -> Result<ScalarValue, VortexError> {
+    let [idx, value] = value.values.as_slice() else {
+        vortex_bail!(InvalidSerde: "Union value must hold a variant index and a value");
+    };
+    let Some(&Kind::NumberValue(idx)) = idx.kind.as_ref() else {
+        vortex_bail!(InvalidSerde: "Union variant index must be a number");
+    };
+    let Some(variant_dtype) = dtype.dtypes().get(idx as usize) else {
+        vortex_bail!(InvalidSerde: "Invalid union variant index {}", idx);
+    };
+
+    Ok(ScalarValue::List(
+        [
+            ScalarValue::Primitive(PValue::U8(idx as u8)),
+            try_from_value(variant_dtype, value)?,
+        ]
+        .into(),
+    ))
+}
+
 fn try_from_struct_value(dtype: &StructDType, value: &Struct) -> Result<ScalarValue, VortexError> {
     let mut values = vec![];
 
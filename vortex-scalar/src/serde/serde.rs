@@ -18,6 +18,11 @@ impl Serialize for ScalarValue {
             Self::Null => ().serialize(serializer),
             Self::Bool(b) => b.serialize(serializer),
             Self::Primitive(p) => p.serialize(serializer),
+            // Not all formats support 128-bit integers, so only large decimals are written as bytes.
+            Self::Decimal(d) => match i64::try_from(*d) {
+                Ok(d) => d.serialize(serializer),
+                Err(_) => serializer.serialize_bytes(&d.to_le_bytes()),
+            },
             Self::Buffer(buffer) => buffer.as_ref().serialize(serializer),
             Self::BufferString(buffer) => buffer.as_str().serialize(serializer),
             Self::List(l) => l.serialize(serializer),
@@ -0,0 +1,87 @@
+use vortex_dtype::{DType, UnionDType};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::pvalue::PValue;
+use crate::value::ScalarValue;
+use crate::Scalar;
+
+/// A union scalar holds the index of its variant followed by the value of that variant.
+pub struct UnionScalar<'a> {
+    dtype: &'a DType,
+    variant: Option<(u8, &'a ScalarValue)>,
+}
+
+impl<'a> UnionScalar<'a> {
+    #[inline]
+    pub fn dtype(&self) -> &'a DType {
+        self.dtype
+    }
+
+    pub fn union_dtype(&self) -> &'a UnionDType {
+        let DType::Union(union_dtype, _) = self.dtype() else {
+            unreachable!();
+        };
+        union_dtype
+    }
+
+    /// The index of the variant, or `None` if the scalar is null without a variant.
+    pub fn variant_index(&self) -> Option<u8> {
+        self.variant.map(|(idx, _)| idx)
+    }
+
+    /// The value of the variant, or `None` if the scalar is null without a variant.
+    pub fn value(&self) -> Option<Scalar> {
+        self.variant.map(|(idx, value)| Scalar {
+            dtype: self.union_dtype().dtypes()[idx as usize].clone(),
+            value: value.clone(),
+        })
+    }
+
+    pub fn cast(&self, dtype: &DType) -> VortexResult<Scalar> {
+        vortex_bail!(
+            "Can't cast union scalar of type {} to {}",
+            self.dtype(),
+            dtype
+        )
+    }
+}
+
+impl Scalar {
+    pub fn union(dtype: DType, variant: u8, value: ScalarValue) -> Self {
+        Self {
+            dtype,
+            value: ScalarValue::List([ScalarValue::Primitive(PValue::U8(variant)), value].into()),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Scalar> for UnionScalar<'a> {
+    type Error = VortexError;
+
+    fn try_from(value: &'a Scalar) -> Result<Self, Self::Error> {
+        let DType::Union(union_dtype, _) = value.dtype() else {
+            vortex_bail!("Expected union scalar, found {}", value.dtype())
+        };
+
+        let variant = match &value.value {
+            ScalarValue::Null => None,
+            v => match v.as_list()?.map(|l| l.as_ref()) {
+                Some([idx, variant_value]) => {
+                    let idx: u8 = idx
+                        .as_pvalue()?
+                        .ok_or_else(|| vortex_err!("Missing union variant index"))?
+                        .try_into()?;
+                    if idx as usize >= union_dtype.dtypes().len() {
+                        vortex_bail!("Invalid union variant index {}", idx);
+                    }
+                    Some((idx, variant_value))
+                }
+                _ => vortex_bail!("Union scalars must hold a variant index and a value"),
+            },
+        };
+        Ok(Self {
+            dtype: value.dtype(),
+            variant,
+        })
+    }
+}
@@ -16,6 +16,8 @@ pub enum ScalarValue {
     Null,
     Bool(bool),
     Primitive(PValue),
+    /// The unscaled value of a decimal.
+    Decimal(i128),
     Buffer(Buffer),
     BufferString(BufferString),
     List(Arc<[ScalarValue]>),
@@ -42,6 +44,30 @@ impl ScalarValue {
         }
     }
 
+    /// Read the unscaled value of a decimal.
+    ///
+    /// Decimals that fit in 64 bits are serialized as integers, and larger ones as 16 little-endian
+    /// bytes, so both are accepted here.
+    pub fn as_decimal(&self) -> VortexResult<Option<i128>> {
+        match self {
+            Self::Null => Ok(None),
+            Self::Decimal(d) => Ok(Some(*d)),
+            Self::Primitive(p) => Ok(Some(match *p {
+                PValue::U8(v) => v.into(),
+                PValue::U16(v) => v.into(),
+                PValue::U32(v) => v.into(),
+                PValue::U64(v) => v.into(),
+                PValue::I8(v) => v.into(),
+                PValue::I16(v) => v.into(),
+                PValue::I32(v) => v.into(),
+                PValue::I64(v) => v.into(),
+                _ => return Err(vortex_err!("Expected a decimal scalar, found {:?}", self)),
+            })),
+            Self::Buffer(b) => Ok(Some(i128::from_le_bytes(b.as_ref().try_into()?))),
+            _ => Err(vortex_err!("Expected a decimal scalar, found {:?}", self)),
+        }
+    }
+
     pub fn as_buffer(&self) -> VortexResult<Option<Buffer>> {
         match self {
             Self::Null => Ok(None),
@@ -61,6 +87,7 @@ impl ScalarValue {
 
     pub fn as_list(&self) -> VortexResult<Option<&Arc<[Self]>>> {
         match self {
+            Self::Null => Ok(None),
             Self::List(l) => Ok(Some(l)),
             _ => Err(vortex_err!("Expected a list scalar, found {:?}", self)),
         }